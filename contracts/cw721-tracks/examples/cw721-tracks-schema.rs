@@ -6,7 +6,7 @@ use cw721::{
     NumTokensResponse, OperatorResponse, OperatorsResponse, OwnerOfResponse, TokensResponse,
 };
 use cw721_base::MinterResponse;
-use cw721_tracks_api::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use cw721_tracks_api::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 use std::env::current_dir;
 use std::fs::create_dir_all;
 
@@ -19,6 +19,7 @@ fn main() {
     export_schema(&schema_for!(InstantiateMsg), &out_dir);
     export_schema(&schema_for!(ExecuteMsg), &out_dir);
     export_schema(&schema_for!(QueryMsg), &out_dir);
+    export_schema(&schema_for!(MigrateMsg), &out_dir);
 
     export_schema(&schema_for!(OwnerOfResponse), &out_dir);
     export_schema(&schema_for!(ApprovalResponse), &out_dir);
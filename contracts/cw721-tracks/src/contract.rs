@@ -1,9 +1,11 @@
-use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response};
+use cosmwasm_std::{
+    entry_point, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdError,
+};
 use cw721_base::state::TokenInfo;
 use cw721_base::ContractError;
 use cw721_tracks_api::api::TrackMetadata;
 use cw721_tracks_api::error::TracksError;
-use cw721_tracks_api::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use cw721_tracks_api::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 
 // Version info for migration
 const CONTRACT_NAME: &str = "cw721-tracks";
@@ -52,6 +54,27 @@ pub fn execute(
             token_uri,
             extension,
         } => {
+            if let Some(artist_address) = &extension.artist_address {
+                if *artist_address != info.sender {
+                    return Err(StdError::generic_err(
+                        "artist address can only be set by the artist minting the track",
+                    )
+                    .into());
+                }
+            }
+
+            // token IDs namespaced with an address, as in "<address>/<id>",
+            // are reserved for that address, so they cannot be claimed ahead of it;
+            // any other ID containing a '/', like "<album>/<track>", is free to mint
+            if let Some((namespace, _)) = token_id.split_once('/') {
+                if deps.api.addr_validate(namespace).is_ok() && namespace != info.sender.as_str() {
+                    return Err(StdError::generic_err(
+                        "token IDs namespaced with an address can only be minted by that address",
+                    )
+                    .into());
+                }
+            }
+
             // create the token
             let token = TokenInfo {
                 owner: deps.api.addr_validate(&owner)?,
@@ -84,3 +107,15 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, TracksError>
 
     Ok(response)
 }
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, TracksError> {
+    // refuses to migrate from a different contract or from a newer version
+    let stored_version =
+        cw2::ensure_from_older_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", stored_version.to_string())
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
@@ -0,0 +1,94 @@
+use crate::contract::{instantiate, migrate};
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{attr, StdError};
+use cw721_base::ContractError;
+use cw721_tracks_api::msg::{InstantiateMsg, MigrateMsg};
+
+const ADMIN: &str = "admin";
+
+const CONTRACT_NAME: &str = "cw721-tracks";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[test]
+fn migrate_from_different_contract_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(ADMIN, &[]),
+        anonymous_instantiate_msg(),
+    )?;
+    cw2::set_contract_version(deps.as_mut().storage, "crates.io:cw721-base", "0.1.0")?;
+
+    let result = migrate(deps.as_mut(), env.clone(), MigrateMsg {});
+
+    assert!(matches!(
+        result,
+        Err(ContractError::Std(StdError::GenericErr { .. }))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn migrate_from_newer_version_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(ADMIN, &[]),
+        anonymous_instantiate_msg(),
+    )?;
+    cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "99.0.0")?;
+
+    let result = migrate(deps.as_mut(), env.clone(), MigrateMsg {});
+
+    assert!(matches!(
+        result,
+        Err(ContractError::Std(StdError::GenericErr { .. }))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn migrate_from_older_version_updates_version() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(ADMIN, &[]),
+        anonymous_instantiate_msg(),
+    )?;
+    cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.0.1")?;
+
+    let response = migrate(deps.as_mut(), env.clone(), MigrateMsg {})?;
+
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "migrate"),
+            attr("from_version", "0.0.1"),
+            attr("to_version", CONTRACT_VERSION),
+        ]
+    );
+    assert_eq!(
+        cw2::get_contract_version(deps.as_ref().storage)?.version,
+        CONTRACT_VERSION
+    );
+
+    Ok(())
+}
+
+fn anonymous_instantiate_msg() -> InstantiateMsg {
+    InstantiateMsg {
+        name: "Track NFTs".to_string(),
+        symbol: "TRKS".to_string(),
+    }
+}
@@ -1,10 +1,12 @@
 use crate::contract::{execute, instantiate, Cw721TrackMetadataContract};
-use cosmwasm_std::attr;
-use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockQuerier, MockStorage};
+use cosmwasm_std::{attr, Addr, Empty, OwnedDeps, StdError};
 use cw721::Cw721Query;
 use cw721_base::{ContractError, ExecuteMsg};
 use cw721_tracks_api::api::{AlbumMetadata, TrackMetadata};
 use cw721_tracks_api::msg::InstantiateMsg;
+use cw_multi_test::MockApiBech32;
+use std::marker::PhantomData;
 use ContractError::Claimed;
 use ExecuteMsg::Mint;
 
@@ -21,7 +23,7 @@ fn mint_creates_new_token() -> anyhow::Result<()> {
     instantiate(
         deps.as_mut(),
         env.clone(),
-        mock_info(ADMIN, &vec![]),
+        mock_info(ADMIN, &[]),
         anonymous_instantiate_msg(),
     )?;
 
@@ -32,7 +34,7 @@ fn mint_creates_new_token() -> anyhow::Result<()> {
     let response = execute(
         deps.as_mut(),
         env.clone(),
-        mock_info(USER1, &vec![]),
+        mock_info(USER1, &[]),
         Mint {
             token_id: token_id.to_string(),
             owner: USER2.to_string(),
@@ -76,7 +78,7 @@ fn mint_existing_id_fails() -> anyhow::Result<()> {
     instantiate(
         deps.as_mut(),
         env.clone(),
-        mock_info(ADMIN, &vec![]),
+        mock_info(ADMIN, &[]),
         anonymous_instantiate_msg(),
     )?;
 
@@ -85,7 +87,7 @@ fn mint_existing_id_fails() -> anyhow::Result<()> {
     execute(
         deps.as_mut(),
         env.clone(),
-        mock_info(USER1, &vec![]),
+        mock_info(USER1, &[]),
         Mint {
             token_id: token_id.to_string(),
             owner: USER1.to_string(),
@@ -97,7 +99,7 @@ fn mint_existing_id_fails() -> anyhow::Result<()> {
     let mint_existing_id_result = execute(
         deps.as_mut(),
         env.clone(),
-        mock_info(USER2, &vec![]),
+        mock_info(USER2, &[]),
         Mint {
             token_id: token_id.to_string(),
             owner: USER2.to_string(),
@@ -123,14 +125,14 @@ fn minting_is_permissionless() -> anyhow::Result<()> {
     instantiate(
         deps.as_mut(),
         env.clone(),
-        mock_info(ADMIN, &vec![]),
+        mock_info(ADMIN, &[]),
         anonymous_instantiate_msg(),
     )?;
 
     execute(
         deps.as_mut(),
         env.clone(),
-        mock_info("random minter", &vec![]),
+        mock_info("random minter", &[]),
         Mint {
             token_id: "1".to_string(),
             owner: "random owner".to_string(),
@@ -142,7 +144,7 @@ fn minting_is_permissionless() -> anyhow::Result<()> {
     execute(
         deps.as_mut(),
         env.clone(),
-        mock_info("another random minter", &vec![]),
+        mock_info("another random minter", &[]),
         Mint {
             token_id: "2".to_string(),
             owner: "another random owner".to_string(),
@@ -158,6 +160,188 @@ fn minting_is_permissionless() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn mint_with_artist_address() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(ADMIN, &[]),
+        anonymous_instantiate_msg(),
+    )?;
+
+    let track_metadata = TrackMetadata {
+        artist_address: Some(Addr::unchecked(USER1)),
+        ..anonymous_track_metadata()
+    };
+
+    // only the artist can claim a track as theirs
+    let result = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(USER2, &[]),
+        Mint {
+            token_id: "1".to_string(),
+            owner: USER2.to_string(),
+            token_uri: None,
+            extension: track_metadata.clone(),
+        },
+    );
+    assert_eq!(
+        result,
+        Err(StdError::generic_err(
+            "artist address can only be set by the artist minting the track"
+        )
+        .into())
+    );
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(USER1, &[]),
+        Mint {
+            token_id: "1".to_string(),
+            owner: USER2.to_string(),
+            token_uri: None,
+            extension: track_metadata.clone(),
+        },
+    )?;
+
+    let contract = Cw721TrackMetadataContract::default();
+    let nft_info = contract.nft_info(deps.as_ref(), "1".to_string())?;
+    assert_eq!(nft_info.extension, track_metadata);
+
+    Ok(())
+}
+
+#[test]
+fn mint_with_namespaced_token_id() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(ADMIN, &[]),
+        anonymous_instantiate_msg(),
+    )?;
+
+    let token_id = format!("{USER1}/1");
+
+    let result = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(USER2, &[]),
+        Mint {
+            token_id: token_id.clone(),
+            owner: USER2.to_string(),
+            token_uri: None,
+            extension: anonymous_track_metadata(),
+        },
+    );
+    assert_eq!(
+        result,
+        Err(StdError::generic_err(
+            "token IDs namespaced with an address can only be minted by that address"
+        )
+        .into())
+    );
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(USER1, &[]),
+        Mint {
+            token_id: token_id.clone(),
+            owner: USER2.to_string(),
+            token_uri: None,
+            extension: anonymous_track_metadata(),
+        },
+    )?;
+
+    let contract = Cw721TrackMetadataContract::default();
+    assert_eq!(
+        contract
+            .owner_of(deps.as_ref(), env, token_id, false)?
+            .owner,
+        USER2
+    );
+
+    Ok(())
+}
+
+#[test]
+fn mint_with_token_id_not_namespaced_with_address() -> anyhow::Result<()> {
+    // bech32 addresses, so that "label" isn't a valid address, as on chain
+    let mut deps = OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApiBech32::new("andr"),
+        querier: MockQuerier::default(),
+        custom_query_type: PhantomData::<Empty>,
+    };
+    let mut env = mock_env();
+    env.contract.address = deps.api.addr_make("contract");
+    let admin = deps.api.addr_make(ADMIN);
+    let user = deps.api.addr_make(USER1);
+    let other_user = deps.api.addr_make(USER2);
+
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(admin.as_str(), &[]),
+        anonymous_instantiate_msg(),
+    )?;
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(user.as_str(), &[]),
+        Mint {
+            token_id: "label/track1".to_string(),
+            owner: user.to_string(),
+            token_uri: None,
+            extension: anonymous_track_metadata(),
+        },
+    )?;
+
+    let contract = Cw721TrackMetadataContract::default();
+    assert_eq!(
+        contract
+            .owner_of(
+                deps.as_ref(),
+                env.clone(),
+                "label/track1".to_string(),
+                false
+            )?
+            .owner,
+        user.to_string()
+    );
+
+    // a namespace that is a valid address is still reserved to it
+    let result = execute(
+        deps.as_mut(),
+        env,
+        mock_info(user.as_str(), &[]),
+        Mint {
+            token_id: format!("{other_user}/1"),
+            owner: user.to_string(),
+            token_uri: None,
+            extension: anonymous_track_metadata(),
+        },
+    );
+    assert_eq!(
+        result,
+        Err(StdError::generic_err(
+            "token IDs namespaced with an address can only be minted by that address"
+        )
+        .into())
+    );
+
+    Ok(())
+}
+
 fn anonymous_instantiate_msg() -> InstantiateMsg {
     InstantiateMsg {
         name: "Track NFTs".to_string(),
@@ -168,6 +352,7 @@ fn anonymous_instantiate_msg() -> InstantiateMsg {
 fn anonymous_track_metadata() -> TrackMetadata {
     TrackMetadata {
         artist_name: "Boden".to_string(),
+        artist_address: None,
         album: Some(AlbumMetadata {
             name: "Presidency".to_string(),
             artwork_url: None,
@@ -175,5 +360,6 @@ fn anonymous_track_metadata() -> TrackMetadata {
         }),
         track_name: "Debt Spiral".to_string(),
         audio_track_url: "https://www.usdebtclock.org/".to_string(),
+        edition: None,
     }
 }
@@ -2,8 +2,16 @@ use cosmwasm_schema::schemars::schema_for;
 use cosmwasm_schema::{export_schema, remove_schemas};
 use std::env::current_dir;
 use std::fs::create_dir_all;
-use tracks_auction_api::api::{AuctionResponse, AuctionsResponse, ConfigResponse};
-use tracks_auction_api::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use tracks_auction_api::api::{
+    AuctionResponse, AuctionsResponse, CollectionOfferResponse, CollectionOffersResponse,
+    CollectionResponse, CollectionsResponse, ConfigResponse, CreationDepositBalanceResponse,
+    CurrentPriceResponse, DropResponse, DropsResponse, FeeInfoResponse, ListingResponse,
+    ListingsResponse, LotResponse, OfferResponse, OffersResponse, PauseStatusResponse,
+    PendingAdminResponse, RaffleResponse, RaffleTicketsResponse, RafflesResponse,
+    ReservePriceResponse, SealedBidCommitmentResponse, SealedBidResponse,
+    UnaccountedBalancesResponse,
+};
+use tracks_auction_api::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 
 fn main() {
     let mut out_dir = current_dir().unwrap();
@@ -14,8 +22,32 @@ fn main() {
     export_schema(&schema_for!(InstantiateMsg), &out_dir);
     export_schema(&schema_for!(ExecuteMsg), &out_dir);
     export_schema(&schema_for!(QueryMsg), &out_dir);
+    export_schema(&schema_for!(MigrateMsg), &out_dir);
 
     export_schema(&schema_for!(ConfigResponse), &out_dir);
+    export_schema(&schema_for!(FeeInfoResponse), &out_dir);
+    export_schema(&schema_for!(PauseStatusResponse), &out_dir);
+    export_schema(&schema_for!(CreationDepositBalanceResponse), &out_dir);
+    export_schema(&schema_for!(PendingAdminResponse), &out_dir);
+    export_schema(&schema_for!(UnaccountedBalancesResponse), &out_dir);
+    export_schema(&schema_for!(CollectionResponse), &out_dir);
+    export_schema(&schema_for!(CollectionsResponse), &out_dir);
     export_schema(&schema_for!(AuctionResponse), &out_dir);
     export_schema(&schema_for!(AuctionsResponse), &out_dir);
+    export_schema(&schema_for!(CurrentPriceResponse), &out_dir);
+    export_schema(&schema_for!(ReservePriceResponse), &out_dir);
+    export_schema(&schema_for!(SealedBidResponse), &out_dir);
+    export_schema(&schema_for!(SealedBidCommitmentResponse), &out_dir);
+    export_schema(&schema_for!(ListingResponse), &out_dir);
+    export_schema(&schema_for!(ListingsResponse), &out_dir);
+    export_schema(&schema_for!(LotResponse), &out_dir);
+    export_schema(&schema_for!(DropResponse), &out_dir);
+    export_schema(&schema_for!(DropsResponse), &out_dir);
+    export_schema(&schema_for!(RaffleResponse), &out_dir);
+    export_schema(&schema_for!(RafflesResponse), &out_dir);
+    export_schema(&schema_for!(RaffleTicketsResponse), &out_dir);
+    export_schema(&schema_for!(OfferResponse), &out_dir);
+    export_schema(&schema_for!(OffersResponse), &out_dir);
+    export_schema(&schema_for!(CollectionOfferResponse), &out_dir);
+    export_schema(&schema_for!(CollectionOffersResponse), &out_dir);
 }
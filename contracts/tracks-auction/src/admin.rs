@@ -0,0 +1,318 @@
+use crate::anti_sniping::{anti_sniping_attributes, validate_anti_sniping};
+use crate::bid_increments::{bid_increment_attributes, validate_bid_increment};
+use crate::collections::{delete_collection, load_collection, save_collection};
+use crate::config::{
+    load_config, load_paused, load_pending_admin, remove_pending_admin, save_config, save_paused,
+    save_pending_admin,
+};
+use crate::deposits::{check_creation_deposit, creation_deposit_attributes};
+use crate::fees::validate_fee_bps;
+use crate::limits::{
+    check_max_active_auctions, duration_limits_attributes, validate_duration_limits,
+};
+use crate::raffles::{randomness_beacon_attributes, validate_randomness_beacon};
+use crate::sealed_bids::{unrevealed_bid_penalty_attributes, validate_unrevealed_bid_penalty};
+use cosmwasm_std::{Api, Attribute, DepsMut, MessageInfo, Response};
+use tracks_auction_api::api::PriceAsset::{Cw20, Native};
+use tracks_auction_api::api::{
+    AntiSniping, BidIncrement, CollectionSettings, Config, CreationDeposit, DurationLimits,
+    PriceAsset, PriceAssetUnchecked, RandomnessBeacon, UnrevealedBidPenalty,
+};
+use tracks_auction_api::error::AuctionError::{
+    ContractNotPaused, ContractPaused, Cw721NotWhitelisted, NoPriceAssets, Unauthorized,
+};
+use tracks_auction_api::error::AuctionResult;
+
+/// Configuration changes requested through `UpdateConfig`. Fields that are not set are left as is.
+#[derive(Default)]
+pub struct ConfigUpdate {
+    pub price_assets: Option<Vec<PriceAssetUnchecked>>,
+    pub fee_bps: Option<u16>,
+    pub fee_collector: Option<String>,
+    pub creation_deposit: Option<CreationDeposit>,
+    pub duration_limits: Option<DurationLimits>,
+    pub max_active_auctions_per_creator: Option<u32>,
+    pub bid_increment: Option<BidIncrement>,
+    pub anti_sniping: Option<AntiSniping>,
+    pub unrevealed_bid_penalty: Option<UnrevealedBidPenalty>,
+    pub randomness_beacon: Option<RandomnessBeacon>,
+}
+
+pub fn update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    update: ConfigUpdate,
+) -> AuctionResult<Response> {
+    let config = load_config(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(Unauthorized);
+    }
+
+    let mut response = Response::new().add_attribute("action", "update_config");
+
+    let price_assets = match update.price_assets {
+        Some(assets) => {
+            let assets = check_price_assets(deps.api, assets)?;
+            for asset in &assets {
+                response = response.add_attributes(price_asset_attributes(asset));
+            }
+            assets
+        }
+        None => config.price_assets,
+    };
+
+    let fee_bps = match update.fee_bps {
+        Some(fee_bps) => {
+            let fee_bps = validate_fee_bps(fee_bps)?;
+            response = response.add_attribute("fee_bps", fee_bps.to_string());
+            fee_bps
+        }
+        None => config.fee_bps,
+    };
+
+    let fee_collector = match update.fee_collector {
+        Some(fee_collector) => {
+            let fee_collector = deps.api.addr_validate(&fee_collector)?;
+            response = response.add_attribute("fee_collector", fee_collector.to_string());
+            fee_collector
+        }
+        None => config.fee_collector,
+    };
+
+    let creation_deposit = match update.creation_deposit {
+        Some(creation_deposit) => {
+            let creation_deposit = check_creation_deposit(Some(creation_deposit));
+            response = response.add_attributes(creation_deposit_attributes(&creation_deposit));
+            creation_deposit
+        }
+        None => config.creation_deposit,
+    };
+
+    let duration_limits = match update.duration_limits {
+        Some(duration_limits) => {
+            let duration_limits = validate_duration_limits(duration_limits)?;
+            response = response.add_attributes(duration_limits_attributes(&duration_limits));
+            duration_limits
+        }
+        None => config.duration_limits,
+    };
+
+    let max_active_auctions_per_creator = match update.max_active_auctions_per_creator {
+        Some(max_active_auctions) => {
+            let max_active_auctions = check_max_active_auctions(Some(max_active_auctions));
+            response = response.add_attribute(
+                "max_active_auctions_per_creator",
+                max_active_auctions.map_or("none".to_string(), |it| it.to_string()),
+            );
+            max_active_auctions
+        }
+        None => config.max_active_auctions_per_creator,
+    };
+
+    let bid_increment = match update.bid_increment {
+        Some(bid_increment) => {
+            let bid_increment = validate_bid_increment(bid_increment)?;
+            response = response.add_attributes(bid_increment_attributes(&bid_increment));
+            bid_increment
+        }
+        None => config.bid_increment,
+    };
+
+    let anti_sniping = match update.anti_sniping {
+        Some(anti_sniping) => {
+            let anti_sniping = validate_anti_sniping(anti_sniping)?;
+            response = response.add_attributes(anti_sniping_attributes(&anti_sniping));
+            anti_sniping
+        }
+        None => config.anti_sniping,
+    };
+
+    let unrevealed_bid_penalty = match update.unrevealed_bid_penalty {
+        Some(penalty) => {
+            let penalty = validate_unrevealed_bid_penalty(penalty)?;
+            response = response.add_attributes(unrevealed_bid_penalty_attributes(&penalty));
+            penalty
+        }
+        None => config.unrevealed_bid_penalty,
+    };
+
+    let randomness_beacon = match update.randomness_beacon {
+        Some(beacon) => {
+            let beacon = validate_randomness_beacon(beacon)?;
+            response = response.add_attributes(randomness_beacon_attributes(&beacon));
+            Some(beacon)
+        }
+        None => config.randomness_beacon,
+    };
+
+    save_config(
+        deps.storage,
+        &Config {
+            price_assets,
+            fee_bps,
+            fee_collector,
+            creation_deposit,
+            duration_limits,
+            max_active_auctions_per_creator,
+            bid_increment,
+            anti_sniping,
+            unrevealed_bid_penalty,
+            randomness_beacon,
+            ..config
+        },
+    )?;
+
+    Ok(response)
+}
+
+pub fn add_collection(
+    deps: DepsMut,
+    info: MessageInfo,
+    nft_contract: String,
+    settings: Option<CollectionSettings>,
+) -> AuctionResult<Response> {
+    let config = load_config(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(Unauthorized);
+    }
+
+    let nft_addr = deps.api.addr_validate(&nft_contract)?;
+    save_collection(deps.storage, &nft_addr, &settings.unwrap_or_default())?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add_collection")
+        .add_attribute("nft_contract", nft_addr.to_string()))
+}
+
+pub fn remove_collection(
+    deps: DepsMut,
+    info: MessageInfo,
+    nft_contract: String,
+) -> AuctionResult<Response> {
+    let config = load_config(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(Unauthorized);
+    }
+
+    let nft_addr = deps.api.addr_validate(&nft_contract)?;
+
+    if load_collection(deps.storage, &nft_addr)?.is_none() {
+        return Err(Cw721NotWhitelisted);
+    }
+
+    delete_collection(deps.storage, &nft_addr);
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_collection")
+        .add_attribute("nft_contract", nft_addr.to_string()))
+}
+
+pub fn propose_new_admin(
+    deps: DepsMut,
+    info: MessageInfo,
+    new_admin: String,
+) -> AuctionResult<Response> {
+    let config = load_config(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(Unauthorized);
+    }
+
+    let new_admin = deps.api.addr_validate(&new_admin)?;
+    save_pending_admin(deps.storage, &new_admin)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose_new_admin")
+        .add_attribute("pending_admin", new_admin.to_string()))
+}
+
+pub fn accept_admin_role(deps: DepsMut, info: MessageInfo) -> AuctionResult<Response> {
+    let pending_admin = load_pending_admin(deps.storage)?;
+
+    if pending_admin.as_ref() != Some(&info.sender) {
+        return Err(Unauthorized);
+    }
+
+    let config = load_config(deps.storage)?;
+    save_config(
+        deps.storage,
+        &Config {
+            admin: info.sender.clone(),
+            ..config
+        },
+    )?;
+    remove_pending_admin(deps.storage);
+
+    Ok(Response::new()
+        .add_attribute("action", "accept_admin_role")
+        .add_attribute("admin", info.sender.to_string()))
+}
+
+pub fn pause(deps: DepsMut, info: MessageInfo) -> AuctionResult<Response> {
+    let config = load_config(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(Unauthorized);
+    }
+
+    if load_paused(deps.storage)? {
+        return Err(ContractPaused);
+    }
+
+    save_paused(deps.storage, true)?;
+
+    Ok(Response::new().add_attribute("action", "pause"))
+}
+
+pub fn unpause(deps: DepsMut, info: MessageInfo) -> AuctionResult<Response> {
+    let config = load_config(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(Unauthorized);
+    }
+
+    if !load_paused(deps.storage)? {
+        return Err(ContractNotPaused);
+    }
+
+    save_paused(deps.storage, false)?;
+
+    Ok(Response::new().add_attribute("action", "unpause"))
+}
+
+/// Validates the given price assets, removing any duplicates.
+pub fn check_price_assets(
+    api: &dyn Api,
+    price_assets: Vec<PriceAssetUnchecked>,
+) -> AuctionResult<Vec<PriceAsset>> {
+    let mut checked_assets: Vec<PriceAsset> = vec![];
+
+    for asset in price_assets {
+        let asset = asset.check(api)?;
+        if !checked_assets.contains(&asset) {
+            checked_assets.push(asset);
+        }
+    }
+
+    if checked_assets.is_empty() {
+        return Err(NoPriceAssets);
+    }
+
+    Ok(checked_assets)
+}
+
+pub fn price_asset_attributes(price_asset: &PriceAsset) -> Vec<Attribute> {
+    match price_asset {
+        Native { denom } => vec![
+            Attribute::new("price_asset", "native"),
+            Attribute::new("price_asset_denom", denom),
+        ],
+        Cw20 { contract } => vec![
+            Attribute::new("price_asset", "cw20"),
+            Attribute::new("price_asset_cw20_contract", contract.to_string()),
+        ],
+    }
+}
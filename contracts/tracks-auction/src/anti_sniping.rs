@@ -0,0 +1,38 @@
+use cosmwasm_std::Attribute;
+use tracks_auction_api::api::{AntiSniping, ExtensionWindow};
+use tracks_auction_api::error::AuctionError::InvalidAntiSniping;
+use tracks_auction_api::error::AuctionResult;
+
+pub fn validate_anti_sniping(anti_sniping: AntiSniping) -> AuctionResult<AntiSniping> {
+    let rule_valid = |rule: &Option<ExtensionWindow>| match rule {
+        Some(rule) => rule.window > 0 && rule.extension > 0,
+        None => true,
+    };
+
+    if !rule_valid(&anti_sniping.time) || !rule_valid(&anti_sniping.height) {
+        return Err(InvalidAntiSniping);
+    }
+
+    Ok(anti_sniping)
+}
+
+pub fn anti_sniping_attributes(anti_sniping: &AntiSniping) -> Vec<Attribute> {
+    let window = |rule: &Option<ExtensionWindow>| {
+        rule.as_ref()
+            .map_or("none".to_string(), |it| it.window.to_string())
+    };
+    let extension = |rule: &Option<ExtensionWindow>| {
+        rule.as_ref()
+            .map_or("none".to_string(), |it| it.extension.to_string())
+    };
+
+    vec![
+        Attribute::new("anti_sniping_time_window", window(&anti_sniping.time)),
+        Attribute::new("anti_sniping_time_extension", extension(&anti_sniping.time)),
+        Attribute::new("anti_sniping_height_window", window(&anti_sniping.height)),
+        Attribute::new(
+            "anti_sniping_height_extension",
+            extension(&anti_sniping.height),
+        ),
+    ]
+}
@@ -1,10 +1,20 @@
-use crate::config::load_config;
+use crate::escrow::{add_to_escrow, remove_from_escrow};
+use crate::lots::load_lot;
+use crate::sealed_bids::validate_sealed_bid_auction;
+use cosmwasm_schema::serde::de::DeserializeOwned;
+use cosmwasm_schema::serde::Serialize;
 use cosmwasm_std::Order::Ascending;
 use cosmwasm_std::{Addr, BlockInfo, StdError, StdResult, Storage, Uint128};
 use cw_storage_plus::{Bound, Item, Map};
-use cw_utils::Duration;
-use tracks_auction_api::api::{AuctionId, AuctionStatus, Bid, TrackAuction};
-use tracks_auction_api::error::AuctionError::AuctionIdNotFound;
+use cw_utils::{Duration, Expiration, Scheduled};
+use tracks_auction_api::api::{
+    AuctionId, AuctionKind, AuctionStatus, Bid, BidIncrement, CreationDeposit, DutchAuction,
+    ExtensionWindow, LotId, PriceAsset, PriceDecay, RelistAttempt, TrackAuction,
+    UnrevealedBidPenalty,
+};
+use tracks_auction_api::error::AuctionError::{
+    AuctionIdNotFound, InvalidDutchAuction, InvalidReservePrice,
+};
 use tracks_auction_api::error::AuctionResult;
 use AuctionStatus::{Active, Canceled, Resolved};
 
@@ -13,8 +23,21 @@ const MAX_AUCTIONS_QUERY_LIMIT: u32 = 100;
 
 const NEXT_AUCTION_ID: Item<u64> = Item::new("next_auction_id");
 
-const ACTIVE_AUCTIONS_MAP: Map<u64, TrackAuction> = Map::new("active_auctions");
-const FINISHED_AUCTIONS_MAP: Map<u64, TrackAuction> = Map::new("finished_auctions");
+const ACTIVE_AUCTIONS_NAMESPACE: &str = "active_auctions";
+const FINISHED_AUCTIONS_NAMESPACE: &str = "finished_auctions";
+
+const ACTIVE_AUCTIONS_MAP: Map<u64, TrackAuction> = Map::new(ACTIVE_AUCTIONS_NAMESPACE);
+const FINISHED_AUCTIONS_MAP: Map<u64, TrackAuction> = Map::new(FINISHED_AUCTIONS_NAMESPACE);
+
+/// Hidden reserve prices of auctions, kept apart so they are not returned with the auctions.
+const RESERVE_PRICES: Map<AuctionId, Uint128> = Map::new("reserve_prices");
+
+/// Maximums of proxy bids, kept apart like reserve prices. Only the active bid of an auction
+/// can be a proxy bid, and its bidder has escrowed the whole maximum.
+const PROXY_BID_MAXIMUMS: Map<AuctionId, Uint128> = Map::new("proxy_bid_maximums");
+
+/// Number of active auctions per creator.
+const ACTIVE_AUCTION_COUNTS: Map<&Addr, u32> = Map::new("active_auction_counts");
 
 pub struct CreateAuctionData {
     pub duration: Duration,
@@ -23,6 +46,83 @@ pub struct CreateAuctionData {
     pub track_token_id: String,
     pub minimum_bid_amount: Uint128,
     pub buyout_price: Option<Uint128>,
+    pub price_asset: PriceAsset,
+    pub fee_bps: u16,
+    pub creation_deposit: Option<CreationDeposit>,
+    pub bid_increment: BidIncrement,
+    pub anti_sniping: Option<ExtensionWindow>,
+    pub starts_at: Scheduled,
+    pub kind: AuctionKind,
+    pub unrevealed_bid_penalty: Option<UnrevealedBidPenalty>,
+    pub second_price: bool,
+    pub reserve_price: Option<Uint128>,
+    pub lot_id: Option<LotId>,
+    pub relist_attempts: Vec<RelistAttempt>,
+    pub relisted_from: Option<AuctionId>,
+}
+
+/// Checks the settings specific to the kind of auction.
+pub fn validate_auction_kind(
+    kind: AuctionKind,
+    duration: Duration,
+    minimum_bid_amount: Uint128,
+    buyout_price: Option<Uint128>,
+) -> AuctionResult<AuctionKind> {
+    match &kind {
+        AuctionKind::English => {}
+        AuctionKind::Dutch(dutch_auction) => {
+            validate_dutch_auction(dutch_auction, duration, minimum_bid_amount, buyout_price)?
+        }
+        AuctionKind::SealedBid(sealed_bid_auction) => {
+            validate_sealed_bid_auction(sealed_bid_auction, buyout_price)?
+        }
+    }
+
+    Ok(kind)
+}
+
+/// Checks that a Dutch auction's price schedule can be followed over its duration.
+/// Its price already comes down to buyers, so it can't have a buyout price.
+fn validate_dutch_auction(
+    dutch_auction: &DutchAuction,
+    duration: Duration,
+    minimum_bid_amount: Uint128,
+    buyout_price: Option<Uint128>,
+) -> AuctionResult<()> {
+    let length = match duration {
+        Duration::Time(seconds) => seconds,
+        Duration::Height(blocks) => blocks,
+    };
+    let steps_fit = match dutch_auction.decay {
+        PriceDecay::Linear => true,
+        PriceDecay::Stepwise { interval } => interval > 0 && interval <= length,
+    };
+
+    if dutch_auction.start_price <= minimum_bid_amount || !steps_fit || buyout_price.is_some() {
+        return Err(InvalidDutchAuction);
+    }
+
+    Ok(())
+}
+
+/// Checks that a reserve price can be reached by bids above the minimum, without being
+/// undercut by a buyout.
+pub fn validate_reserve_price(
+    reserve_price: Option<Uint128>,
+    kind: &AuctionKind,
+    minimum_bid_amount: Uint128,
+    buyout_price: Option<Uint128>,
+) -> AuctionResult<Option<Uint128>> {
+    if let Some(reserve_price) = reserve_price {
+        if reserve_price <= minimum_bid_amount
+            || buyout_price.is_some_and(|buyout_price| buyout_price < reserve_price)
+            || matches!(kind, AuctionKind::Dutch(_))
+        {
+            return Err(InvalidReservePrice);
+        }
+    }
+
+    Ok(reserve_price)
 }
 
 pub fn save_new_auction(
@@ -33,7 +133,22 @@ pub fn save_new_auction(
     let next_auction_id = NEXT_AUCTION_ID.may_load(storage)?.unwrap_or_default();
     NEXT_AUCTION_ID.save(storage, &(next_auction_id + 1))?;
 
-    let config = load_config(storage)?;
+    let active_auction_count = load_active_auction_count(storage, &auction_data.creator)?;
+    ACTIVE_AUCTION_COUNTS.save(storage, &auction_data.creator, &(active_auction_count + 1))?;
+
+    let ends_at = auction_end(auction_data.starts_at, auction_data.duration)?;
+
+    if let Some(reserve_price) = auction_data.reserve_price {
+        RESERVE_PRICES.save(storage, next_auction_id, &reserve_price)?;
+    }
+
+    if let Some(deposit) = &auction_data.creation_deposit {
+        add_to_escrow(
+            storage,
+            &PriceAsset::native(&deposit.amount.denom),
+            deposit.amount.amount,
+        )?;
+    }
 
     ACTIVE_AUCTIONS_MAP.save(
         storage,
@@ -47,31 +162,142 @@ pub fn save_new_auction(
             nft_contract: auction_data.nft_contract,
             track_token_id: auction_data.track_token_id,
             minimum_bid_amount: auction_data.minimum_bid_amount,
-            price_asset: config.price_asset,
+            price_asset: auction_data.price_asset,
             active_bid: None,
             buyout_price: auction_data.buyout_price,
+            fee_bps: auction_data.fee_bps,
+            creation_deposit: auction_data.creation_deposit,
+            bid_increment: auction_data.bid_increment,
+            ends_at,
+            anti_sniping: auction_data.anti_sniping,
+            starts_at: auction_data.starts_at,
+            kind: auction_data.kind,
+            unrevealed_bid_penalty: auction_data.unrevealed_bid_penalty,
+            second_price: auction_data.second_price,
+            runner_up_amount: None,
+            lot_id: auction_data.lot_id,
+            relist_attempts: auction_data.relist_attempts,
+            relisted_from: auction_data.relisted_from,
         },
     )?;
 
     Ok(next_auction_id)
 }
 
-/// Updates active bid on the given auction ID.
+pub fn load_reserve_price(
+    storage: &dyn Storage,
+    auction_id: AuctionId,
+) -> AuctionResult<Option<Uint128>> {
+    Ok(RESERVE_PRICES.may_load(storage, auction_id)?)
+}
+
+pub fn load_proxy_bid_maximum(
+    storage: &dyn Storage,
+    auction_id: AuctionId,
+) -> AuctionResult<Option<Uint128>> {
+    Ok(PROXY_BID_MAXIMUMS.may_load(storage, auction_id)?)
+}
+
+/// Sets the maximum of the auction's active bid if it's a proxy bid, or removes it otherwise.
+fn save_proxy_bid_maximum(
+    storage: &mut dyn Storage,
+    auction_id: AuctionId,
+    maximum: Option<Uint128>,
+) -> AuctionResult<()> {
+    match maximum {
+        Some(maximum) => PROXY_BID_MAXIMUMS.save(storage, auction_id, &maximum)?,
+        None => PROXY_BID_MAXIMUMS.remove(storage, auction_id),
+    }
+    Ok(())
+}
+
+/// Amount escrowed for the active bid of the auction, which is the whole maximum of a proxy bid.
+/// Returns None if the auction has no active bid.
+pub fn load_active_bid_escrow(
+    storage: &dyn Storage,
+    auction: &TrackAuction,
+) -> AuctionResult<Option<Uint128>> {
+    match &auction.active_bid {
+        Some(bid) => {
+            let maximum = load_proxy_bid_maximum(storage, auction.id)?;
+            Ok(Some(maximum.unwrap_or(bid.amount)))
+        }
+        None => Ok(None),
+    }
+}
+
+/// The winning sealed bid is paid out of its collateral, which is escrowed with the sealed bid.
+fn is_sealed_bid(auction: &TrackAuction) -> bool {
+    matches!(auction.kind, AuctionKind::SealedBid(_))
+}
+
+fn release_active_bid_escrow(
+    storage: &mut dyn Storage,
+    auction: &TrackAuction,
+) -> AuctionResult<()> {
+    if let (Some(bid), Some(escrow)) = (
+        &auction.active_bid,
+        load_active_bid_escrow(storage, auction)?,
+    ) {
+        if !is_sealed_bid(auction) {
+            remove_from_escrow(storage, &bid.asset, escrow)?;
+        }
+    }
+    Ok(())
+}
+
+/// Whether the active bid of the auction meets its reserve price, always true without one.
+/// What's escrowed for the bid counts, so a proxy bid meets the reserve price as soon as its
+/// maximum reaches it, and is sold at the reserve price when the auction is resolved.
+pub fn is_reserve_price_met(storage: &dyn Storage, auction: &TrackAuction) -> AuctionResult<bool> {
+    match load_reserve_price(storage, auction.id)? {
+        Some(reserve_price) => {
+            Ok(load_active_bid_escrow(storage, auction)?
+                .is_some_and(|escrow| escrow >= reserve_price))
+        }
+        None => Ok(true),
+    }
+}
+
+/// Calculates the end of an auction starting at the given point.
+pub fn auction_end(starts_at: Scheduled, duration: Duration) -> AuctionResult<Expiration> {
+    let ends_at = match (starts_at + duration)? {
+        Scheduled::AtHeight(height) => Expiration::AtHeight(height),
+        Scheduled::AtTime(time) => Expiration::AtTime(time),
+    };
+    Ok(ends_at)
+}
+
+/// Updates active bid on the given auction ID, making the last active bid the runner-up.
+/// The maximum is set if the new active bid is a proxy bid.
 /// Returns last active bid, or None if no previous bid on this auction existed.
 pub fn update_active_bid(
     storage: &mut dyn Storage,
     auction_id: AuctionId,
     new_active_bid: Bid,
+    proxy_maximum: Option<Uint128>,
 ) -> AuctionResult<Option<Bid>> {
     let auction = load_auction(storage, auction_id)?.ok_or(AuctionIdNotFound)?;
 
     // TODO: also store the last bid for historical reasons?
 
+    // the previous active bid is released, as it's refunded or kept escrowed by a proxy bid
+    release_active_bid_escrow(storage, &auction)?;
+    if !is_sealed_bid(&auction) {
+        add_to_escrow(
+            storage,
+            &new_active_bid.asset,
+            proxy_maximum.unwrap_or(new_active_bid.amount),
+        )?;
+    }
+    save_proxy_bid_maximum(storage, auction_id, proxy_maximum)?;
+
     ACTIVE_AUCTIONS_MAP.save(
         storage,
         auction_id,
         &TrackAuction {
             active_bid: Some(new_active_bid),
+            runner_up_amount: auction.active_bid.as_ref().map(|bid| bid.amount),
             ..auction
         },
     )?;
@@ -79,6 +305,79 @@ pub fn update_active_bid(
     Ok(auction.active_bid)
 }
 
+/// Records a bid that is lower than the active bid as the runner-up, if it's higher than
+/// the current runner-up.
+pub fn update_runner_up_amount(
+    storage: &mut dyn Storage,
+    auction_id: AuctionId,
+    amount: Uint128,
+) -> AuctionResult<()> {
+    let auction = load_auction(storage, auction_id)?.ok_or(AuctionIdNotFound)?;
+
+    if auction
+        .runner_up_amount
+        .is_some_and(|runner_up| runner_up >= amount)
+    {
+        return Ok(());
+    }
+
+    ACTIVE_AUCTIONS_MAP.save(
+        storage,
+        auction_id,
+        &TrackAuction {
+            runner_up_amount: Some(amount),
+            ..auction
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Moves the end of an active auction to the given point.
+pub fn extend_auction(
+    storage: &mut dyn Storage,
+    auction_id: AuctionId,
+    ends_at: Expiration,
+) -> AuctionResult<()> {
+    let auction = ACTIVE_AUCTIONS_MAP
+        .may_load(storage, auction_id)?
+        .ok_or(AuctionIdNotFound)?;
+
+    ACTIVE_AUCTIONS_MAP.save(storage, auction_id, &TrackAuction { ends_at, ..auction })?;
+
+    Ok(())
+}
+
+/// Changes the terms of an active auction, moving its end if the duration changed.
+pub fn update_auction_terms(
+    storage: &mut dyn Storage,
+    auction_id: AuctionId,
+    minimum_bid_amount: Uint128,
+    buyout_price: Option<Uint128>,
+    duration: Duration,
+) -> AuctionResult<TrackAuction> {
+    let auction = ACTIVE_AUCTIONS_MAP
+        .may_load(storage, auction_id)?
+        .ok_or(AuctionIdNotFound)?;
+
+    let ends_at = if duration == auction.duration {
+        auction.ends_at
+    } else {
+        auction_end(auction.starts_at, duration)?
+    };
+
+    let auction = TrackAuction {
+        minimum_bid_amount,
+        buyout_price,
+        duration,
+        ends_at,
+        ..auction
+    };
+    ACTIVE_AUCTIONS_MAP.save(storage, auction_id, &auction)?;
+
+    Ok(auction)
+}
+
 /// Move an auction from Active status to one of the final statuses.
 pub fn finish_auction(
     storage: &mut dyn Storage,
@@ -106,6 +405,23 @@ pub fn finish_auction(
         }
     }
 
+    let active_auction_count = load_active_auction_count(storage, &auction.creator)?;
+    if active_auction_count <= 1 {
+        ACTIVE_AUCTION_COUNTS.remove(storage, &auction.creator);
+    } else {
+        ACTIVE_AUCTION_COUNTS.save(storage, &auction.creator, &(active_auction_count - 1))?;
+    }
+
+    // the active bid is paid out or refunded, and the creation deposit settled
+    release_active_bid_escrow(storage, &auction)?;
+    if let Some(deposit) = &auction.creation_deposit {
+        remove_from_escrow(
+            storage,
+            &PriceAsset::native(&deposit.amount.denom),
+            deposit.amount.amount,
+        )?;
+    }
+
     FINISHED_AUCTIONS_MAP.save(
         storage,
         auction_id,
@@ -116,12 +432,24 @@ pub fn finish_auction(
     )?;
     ACTIVE_AUCTIONS_MAP.remove(storage, auction_id);
 
+    // the hidden terms are only needed while the auction is active
+    RESERVE_PRICES.remove(storage, auction_id);
+    PROXY_BID_MAXIMUMS.remove(storage, auction_id);
+
     Ok(())
 }
 
+pub fn load_active_auction_count(storage: &dyn Storage, creator: &Addr) -> AuctionResult<u32> {
+    let count = ACTIVE_AUCTION_COUNTS
+        .may_load(storage, creator)?
+        .unwrap_or_default();
+    Ok(count)
+}
+
 pub fn load_auctions(
     storage: &dyn Storage,
     active_auctions: bool,
+    nft_contract: Option<Addr>,
     start_after: Option<AuctionId>,
     limit: Option<u32>,
 ) -> AuctionResult<Vec<TrackAuction>> {
@@ -136,11 +464,45 @@ pub fn load_auctions(
         FINISHED_AUCTIONS_MAP
     };
 
-    Ok(auctions_map
-        .range(storage, start_after, None, Ascending)
-        .take(limit as usize)
-        .map(|res| res.map(|(_, auction)| auction))
-        .collect::<StdResult<Vec<TrackAuction>>>()?)
+    let mut auctions = vec![];
+    for res in auctions_map.range(storage, start_after, None, Ascending) {
+        if auctions.len() == limit as usize {
+            break;
+        }
+
+        let (_, auction) = res?;
+        let matches = match &nft_contract {
+            Some(nft_contract) => has_token_of(storage, &auction, nft_contract)?,
+            None => true,
+        };
+        if matches {
+            auctions.push(auction);
+        }
+    }
+
+    Ok(auctions)
+}
+
+/// Whether any of the tokens sold by the auction, including those of its lot, is from the NFT
+/// contract.
+fn has_token_of(
+    storage: &dyn Storage,
+    auction: &TrackAuction,
+    nft_contract: &Addr,
+) -> AuctionResult<bool> {
+    if auction.nft_contract == *nft_contract {
+        return Ok(true);
+    }
+
+    let lot = match auction.lot_id {
+        Some(lot_id) => load_lot(storage, lot_id)?,
+        None => None,
+    };
+    Ok(lot.is_some_and(|lot| {
+        lot.tokens
+            .iter()
+            .any(|token| token.nft_contract == *nft_contract)
+    }))
 }
 
 pub fn load_auction(storage: &dyn Storage, id: AuctionId) -> AuctionResult<Option<TrackAuction>> {
@@ -155,3 +517,62 @@ pub fn load_auction(storage: &dyn Storage, id: AuctionId) -> AuctionResult<Optio
 
     Ok(auction)
 }
+
+/// Rewrites all stored auctions, both active and finished, from a legacy layout to the current one.
+/// Used by storage migrations when TrackAuction changes.
+pub fn rewrite_auctions<T: Serialize + DeserializeOwned>(
+    storage: &mut dyn Storage,
+    convert: impl Fn(T) -> TrackAuction,
+) -> AuctionResult<()> {
+    for (legacy_map, auctions_map) in [
+        (
+            Map::<u64, T>::new(ACTIVE_AUCTIONS_NAMESPACE),
+            ACTIVE_AUCTIONS_MAP,
+        ),
+        (
+            Map::<u64, T>::new(FINISHED_AUCTIONS_NAMESPACE),
+            FINISHED_AUCTIONS_MAP,
+        ),
+    ] {
+        let legacy_auctions = legacy_map
+            .range(storage, None, None, Ascending)
+            .collect::<StdResult<Vec<(u64, T)>>>()?;
+
+        for (id, legacy_auction) in legacy_auctions {
+            auctions_map.save(storage, id, &convert(legacy_auction))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Recounts the active auctions of every creator from scratch.
+pub fn rebuild_active_auction_counts(storage: &mut dyn Storage) -> AuctionResult<()> {
+    let creators = ACTIVE_AUCTIONS_MAP
+        .range(storage, None, None, Ascending)
+        .map(|item| item.map(|(_, auction)| auction.creator))
+        .collect::<StdResult<Vec<Addr>>>()?;
+
+    ACTIVE_AUCTION_COUNTS.clear(storage);
+    for creator in creators {
+        let active_auction_count = load_active_auction_count(storage, &creator)?;
+        ACTIVE_AUCTION_COUNTS.save(storage, &creator, &(active_auction_count + 1))?;
+    }
+
+    Ok(())
+}
+
+/// Adds the active bids of all active auctions to the escrow totals, for storage migrated
+/// from before the totals were kept, when active bids were the only escrowed funds.
+pub fn escrow_active_bids(storage: &mut dyn Storage) -> AuctionResult<()> {
+    let active_bids = ACTIVE_AUCTIONS_MAP
+        .range(storage, None, None, Ascending)
+        .filter_map(|item| item.map(|(_, auction)| auction.active_bid).transpose())
+        .collect::<StdResult<Vec<Bid>>>()?;
+
+    for bid in active_bids {
+        add_to_escrow(storage, &bid.asset, bid.amount)?;
+    }
+
+    Ok(())
+}
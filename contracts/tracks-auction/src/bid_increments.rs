@@ -0,0 +1,30 @@
+use cosmwasm_std::Attribute;
+use tracks_auction_api::api::BidIncrement;
+use tracks_auction_api::error::AuctionError::InvalidBidIncrement;
+use tracks_auction_api::error::AuctionResult;
+
+pub fn validate_bid_increment(bid_increment: BidIncrement) -> AuctionResult<BidIncrement> {
+    let valid = match &bid_increment {
+        BidIncrement::Absolute { amount } => !amount.is_zero(),
+        BidIncrement::Percentage { bps } => *bps > 0 && *bps <= 10_000,
+    };
+
+    if !valid {
+        return Err(InvalidBidIncrement);
+    }
+
+    Ok(bid_increment)
+}
+
+pub fn bid_increment_attributes(bid_increment: &BidIncrement) -> Vec<Attribute> {
+    match bid_increment {
+        BidIncrement::Absolute { amount } => vec![
+            Attribute::new("bid_increment", "absolute"),
+            Attribute::new("bid_increment_amount", amount.to_string()),
+        ],
+        BidIncrement::Percentage { bps } => vec![
+            Attribute::new("bid_increment", "percentage"),
+            Attribute::new("bid_increment_bps", bps.to_string()),
+        ],
+    }
+}
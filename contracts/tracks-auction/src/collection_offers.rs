@@ -0,0 +1,168 @@
+use crate::escrow::{add_to_escrow, remove_from_escrow};
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{Addr, Api, BlockInfo, QuerierWrapper, StdResult, Storage, Uint128};
+use cw721::Cw721QueryMsg::NftInfo;
+use cw721::NftInfoResponse;
+use cw721_tracks_api::api::TrackMetadata;
+use cw_storage_plus::{Bound, Item, Map};
+use cw_utils::Expiration;
+use tracks_auction_api::api::{CollectionOffer, CollectionOfferId, OfferTarget, PriceAsset};
+use tracks_auction_api::error::AuctionError::CollectionOfferIdNotFound;
+use tracks_auction_api::error::AuctionResult;
+
+const DEFAULT_COLLECTION_OFFERS_QUERY_LIMIT: u32 = 20;
+const MAX_COLLECTION_OFFERS_QUERY_LIMIT: u32 = 100;
+
+const NEXT_COLLECTION_OFFER_ID: Item<u64> = Item::new("next_collection_offer_id");
+
+/// Open collection offers. Offers are removed once all their tokens are bought,
+/// or they are retracted or refunded.
+const COLLECTION_OFFERS: Map<CollectionOfferId, CollectionOffer> = Map::new("collection_offers");
+
+pub struct MakeCollectionOfferData {
+    pub offerer: Addr,
+    pub nft_contract: Addr,
+    pub target: OfferTarget,
+    pub price_per_token: Uint128,
+    pub price_asset: PriceAsset,
+    pub quantity: u32,
+    pub expires: Expiration,
+}
+
+pub fn validate_offer_target(api: &dyn Api, target: OfferTarget) -> AuctionResult<OfferTarget> {
+    match target {
+        OfferTarget::VerifiedArtist { artist_address } => Ok(OfferTarget::VerifiedArtist {
+            artist_address: api.addr_validate(&artist_address)?.to_string(),
+        }),
+        target => Ok(target),
+    }
+}
+
+/// Checks whether a token of the offer's collection matches the offer's target,
+/// querying the token's metadata from the NFT contract if the target depends on it.
+pub fn token_matches_offer(
+    querier: &QuerierWrapper,
+    offer: &CollectionOffer,
+    token_id: &str,
+) -> AuctionResult<bool> {
+    if let OfferTarget::AnyToken {} = offer.target {
+        return Ok(true);
+    }
+
+    let nft_info: NftInfoResponse<TrackMetadata> = querier.query_wasm_smart(
+        &offer.nft_contract,
+        &NftInfo {
+            token_id: token_id.to_string(),
+        },
+    )?;
+    let metadata = nft_info.extension;
+
+    Ok(match &offer.target {
+        OfferTarget::AnyToken {} => true,
+        OfferTarget::ArtistName { artist_name } => metadata.artist_name == *artist_name,
+        OfferTarget::VerifiedArtist { artist_address } => metadata
+            .artist_address
+            .is_some_and(|address| address.as_str() == artist_address),
+    })
+}
+
+pub fn save_new_collection_offer(
+    storage: &mut dyn Storage,
+    current_block: BlockInfo,
+    offer_data: MakeCollectionOfferData,
+) -> AuctionResult<CollectionOfferId> {
+    let next_offer_id = NEXT_COLLECTION_OFFER_ID
+        .may_load(storage)?
+        .unwrap_or_default();
+    NEXT_COLLECTION_OFFER_ID.save(storage, &(next_offer_id + 1))?;
+
+    let offer = CollectionOffer {
+        id: next_offer_id,
+        created_at: current_block,
+        offerer: offer_data.offerer,
+        nft_contract: offer_data.nft_contract,
+        target: offer_data.target,
+        price_per_token: offer_data.price_per_token,
+        price_asset: offer_data.price_asset,
+        remaining_quantity: offer_data.quantity,
+        expires: offer_data.expires,
+    };
+    add_to_escrow(storage, &offer.price_asset, offer.escrowed_amount())?;
+    COLLECTION_OFFERS.save(storage, next_offer_id, &offer)?;
+
+    Ok(next_offer_id)
+}
+
+pub fn load_collection_offer(
+    storage: &dyn Storage,
+    id: CollectionOfferId,
+) -> AuctionResult<Option<CollectionOffer>> {
+    Ok(COLLECTION_OFFERS.may_load(storage, id)?)
+}
+
+/// Takes one token off the offer's remaining quantity, removing the offer once none are left.
+/// Returns the remaining quantity.
+pub fn fill_collection_offer(
+    storage: &mut dyn Storage,
+    id: CollectionOfferId,
+) -> AuctionResult<u32> {
+    let offer = load_collection_offer(storage, id)?.ok_or(CollectionOfferIdNotFound)?;
+
+    let remaining_quantity = offer.remaining_quantity.saturating_sub(1);
+    if remaining_quantity == 0 {
+        remove_collection_offer(storage, id)?;
+    } else {
+        remove_from_escrow(storage, &offer.price_asset, offer.price_per_token)?;
+        COLLECTION_OFFERS.save(
+            storage,
+            id,
+            &CollectionOffer {
+                remaining_quantity,
+                ..offer
+            },
+        )?;
+    }
+
+    Ok(remaining_quantity)
+}
+
+pub fn remove_collection_offer(
+    storage: &mut dyn Storage,
+    id: CollectionOfferId,
+) -> AuctionResult<()> {
+    if let Some(offer) = load_collection_offer(storage, id)? {
+        remove_from_escrow(storage, &offer.price_asset, offer.escrowed_amount())?;
+        COLLECTION_OFFERS.remove(storage, id);
+    }
+    Ok(())
+}
+
+pub fn load_collection_offers(
+    storage: &dyn Storage,
+    nft_contract: Option<Addr>,
+    offerer: Option<Addr>,
+    start_after: Option<CollectionOfferId>,
+    limit: Option<u32>,
+) -> AuctionResult<Vec<CollectionOffer>> {
+    let start_after = start_after.map(Bound::exclusive);
+    let limit = limit
+        .unwrap_or(DEFAULT_COLLECTION_OFFERS_QUERY_LIMIT)
+        .min(MAX_COLLECTION_OFFERS_QUERY_LIMIT);
+
+    Ok(COLLECTION_OFFERS
+        .range(storage, start_after, None, Ascending)
+        .map(|res| res.map(|(_, offer)| offer))
+        .filter(|res| match res {
+            Ok(offer) => {
+                nft_contract
+                    .as_ref()
+                    .is_none_or(|nft_contract| offer.nft_contract == *nft_contract)
+                    && offerer
+                        .as_ref()
+                        .is_none_or(|offerer| offer.offerer == *offerer)
+            }
+            Err(_) => true,
+        })
+        .take(limit as usize)
+        .collect::<StdResult<Vec<CollectionOffer>>>()?)
+}
@@ -0,0 +1,54 @@
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{Addr, StdResult, Storage};
+use cw_storage_plus::{Bound, Map};
+use tracks_auction_api::api::{CollectionSettings, WhitelistedCollection};
+use tracks_auction_api::error::AuctionResult;
+
+const DEFAULT_COLLECTIONS_QUERY_LIMIT: u32 = 20;
+const MAX_COLLECTIONS_QUERY_LIMIT: u32 = 100;
+
+const WHITELISTED_COLLECTIONS: Map<&Addr, CollectionSettings> = Map::new("whitelisted_collections");
+
+pub fn save_collection(
+    storage: &mut dyn Storage,
+    nft_contract: &Addr,
+    settings: &CollectionSettings,
+) -> AuctionResult<()> {
+    WHITELISTED_COLLECTIONS.save(storage, nft_contract, settings)?;
+    Ok(())
+}
+
+pub fn delete_collection(storage: &mut dyn Storage, nft_contract: &Addr) {
+    WHITELISTED_COLLECTIONS.remove(storage, nft_contract)
+}
+
+/// Loads settings of the given collection, or None if the collection is not whitelisted.
+pub fn load_collection(
+    storage: &dyn Storage,
+    nft_contract: &Addr,
+) -> AuctionResult<Option<CollectionSettings>> {
+    let settings = WHITELISTED_COLLECTIONS.may_load(storage, nft_contract)?;
+    Ok(settings)
+}
+
+pub fn load_collections(
+    storage: &dyn Storage,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> AuctionResult<Vec<WhitelistedCollection>> {
+    let start_after = start_after.as_ref().map(Bound::exclusive);
+    let limit = limit
+        .unwrap_or(DEFAULT_COLLECTIONS_QUERY_LIMIT)
+        .min(MAX_COLLECTIONS_QUERY_LIMIT);
+
+    Ok(WHITELISTED_COLLECTIONS
+        .range(storage, start_after, None, Ascending)
+        .take(limit as usize)
+        .map(|res| {
+            res.map(|(nft_contract, settings)| WhitelistedCollection {
+                nft_contract,
+                settings,
+            })
+        })
+        .collect::<StdResult<Vec<WhitelistedCollection>>>()?)
+}
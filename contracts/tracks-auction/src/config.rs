@@ -1,10 +1,15 @@
-use cosmwasm_std::Storage;
+use cosmwasm_std::{Addr, Storage};
 use cw_storage_plus::Item;
 use tracks_auction_api::api::Config;
+use tracks_auction_api::error::AuctionError::ContractPaused;
 use tracks_auction_api::error::AuctionResult;
 
 const CONFIG: Item<Config> = Item::new("config");
 
+const PENDING_ADMIN: Item<Addr> = Item::new("pending_admin");
+
+const PAUSED: Item<bool> = Item::new("paused");
+
 pub fn load_config(storage: &dyn Storage) -> AuctionResult<Config> {
     let config = CONFIG.load(storage)?;
     Ok(config)
@@ -14,3 +19,34 @@ pub fn save_config(storage: &mut dyn Storage, config: &Config) -> AuctionResult<
     CONFIG.save(storage, config)?;
     Ok(())
 }
+
+pub fn load_pending_admin(storage: &dyn Storage) -> AuctionResult<Option<Addr>> {
+    let pending_admin = PENDING_ADMIN.may_load(storage)?;
+    Ok(pending_admin)
+}
+
+pub fn save_pending_admin(storage: &mut dyn Storage, pending_admin: &Addr) -> AuctionResult<()> {
+    PENDING_ADMIN.save(storage, pending_admin)?;
+    Ok(())
+}
+
+pub fn remove_pending_admin(storage: &mut dyn Storage) {
+    PENDING_ADMIN.remove(storage)
+}
+
+pub fn load_paused(storage: &dyn Storage) -> AuctionResult<bool> {
+    let paused = PAUSED.may_load(storage)?.unwrap_or_default();
+    Ok(paused)
+}
+
+pub fn save_paused(storage: &mut dyn Storage, paused: bool) -> AuctionResult<()> {
+    PAUSED.save(storage, &paused)?;
+    Ok(())
+}
+
+pub fn ensure_not_paused(storage: &dyn Storage) -> AuctionResult<()> {
+    if load_paused(storage)? {
+        return Err(ContractPaused);
+    }
+    Ok(())
+}
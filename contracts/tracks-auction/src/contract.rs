@@ -1,17 +1,55 @@
+use crate::admin::{
+    accept_admin_role, add_collection, check_price_assets, pause, price_asset_attributes,
+    propose_new_admin, remove_collection, unpause, update_config, ConfigUpdate,
+};
+use crate::anti_sniping::validate_anti_sniping;
+use crate::bid_increments::validate_bid_increment;
+use crate::collections::save_collection;
 use crate::config::save_config;
+use crate::deposits::{check_creation_deposit, creation_deposit_attributes};
 use crate::execute;
-use crate::execute::{bid, receive_cw20};
-use crate::query::{query_auction, query_auctions, query_config};
+use crate::execute::{
+    bid, buy, buy_tickets, cancel_raffle, commit_bid, commit_raffle_draw, create_drop, delist,
+    discard_lot, draw_raffle, fund_creation_deposit, make_collection_offer, make_offer,
+    mint_from_drop, open_lot, proxy_bid, receive_cw20, refund_expired_collection_offer,
+    refund_expired_offer, retract_collection_offer, retract_offer, reveal_bid, start_lot_auction,
+    update_price, void_raffle, withdraw_creation_deposit, AuctionTerms, CollectionOfferTerms,
+    DropTerms,
+};
+use crate::fees::validate_fee_bps;
+use crate::limits::{check_max_active_auctions, validate_duration_limits};
+use crate::migrate::migrate_storage;
+use crate::query::{
+    query_auction, query_auctions, query_collection, query_collection_offer,
+    query_collection_offers, query_collections, query_config, query_creation_deposit_balance,
+    query_current_price, query_drop, query_drops, query_fee_info, query_listing, query_listings,
+    query_lot, query_offer, query_offers_by_offerer, query_offers_by_token, query_pause_status,
+    query_pending_admin, query_raffle, query_raffle_tickets, query_raffles, query_reserve_price,
+    query_sealed_bid, query_sealed_bid_commitment, query_unaccounted_balances,
+};
+use crate::raffles::validate_randomness_beacon;
+use crate::sealed_bids::validate_unrevealed_bid_penalty;
 use cosmwasm_std::{
     entry_point, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response,
 };
-use execute::{cancel_auction, receive_nft, resolve_auction};
-use tracks_auction_api::api::{Config, PriceAsset};
+use execute::{cancel_auction, receive_nft, resolve_auction, update_auction};
+use tracks_auction_api::api::{CollectionSettings, Config};
 use tracks_auction_api::error::{AuctionError, AuctionResult};
-use tracks_auction_api::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
-use ExecuteMsg::{Bid, CancelAuction, Receive, ReceiveNft, ResolveAuction};
-use PriceAsset::{Cw20, Native};
-use QueryMsg::{Auction, Auctions};
+use tracks_auction_api::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use ExecuteMsg::{
+    AcceptAdminRole, AddCollection, Bid, Buy, BuyTickets, CancelAuction, CancelRaffle, CommitBid,
+    CommitRaffleDraw, CreateDrop, Delist, DiscardLot, DrawRaffle, FundCreationDeposit,
+    MakeCollectionOffer, MakeOffer, MintFromDrop, OpenLot, Pause, ProposeNewAdmin, ProxyBid,
+    Receive, ReceiveNft, RefundExpiredCollectionOffer, RefundExpiredOffer, RemoveCollection,
+    ResolveAuction, RetractCollectionOffer, RetractOffer, RevealBid, StartLotAuction, Unpause,
+    UpdateAuction, UpdateConfig, UpdatePrice, VoidRaffle, WithdrawCreationDeposit,
+};
+use QueryMsg::{
+    Auction, Auctions, Collection, CollectionOffer, CollectionOffers, Collections,
+    CreationDepositBalance, CurrentPrice, Drop, Drops, FeeInfo, Listing, Listings, Lot, Offer,
+    OffersByOfferer, OffersByToken, PauseStatus, PendingAdmin, Raffle, RaffleTickets, Raffles,
+    ReservePrice, SealedBidCommitment, UnaccountedBalances,
+};
 
 // Version info for migration
 const CONTRACT_NAME: &str = "tracks-auction";
@@ -21,31 +59,64 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub fn instantiate(
     deps: DepsMut,
     _env: Env,
-    _info: MessageInfo,
+    info: MessageInfo,
     msg: InstantiateMsg,
 ) -> AuctionResult<Response> {
     cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
-    let nft_addr = deps.api.addr_validate(&msg.whitelisted_nft)?;
+    let admin = match msg.admin {
+        Some(admin) => deps.api.addr_validate(&admin)?,
+        None => info.sender,
+    };
+
+    let fee_collector = match msg.fee_collector {
+        Some(fee_collector) => deps.api.addr_validate(&fee_collector)?,
+        None => admin.clone(),
+    };
 
     let config = Config {
-        whitelisted_nft: nft_addr.clone(),
-        price_asset: msg.price_asset.check(deps.api)?,
+        admin: admin.clone(),
+        price_assets: check_price_assets(deps.api, msg.price_assets)?,
+        fee_bps: validate_fee_bps(msg.fee_bps)?,
+        fee_collector,
+        creation_deposit: check_creation_deposit(msg.creation_deposit),
+        duration_limits: validate_duration_limits(msg.duration_limits.unwrap_or_default())?,
+        max_active_auctions_per_creator: check_max_active_auctions(
+            msg.max_active_auctions_per_creator,
+        ),
+        bid_increment: validate_bid_increment(msg.bid_increment.unwrap_or_default())?,
+        anti_sniping: validate_anti_sniping(msg.anti_sniping.unwrap_or_default())?,
+        unrevealed_bid_penalty: validate_unrevealed_bid_penalty(
+            msg.unrevealed_bid_penalty.unwrap_or_default(),
+        )?,
+        randomness_beacon: msg
+            .randomness_beacon
+            .map(validate_randomness_beacon)
+            .transpose()?,
     };
     save_config(deps.storage, &config)?;
 
-    let price_asset_attributes = match &config.price_asset {
-        Native { denom } => vec![("price_asset", "native"), ("price_asset_denom", denom)],
-        Cw20 { contract } => vec![
-            ("price_asset", "cw20"),
-            ("price_asset_cw20_contract", contract.as_ref()),
-        ],
-    };
-
-    Ok(Response::new()
+    let mut response = Response::new()
         .add_attribute("action", "instantiate")
-        .add_attribute("whitelisted_nft", nft_addr.to_string())
-        .add_attributes(price_asset_attributes))
+        .add_attribute("admin", admin.to_string())
+        .add_attribute("fee_bps", config.fee_bps.to_string())
+        .add_attribute("fee_collector", config.fee_collector.to_string());
+
+    for nft in msg.whitelisted_nfts {
+        let nft_addr = deps.api.addr_validate(&nft)?;
+        save_collection(deps.storage, &nft_addr, &CollectionSettings::default())?;
+        response = response.add_attribute("whitelisted_nft", nft_addr.to_string());
+    }
+
+    for asset in &config.price_assets {
+        response = response.add_attributes(price_asset_attributes(asset));
+    }
+
+    if config.creation_deposit.is_some() {
+        response = response.add_attributes(creation_deposit_attributes(&config.creation_deposit));
+    }
+
+    Ok(response)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -62,22 +133,294 @@ pub fn execute(
             auction_id,
             bid_amount,
         } => bid(deps, env, info, auction_id, bid_amount),
+        ProxyBid {
+            auction_id,
+            max_amount,
+        } => proxy_bid(deps, env, info, auction_id, max_amount),
+        CommitBid {
+            auction_id,
+            commitment,
+        } => commit_bid(deps, env, info, auction_id, commitment),
+        RevealBid {
+            auction_id,
+            amount,
+            salt,
+        } => reveal_bid(deps, env, info, auction_id, amount, salt),
+        Buy { listing_id, price } => buy(deps, info, listing_id, price),
+        UpdatePrice { listing_id, price } => update_price(deps, info, listing_id, price),
+        Delist { listing_id } => delist(deps, info, listing_id),
+        MakeOffer {
+            nft_contract,
+            token_id,
+            expires,
+        } => make_offer(deps, env, info, nft_contract, token_id, expires),
+        RetractOffer { offer_id } => retract_offer(deps, info, offer_id),
+        RefundExpiredOffer { offer_id } => refund_expired_offer(deps, env, offer_id),
+        MakeCollectionOffer {
+            nft_contract,
+            target,
+            price_per_token,
+            quantity,
+            expires,
+        } => make_collection_offer(
+            deps,
+            env,
+            info,
+            CollectionOfferTerms {
+                nft_contract,
+                target,
+                price_per_token,
+                quantity,
+                expires,
+            },
+        ),
+        RetractCollectionOffer { offer_id } => retract_collection_offer(deps, info, offer_id),
+        RefundExpiredCollectionOffer { offer_id } => {
+            refund_expired_collection_offer(deps, env, offer_id)
+        }
+        OpenLot {} => open_lot(deps, info),
+        StartLotAuction {
+            lot_id,
+            duration,
+            start,
+            minimum_bid_amount,
+            buyout_price,
+            price_asset,
+            bid_increment,
+            kind,
+            second_price,
+            reserve_price,
+            relist,
+        } => start_lot_auction(
+            deps,
+            env,
+            info,
+            lot_id,
+            AuctionTerms {
+                duration,
+                start,
+                minimum_bid_amount,
+                buyout_price,
+                price_asset,
+                bid_increment,
+                kind,
+                second_price,
+                reserve_price,
+                relist,
+            },
+        ),
+        DiscardLot { lot_id } => discard_lot(deps, info, lot_id),
+        CreateDrop {
+            nft_contract,
+            metadata,
+            price,
+            price_asset,
+            starts_at,
+            ends_at,
+            supply_cap,
+        } => create_drop(
+            deps,
+            env,
+            info,
+            DropTerms {
+                nft_contract,
+                metadata,
+                price,
+                price_asset,
+                starts_at,
+                ends_at,
+                supply_cap,
+            },
+        ),
+        MintFromDrop { drop_id } => mint_from_drop(deps, env, info, drop_id),
+        BuyTickets { raffle_id, count } => buy_tickets(deps, env, info, raffle_id, count),
+        DrawRaffle {
+            raffle_id,
+            signature,
+        } => draw_raffle(deps, env, raffle_id, signature),
+        CancelRaffle { raffle_id } => cancel_raffle(deps, info, raffle_id),
+        VoidRaffle { raffle_id } => void_raffle(deps, env, raffle_id),
+        CommitRaffleDraw {
+            raffle_id,
+            commitment,
+            signature,
+        } => commit_raffle_draw(deps, env, raffle_id, commitment, signature),
         ResolveAuction { auction_id } => resolve_auction(deps, env, info, auction_id),
         CancelAuction { auction_id } => cancel_auction(deps, env, info, auction_id),
+        UpdateAuction {
+            auction_id,
+            minimum_bid_amount,
+            buyout_price,
+            duration,
+        } => update_auction(
+            deps,
+            env,
+            info,
+            auction_id,
+            minimum_bid_amount,
+            buyout_price,
+            duration,
+        ),
+        UpdateConfig {
+            price_assets,
+            fee_bps,
+            fee_collector,
+            creation_deposit,
+            duration_limits,
+            max_active_auctions_per_creator,
+            bid_increment,
+            anti_sniping,
+            unrevealed_bid_penalty,
+            randomness_beacon,
+        } => update_config(
+            deps,
+            info,
+            ConfigUpdate {
+                price_assets,
+                fee_bps,
+                fee_collector,
+                creation_deposit,
+                duration_limits,
+                max_active_auctions_per_creator,
+                bid_increment,
+                anti_sniping,
+                unrevealed_bid_penalty,
+                randomness_beacon,
+            },
+        ),
+        FundCreationDeposit {} => fund_creation_deposit(deps, info),
+        WithdrawCreationDeposit { denom, amount } => {
+            withdraw_creation_deposit(deps, info, denom, amount)
+        }
+        AddCollection {
+            nft_contract,
+            settings,
+        } => add_collection(deps, info, nft_contract, settings),
+        RemoveCollection { nft_contract } => remove_collection(deps, info, nft_contract),
+        ProposeNewAdmin { new_admin } => propose_new_admin(deps, info, new_admin),
+        AcceptAdminRole {} => accept_admin_role(deps, info),
+        Pause {} => pause(deps, info),
+        Unpause {} => unpause(deps, info),
     }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, AuctionError> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, AuctionError> {
     let response = match msg {
         QueryMsg::Config {} => to_json_binary(&query_config(deps)?)?,
+        PendingAdmin {} => to_json_binary(&query_pending_admin(deps)?)?,
+        FeeInfo {} => to_json_binary(&query_fee_info(deps)?)?,
+        PauseStatus {} => to_json_binary(&query_pause_status(deps)?)?,
+        CreationDepositBalance { address } => {
+            to_json_binary(&query_creation_deposit_balance(deps, address)?)?
+        }
+        UnaccountedBalances {} => to_json_binary(&query_unaccounted_balances(deps, env)?)?,
+        Collection { nft_contract } => to_json_binary(&query_collection(deps, nft_contract)?)?,
+        Collections { start_after, limit } => {
+            to_json_binary(&query_collections(deps, start_after, limit)?)?
+        }
         Auction { id } => to_json_binary(&query_auction(deps, id)?)?,
+        CurrentPrice { auction_id } => {
+            to_json_binary(&query_current_price(deps, env, auction_id)?)?
+        }
+        ReservePrice { auction_id } => to_json_binary(&query_reserve_price(deps, auction_id)?)?,
+        QueryMsg::SealedBid { auction_id, bidder } => {
+            to_json_binary(&query_sealed_bid(deps, auction_id, bidder)?)?
+        }
+        SealedBidCommitment {
+            amount,
+            salt,
+            bidder,
+        } => to_json_binary(&query_sealed_bid_commitment(deps, amount, salt, bidder)?)?,
         Auctions {
             active_auctions,
+            nft_contract,
+            start_after,
+            limit,
+        } => to_json_binary(&query_auctions(
+            deps,
+            active_auctions,
+            nft_contract,
+            start_after,
+            limit,
+        )?)?,
+        Listing { id } => to_json_binary(&query_listing(deps, id)?)?,
+        Listings {
+            seller,
+            nft_contract,
+            start_after,
+            limit,
+        } => to_json_binary(&query_listings(
+            deps,
+            seller,
+            nft_contract,
+            start_after,
+            limit,
+        )?)?,
+        Offer { id } => to_json_binary(&query_offer(deps, id)?)?,
+        Lot { id } => to_json_binary(&query_lot(deps, id)?)?,
+        Drop { id } => to_json_binary(&query_drop(deps, id)?)?,
+        Drops {
+            artist,
             start_after,
             limit,
-        } => to_json_binary(&query_auctions(deps, active_auctions, start_after, limit)?)?,
+        } => to_json_binary(&query_drops(deps, artist, start_after, limit)?)?,
+        Raffle { id } => to_json_binary(&query_raffle(deps, id)?)?,
+        Raffles {
+            creator,
+            start_after,
+            limit,
+        } => to_json_binary(&query_raffles(deps, creator, start_after, limit)?)?,
+        RaffleTickets {
+            raffle_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query_raffle_tickets(deps, raffle_id, start_after, limit)?)?,
+        CollectionOffer { id } => to_json_binary(&query_collection_offer(deps, id)?)?,
+        CollectionOffers {
+            nft_contract,
+            offerer,
+            start_after,
+            limit,
+        } => to_json_binary(&query_collection_offers(
+            deps,
+            nft_contract,
+            offerer,
+            start_after,
+            limit,
+        )?)?,
+        OffersByToken {
+            nft_contract,
+            token_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query_offers_by_token(
+            deps,
+            nft_contract,
+            token_id,
+            start_after,
+            limit,
+        )?)?,
+        OffersByOfferer {
+            offerer,
+            start_after,
+            limit,
+        } => to_json_binary(&query_offers_by_offerer(deps, offerer, start_after, limit)?)?,
     };
 
     Ok(response)
 }
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> AuctionResult<Response> {
+    // refuses to migrate from a different contract or from a newer version
+    let stored_version =
+        cw2::ensure_from_older_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    migrate_storage(deps, &env, &stored_version)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", stored_version.to_string())
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
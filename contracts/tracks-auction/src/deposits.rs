@@ -0,0 +1,146 @@
+use crate::config::load_config;
+use crate::escrow::{add_to_escrow, remove_from_escrow};
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{Addr, Attribute, BankMsg, Coin, StdError, StdResult, Storage, SubMsg, Uint128};
+use cw_storage_plus::Map;
+use tracks_auction_api::api::{CreationDeposit, PriceAsset};
+use tracks_auction_api::error::AuctionError::{
+    InsufficientCreationDeposit, WithdrawingMoreThanDeposited,
+};
+use tracks_auction_api::error::AuctionResult;
+
+/// Pre-funded creation deposits, keyed by owner and denom.
+const CREATION_DEPOSIT_BALANCES: Map<(&Addr, &str), Uint128> =
+    Map::new("creation_deposit_balances");
+
+/// Treats a zero deposit the same as not requiring one.
+pub fn check_creation_deposit(deposit: Option<CreationDeposit>) -> Option<CreationDeposit> {
+    deposit.filter(|deposit| !deposit.amount.amount.is_zero())
+}
+
+pub fn load_deposit_balance(
+    storage: &dyn Storage,
+    owner: &Addr,
+    denom: &str,
+) -> AuctionResult<Uint128> {
+    let balance = CREATION_DEPOSIT_BALANCES
+        .may_load(storage, (owner, denom))?
+        .unwrap_or_default();
+    Ok(balance)
+}
+
+pub fn load_deposit_balances(storage: &dyn Storage, owner: &Addr) -> AuctionResult<Vec<Coin>> {
+    let balances = CREATION_DEPOSIT_BALANCES
+        .prefix(owner)
+        .range(storage, None, None, Ascending)
+        .map(|item| item.map(|(denom, amount)| Coin { denom, amount }))
+        .collect::<StdResult<Vec<Coin>>>()?;
+    Ok(balances)
+}
+
+pub fn add_to_deposit_balance(
+    storage: &mut dyn Storage,
+    owner: &Addr,
+    funds: &Coin,
+) -> AuctionResult<()> {
+    add_to_escrow(storage, &PriceAsset::native(&funds.denom), funds.amount)?;
+
+    let balance = load_deposit_balance(storage, owner, &funds.denom)?;
+    CREATION_DEPOSIT_BALANCES.save(
+        storage,
+        (owner, &funds.denom),
+        &balance.checked_add(funds.amount).map_err(StdError::from)?,
+    )?;
+    Ok(())
+}
+
+pub fn remove_from_deposit_balance(
+    storage: &mut dyn Storage,
+    owner: &Addr,
+    funds: &Coin,
+) -> AuctionResult<()> {
+    let balance = load_deposit_balance(storage, owner, &funds.denom)?;
+    let remaining = balance
+        .checked_sub(funds.amount)
+        .map_err(|_| WithdrawingMoreThanDeposited)?;
+    remove_from_escrow(storage, &PriceAsset::native(&funds.denom), funds.amount)?;
+
+    if remaining.is_zero() {
+        CREATION_DEPOSIT_BALANCES.remove(storage, (owner, &funds.denom));
+    } else {
+        CREATION_DEPOSIT_BALANCES.save(storage, (owner, &funds.denom), &remaining)?;
+    }
+    Ok(())
+}
+
+pub fn creation_deposit_attributes(deposit: &Option<CreationDeposit>) -> Vec<Attribute> {
+    match deposit {
+        Some(deposit) => vec![
+            Attribute::new("creation_deposit", deposit.amount.to_string()),
+            Attribute::new(
+                "creation_deposit_forfeit_unsold",
+                deposit.forfeit_unsold.to_string(),
+            ),
+        ],
+        None => vec![Attribute::new("creation_deposit", "none")],
+    }
+}
+
+/// Takes the required deposit out of the creator's pre-funded balance.
+pub fn take_creation_deposit(
+    storage: &mut dyn Storage,
+    creator: &Addr,
+    deposit: &CreationDeposit,
+) -> AuctionResult<()> {
+    let balance = load_deposit_balance(storage, creator, &deposit.amount.denom)?;
+    if balance < deposit.amount.amount {
+        return Err(InsufficientCreationDeposit {
+            required: deposit.amount.clone(),
+        });
+    }
+
+    remove_from_deposit_balance(storage, creator, &deposit.amount)
+}
+
+/// Messages and attributes settling the creation deposit of a finished auction or raffle.
+pub struct DepositSettlement {
+    pub messages: Vec<SubMsg>,
+    pub attributes: Vec<Attribute>,
+}
+
+/// Returns the creation deposit to the creator, unless the auction or raffle ended without a
+/// sale in a way that can forfeit the deposit, and its deposit is forfeited in that case.
+/// Forfeited deposits go to the fee collector.
+pub fn settle_creation_deposit(
+    storage: &dyn Storage,
+    creator: &Addr,
+    creation_deposit: &Option<CreationDeposit>,
+    forfeitable: bool,
+) -> AuctionResult<DepositSettlement> {
+    let deposit = match creation_deposit {
+        Some(deposit) => deposit,
+        None => {
+            return Ok(DepositSettlement {
+                messages: vec![],
+                attributes: vec![],
+            })
+        }
+    };
+
+    let (recipient, attribute_key) = if forfeitable && deposit.forfeit_unsold {
+        (
+            load_config(storage)?.fee_collector,
+            "creation_deposit_forfeited",
+        )
+    } else {
+        (creator.clone(), "creation_deposit_returned")
+    };
+
+    Ok(DepositSettlement {
+        messages: vec![SubMsg::new(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![deposit.amount.clone()],
+        })],
+        attributes: vec![Attribute::new(attribute_key, deposit.amount.to_string())],
+    })
+}
@@ -0,0 +1,100 @@
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{Addr, BlockInfo, StdResult, Storage, Timestamp, Uint128};
+use cw721_tracks_api::api::TrackMetadata;
+use cw_storage_plus::{Bound, Item, Map};
+use tracks_auction_api::api::{Drop, DropId, PriceAsset};
+use tracks_auction_api::error::AuctionError::DropIdNotFound;
+use tracks_auction_api::error::AuctionResult;
+
+const DEFAULT_DROPS_QUERY_LIMIT: u32 = 20;
+const MAX_DROPS_QUERY_LIMIT: u32 = 100;
+
+const NEXT_DROP_ID: Item<u64> = Item::new("next_drop_id");
+
+/// All drops, kept after they end so their sales stay queryable.
+const DROPS: Map<DropId, Drop> = Map::new("drops");
+
+pub struct CreateDropData {
+    pub artist: Addr,
+    pub nft_contract: Addr,
+    pub metadata: TrackMetadata,
+    pub price: Uint128,
+    pub price_asset: PriceAsset,
+    pub fee_bps: u16,
+    pub starts_at: Timestamp,
+    pub ends_at: Timestamp,
+    pub supply_cap: Option<u32>,
+}
+
+pub fn save_new_drop(
+    storage: &mut dyn Storage,
+    current_block: BlockInfo,
+    drop_data: CreateDropData,
+) -> AuctionResult<DropId> {
+    let next_drop_id = NEXT_DROP_ID.may_load(storage)?.unwrap_or_default();
+    NEXT_DROP_ID.save(storage, &(next_drop_id + 1))?;
+
+    DROPS.save(
+        storage,
+        next_drop_id,
+        &Drop {
+            id: next_drop_id,
+            created_at: current_block,
+            artist: drop_data.artist,
+            nft_contract: drop_data.nft_contract,
+            metadata: drop_data.metadata,
+            price: drop_data.price,
+            price_asset: drop_data.price_asset,
+            fee_bps: drop_data.fee_bps,
+            starts_at: drop_data.starts_at,
+            ends_at: drop_data.ends_at,
+            supply_cap: drop_data.supply_cap,
+            minted: 0,
+        },
+    )?;
+
+    Ok(next_drop_id)
+}
+
+pub fn load_drop(storage: &dyn Storage, id: DropId) -> AuctionResult<Option<Drop>> {
+    Ok(DROPS.may_load(storage, id)?)
+}
+
+/// Counts one more edition of the drop as sold. Returns the number of that edition.
+pub fn record_drop_sale(storage: &mut dyn Storage, id: DropId) -> AuctionResult<u32> {
+    let drop = load_drop(storage, id)?.ok_or(DropIdNotFound)?;
+
+    let edition = drop.minted + 1;
+    DROPS.save(
+        storage,
+        id,
+        &Drop {
+            minted: edition,
+            ..drop
+        },
+    )?;
+
+    Ok(edition)
+}
+
+pub fn load_drops(
+    storage: &dyn Storage,
+    artist: Option<Addr>,
+    start_after: Option<DropId>,
+    limit: Option<u32>,
+) -> AuctionResult<Vec<Drop>> {
+    let start_after = start_after.map(Bound::exclusive);
+    let limit = limit
+        .unwrap_or(DEFAULT_DROPS_QUERY_LIMIT)
+        .min(MAX_DROPS_QUERY_LIMIT);
+
+    Ok(DROPS
+        .range(storage, start_after, None, Ascending)
+        .map(|res| res.map(|(_, drop)| drop))
+        .filter(|res| match res {
+            Ok(drop) => artist.as_ref().is_none_or(|artist| drop.artist == *artist),
+            Err(_) => true,
+        })
+        .take(limit as usize)
+        .collect::<StdResult<Vec<Drop>>>()?)
+}
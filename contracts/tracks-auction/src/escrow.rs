@@ -0,0 +1,73 @@
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{StdError, StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+use tracks_auction_api::api::PriceAsset;
+use tracks_auction_api::error::AuctionResult;
+
+/// Total amount of each asset the contract holds for bidders, offerers, ticket buyers,
+/// auction and raffle creators, and depositors, keyed by the asset's string form.
+/// Updated wherever funds are escrowed or released, so that the held balances can be accounted
+/// for without iterating over every escrow.
+const ESCROW_TOTALS: Map<&str, (PriceAsset, Uint128)> = Map::new("escrow_totals");
+
+fn escrow_key(asset: &PriceAsset) -> String {
+    asset.to_asset_info().to_string()
+}
+
+pub fn load_escrow_total(storage: &dyn Storage, asset: &PriceAsset) -> AuctionResult<Uint128> {
+    let total = ESCROW_TOTALS
+        .may_load(storage, &escrow_key(asset))?
+        .map(|(_, total)| total)
+        .unwrap_or_default();
+    Ok(total)
+}
+
+/// Loads the escrow totals of all assets. Bounded by the number of assets ever escrowed,
+/// which are the accepted price assets and the creation deposit denoms.
+pub fn load_escrow_totals(storage: &dyn Storage) -> AuctionResult<Vec<(PriceAsset, Uint128)>> {
+    Ok(ESCROW_TOTALS
+        .range(storage, None, None, Ascending)
+        .map(|res| res.map(|(_, total)| total))
+        .collect::<StdResult<Vec<(PriceAsset, Uint128)>>>()?)
+}
+
+pub fn add_to_escrow(
+    storage: &mut dyn Storage,
+    asset: &PriceAsset,
+    amount: Uint128,
+) -> AuctionResult<()> {
+    if amount.is_zero() {
+        return Ok(());
+    }
+
+    let total = load_escrow_total(storage, asset)?;
+    ESCROW_TOTALS.save(
+        storage,
+        &escrow_key(asset),
+        &(
+            asset.clone(),
+            total.checked_add(amount).map_err(StdError::from)?,
+        ),
+    )?;
+    Ok(())
+}
+
+pub fn remove_from_escrow(
+    storage: &mut dyn Storage,
+    asset: &PriceAsset,
+    amount: Uint128,
+) -> AuctionResult<()> {
+    if amount.is_zero() {
+        return Ok(());
+    }
+
+    let total = load_escrow_total(storage, asset)?;
+    let remaining = total.checked_sub(amount).map_err(StdError::from)?;
+
+    if remaining.is_zero() {
+        ESCROW_TOTALS.remove(storage, &escrow_key(asset));
+    } else {
+        ESCROW_TOTALS.save(storage, &escrow_key(asset), &(asset.clone(), remaining))?;
+    }
+    Ok(())
+}
@@ -1,26 +1,87 @@
 use crate::auctions::{
-    finish_auction, load_auction, save_new_auction, update_active_bid, CreateAuctionData,
+    extend_auction, finish_auction, is_reserve_price_met, load_active_auction_count,
+    load_active_bid_escrow, load_auction, load_reserve_price, save_new_auction, update_active_bid,
+    update_auction_terms, update_runner_up_amount, validate_auction_kind, validate_reserve_price,
+    CreateAuctionData,
+};
+use crate::bid_increments::validate_bid_increment;
+use crate::collection_offers::{
+    fill_collection_offer, load_collection_offer, remove_collection_offer,
+    save_new_collection_offer, token_matches_offer, validate_offer_target, MakeCollectionOfferData,
+};
+use crate::collections::load_collection;
+use crate::config::{ensure_not_paused, load_config};
+use crate::deposits::{
+    add_to_deposit_balance, load_deposit_balance, remove_from_deposit_balance,
+    settle_creation_deposit, take_creation_deposit,
+};
+use crate::drops::{load_drop, record_drop_sale, save_new_drop, CreateDropData};
+use crate::fees::sale_payout;
+use crate::limits::{check_auction_duration, check_auction_start};
+use crate::listings::{
+    load_listing, remove_listing, save_new_listing, update_listing_price, CreateListingData,
+};
+use crate::lots::{
+    add_to_lot, load_draft_lot, load_lot, relist_lot, remove_lot, save_new_lot, start_lot,
+    validate_relist_attempts,
+};
+use crate::offers::{load_offer, remove_offer, save_new_offer, MakeOfferData};
+use crate::raffles::{
+    count_ticket_purchases, draw_commitment, draw_winning_ticket, finish_raffle,
+    load_all_ticket_purchases, load_open_raffle_count, load_raffle, load_ticket_owner,
+    save_draw_commitment, save_new_raffle, save_ticket_purchase, verify_beacon_signature,
+    verify_draw_commitment, CreateRaffleData, MAX_TICKET_PURCHASES_PER_RAFFLE,
+};
+use crate::sealed_bids::{
+    count_sealed_bids, has_sealed_bids, load_sealed_bid, remove_sealed_bid, save_sealed_bid,
+    sealed_bid_commitment, settle_sealed_bids, transfer_collateral_msg,
+    MAX_SEALED_BIDS_PER_AUCTION,
 };
-use crate::config::load_config;
 use cosmwasm_std::{
-    from_json, wasm_execute, Addr, DepsMut, Env, MessageInfo, Response, StdError, SubMsg, Uint128,
+    coin, from_json, wasm_execute, Addr, Attribute, BankMsg, BlockInfo, DepsMut, Env, HexBinary,
+    MessageInfo, Response, StdError, Storage, SubMsg, Timestamp, Uint128,
 };
 use cw20::Cw20ReceiveMsg;
 use cw721::Cw721ExecuteMsg::TransferNft;
 use cw721::Cw721ReceiveMsg;
+use cw721_tracks_api::api::TrackMetadata;
+use cw721_tracks_api::msg::ExecuteMsg as Cw721TracksExecuteMsg;
 use cw_asset::Asset;
-use cw_utils::Duration::{Height, Time};
+use cw_utils::{Duration, Expiration, Scheduled};
 use tracks_auction_api::api::AuctionStatus::{Active, Canceled, Resolved};
-use tracks_auction_api::api::{AuctionId, Bid, PriceAsset, TrackAuction};
+use tracks_auction_api::api::{
+    AuctionId, AuctionKind, Bid, BidIncrement, BuyoutPriceUpdate, CollectionOffer,
+    CollectionOfferId, DropId, ListingId, LotId, LotToken, Offer, OfferId, OfferTarget, PriceAsset,
+    PriceAssetUnchecked, RaffleId, RaffleStatus, RelistAttempt, SealedBid, TrackAuction,
+};
 use tracks_auction_api::error::AuctionError::{
-    AuctionCanceled, AuctionExpired, AuctionIdNotFound, AuctionResolved, AuctionStillInProgress,
-    BidLowerThanMinimum, BidWrongAsset, BiddingAfterAuctionEnded, Cw721NotWhitelisted,
-    InsufficientFundsForBid, InvalidAuctionDuration, NoBidFundsSupplied, Unauthorized,
-    UnnecessaryAssetsForBid,
+    AuctionCanceled, AuctionExpired, AuctionIdNotFound, AuctionNotStarted, AuctionResolved,
+    AuctionStillInProgress, AuctionUpdateNotAllowed, BidLowerThanMinimum, BidWrongAsset,
+    BiddingAfterAuctionEnded, CollectionOfferIdNotFound, CommitmentMismatch, Cw721NotWhitelisted,
+    DrawCommitmentMismatch, DropEnded, DropIdNotFound, DropNotStarted,
+    DropPriceBelowCollectionFloor, DropSoldOut, EmptyLot, InsufficientFundsForBid,
+    InsufficientFundsForOffer, InsufficientFundsForPurchase, InvalidCreationDepositFunds,
+    InvalidDropMetadata, InvalidDropPrice, InvalidDropSupplyCap, InvalidDropWindow,
+    InvalidDurationExtension, InvalidListingPrice, InvalidMaxTickets, InvalidOfferAmount,
+    InvalidOfferExpiration, InvalidOfferQuantity, InvalidRaffleEnd, InvalidTicketCount,
+    InvalidTicketPrice, ListingIdNotFound, ListingPriceBelowCollectionFloor, ListingPriceMismatch,
+    LotIdNotFound, MinimumBidBelowCollectionFloor, NoBidFundsSupplied, NoOfferFundsSupplied,
+    NoPaymentFundsSupplied, NoTicketsSold, NotEnoughTicketsLeft, NotInRevealPhase,
+    NotSealedBidAuction, OfferBelowCollectionFloor, OfferExpired, OfferIdNotFound, OfferNotExpired,
+    OfferTokenMismatch, PaymentWrongAsset, PriceAssetNotAccepted, ProxyBidsNotSupported,
+    RaffleDrawAlreadyCommitted, RaffleDrawDeadlineNotReached, RaffleDrawNotCommitted, RaffleEnded,
+    RaffleHasTickets, RaffleIdNotFound, RaffleNotOpen, RaffleStillInProgress,
+    RandomnessBeaconNotConfigured, RevealedBidExceedsCollateral, SealedBidAlreadyCommitted,
+    SealedBidAlreadyRevealed, SealedBidNotFound, SealedBidsMustBeCommitted,
+    SecondPriceNotSupported, TicketPriceBelowCollectionFloor, TokenDoesNotMatchOffer,
+    TooManyActiveAuctions, TooManySealedBids, TooManyTicketPurchases, Unauthorized,
+    UnnecessaryAssetsForBid, UnnecessaryAssetsForOffer, UnnecessaryAssetsForPayment,
 };
 use tracks_auction_api::error::AuctionResult;
 use tracks_auction_api::msg::Cw20HookMsg;
-use tracks_auction_api::msg::Cw721HookMsg::CreateAuction;
+use tracks_auction_api::msg::Cw721HookMsg::{
+    AcceptCollectionOffer, AcceptOffer, AddToLot, CreateAuction, CreateListing, CreateRaffle,
+};
 
 pub fn receive_nft(
     deps: DepsMut,
@@ -28,44 +89,450 @@ pub fn receive_nft(
     info: MessageInfo,
     msg: Cw721ReceiveMsg,
 ) -> AuctionResult<Response> {
-    let config = load_config(deps.storage)?;
-
-    // only whitelisted NFT can call this
-    if info.sender != config.whitelisted_nft {
-        return Err(Cw721NotWhitelisted);
-    }
+    // only whitelisted NFTs can call this
+    let collection_settings =
+        load_collection(deps.storage, &info.sender)?.ok_or(Cw721NotWhitelisted)?;
 
     match from_json(msg.msg) {
         Ok(CreateAuction {
             duration,
+            start,
             minimum_bid_amount,
             buyout_price,
+            price_asset,
+            bid_increment,
+            kind,
+            second_price,
+            reserve_price,
+            relist,
         }) => {
-            if duration == Time(0) || duration == Height(0) {
-                return Err(InvalidAuctionDuration);
-            }
-
             let creator = deps.api.addr_validate(&msg.sender)?;
+            let token = LotToken {
+                nft_contract: info.sender,
+                token_id: msg.token_id,
+            };
 
-            let auction_data = CreateAuctionData {
-                duration,
+            create_auction(
+                deps,
+                env,
                 creator,
+                &[token],
+                None,
+                AuctionTerms {
+                    duration,
+                    start,
+                    minimum_bid_amount,
+                    buyout_price,
+                    price_asset,
+                    bid_increment,
+                    kind,
+                    second_price,
+                    reserve_price,
+                    relist,
+                },
+            )
+        }
+        Ok(CreateListing { price, price_asset }) => {
+            ensure_not_paused(deps.storage)?;
+
+            let config = load_config(deps.storage)?;
+
+            check_listing_price(price, collection_settings.minimum_bid_floor)?;
+
+            let price_asset = price_asset.check(deps.api)?;
+            if !config.price_assets.contains(&price_asset) {
+                return Err(PriceAssetNotAccepted);
+            }
+
+            let listing_data = CreateListingData {
+                seller: deps.api.addr_validate(&msg.sender)?,
                 nft_contract: info.sender,
                 track_token_id: msg.token_id,
-                minimum_bid_amount,
-                buyout_price,
+                price,
+                price_asset,
+                fee_bps: config.fee_bps,
             };
 
-            let id = save_new_auction(deps.storage, env.block, auction_data)?;
+            let id = save_new_listing(deps.storage, env.block, listing_data)?;
+
+            Ok(Response::new()
+                .add_attribute("action", "create_listing")
+                .add_attribute("listing_id", id.to_string())
+                .add_attribute("price", price.to_string()))
+        }
+        Ok(AcceptOffer { offer_id }) => {
+            ensure_not_paused(deps.storage)?;
+
+            let offer = load_offer(deps.storage, offer_id)?.ok_or(OfferIdNotFound)?;
+
+            if offer.nft_contract != info.sender || offer.track_token_id != msg.token_id {
+                return Err(OfferTokenMismatch);
+            }
+
+            if offer.expires.is_expired(&env.block) {
+                return Err(OfferExpired);
+            }
+
+            let seller = deps.api.addr_validate(&msg.sender)?;
+            if seller == offer.offerer {
+                return Err(Unauthorized);
+            }
+
+            let send_nft_to_offerer_msg =
+                transfer_nft_msg(&offer.nft_contract, &offer.offerer, &offer.track_token_id)?;
+
+            // the fee is the one in place when the offer is accepted
+            let fee_bps = load_config(deps.storage)?.fee_bps;
+            let payout = sale_payout(
+                deps.storage,
+                &offer.price_asset,
+                offer.amount,
+                fee_bps,
+                &seller,
+            )?;
+
+            remove_offer(deps.storage, offer_id)?;
+
+            Ok(Response::new()
+                .add_attribute("action", "accept_offer")
+                .add_attribute("offer_id", offer_id.to_string())
+                .add_attribute("amount", offer.amount.to_string())
+                .add_attributes(payout.attributes)
+                .add_submessages(payout.messages)
+                .add_submessage(send_nft_to_offerer_msg))
+        }
+        Ok(AcceptCollectionOffer { offer_id }) => {
+            ensure_not_paused(deps.storage)?;
+
+            let offer =
+                load_collection_offer(deps.storage, offer_id)?.ok_or(CollectionOfferIdNotFound)?;
+
+            if offer.expires.is_expired(&env.block) {
+                return Err(OfferExpired);
+            }
+
+            let seller = deps.api.addr_validate(&msg.sender)?;
+            if seller == offer.offerer {
+                return Err(Unauthorized);
+            }
+
+            // matching is checked against the token's metadata as it is now, not when the
+            // offer was made
+            if offer.nft_contract != info.sender
+                || !token_matches_offer(&deps.querier, &offer, &msg.token_id)?
+            {
+                return Err(TokenDoesNotMatchOffer);
+            }
+
+            let send_nft_to_offerer_msg =
+                transfer_nft_msg(&offer.nft_contract, &offer.offerer, &msg.token_id)?;
+
+            let fee_bps = load_config(deps.storage)?.fee_bps;
+            let payout = sale_payout(
+                deps.storage,
+                &offer.price_asset,
+                offer.price_per_token,
+                fee_bps,
+                &seller,
+            )?;
+
+            let remaining_quantity = fill_collection_offer(deps.storage, offer_id)?;
+
+            Ok(Response::new()
+                .add_attribute("action", "accept_collection_offer")
+                .add_attribute("offer_id", offer_id.to_string())
+                .add_attribute("token_id", msg.token_id)
+                .add_attribute("price", offer.price_per_token.to_string())
+                .add_attribute("remaining_quantity", remaining_quantity.to_string())
+                .add_attributes(payout.attributes)
+                .add_submessages(payout.messages)
+                .add_submessage(send_nft_to_offerer_msg))
+        }
+        Ok(AddToLot { lot_id }) => {
+            ensure_not_paused(deps.storage)?;
+
+            let lot = load_draft_lot(deps.storage, lot_id)?;
+            if lot.creator != deps.api.addr_validate(&msg.sender)? {
+                return Err(Unauthorized);
+            }
+
+            add_to_lot(
+                deps.storage,
+                lot_id,
+                LotToken {
+                    nft_contract: info.sender.clone(),
+                    token_id: msg.token_id.clone(),
+                },
+            )?;
 
             Ok(Response::new()
-                .add_attribute("action", "create_auction")
-                .add_attribute("auction_id", id.to_string()))
+                .add_attribute("action", "add_to_lot")
+                .add_attribute("lot_id", lot_id.to_string())
+                .add_attribute("nft_contract", info.sender)
+                .add_attribute("token_id", msg.token_id))
+        }
+        Ok(CreateRaffle {
+            ticket_price,
+            price_asset,
+            ends_at,
+            max_tickets,
+        }) => {
+            let creator = deps.api.addr_validate(&msg.sender)?;
+            let token = LotToken {
+                nft_contract: info.sender,
+                token_id: msg.token_id,
+            };
+
+            create_raffle(
+                deps,
+                env,
+                creator,
+                token,
+                collection_settings.minimum_bid_floor,
+                RaffleTerms {
+                    ticket_price,
+                    price_asset,
+                    ends_at,
+                    max_tickets,
+                },
+            )
         }
         _ => Err(StdError::generic_err("unknown NFT receive hook message").into()),
     }
 }
 
+/// Terms of an auction, as received in the message creating it.
+pub struct AuctionTerms {
+    pub duration: Duration,
+    pub start: Option<Scheduled>,
+    pub minimum_bid_amount: Uint128,
+    pub buyout_price: Option<Uint128>,
+    pub price_asset: PriceAssetUnchecked,
+    pub bid_increment: Option<BidIncrement>,
+    pub kind: Option<AuctionKind>,
+    pub second_price: Option<bool>,
+    pub reserve_price: Option<Uint128>,
+    pub relist: Option<Vec<RelistAttempt>>,
+}
+
+/// Creates an auction for escrowed tokens, either a single track,
+/// or all the tokens of a lot for bundle auctions.
+fn create_auction(
+    deps: DepsMut,
+    env: Env,
+    creator: Addr,
+    tokens: &[LotToken],
+    lot_id: Option<LotId>,
+    terms: AuctionTerms,
+) -> AuctionResult<Response> {
+    ensure_not_paused(deps.storage)?;
+
+    let config = load_config(deps.storage)?;
+
+    let AuctionTerms {
+        duration,
+        start,
+        minimum_bid_amount,
+        buyout_price,
+        price_asset,
+        bid_increment,
+        kind,
+        second_price,
+        reserve_price,
+        relist,
+    } = terms;
+
+    check_auction_duration(&config.duration_limits, duration)?;
+    let starts_at = check_auction_start(start, duration, &env.block)?;
+    let kind = validate_auction_kind(
+        kind.unwrap_or(AuctionKind::English),
+        duration,
+        minimum_bid_amount,
+        buyout_price,
+    )?;
+
+    let second_price = second_price.unwrap_or_default();
+    if second_price && matches!(kind, AuctionKind::Dutch(_)) {
+        return Err(SecondPriceNotSupported);
+    }
+    let reserve_price =
+        validate_reserve_price(reserve_price, &kind, minimum_bid_amount, buyout_price)?;
+
+    let relist_attempts = relist.unwrap_or_default();
+    let lowest_minimum_bid_amount = validate_relist_attempts(
+        &config.duration_limits,
+        &relist_attempts,
+        &kind,
+        duration,
+        minimum_bid_amount,
+        buyout_price,
+    )?;
+
+    // the minimum bid of every round has to reach the floor of every collection in the auction
+    check_collection_floors(deps.storage, tokens, lowest_minimum_bid_amount)?;
+
+    let bid_increment = match bid_increment {
+        Some(bid_increment) => validate_bid_increment(bid_increment)?,
+        None => config.bid_increment,
+    };
+
+    let price_asset = price_asset.check(deps.api)?;
+    if !config.price_assets.contains(&price_asset) {
+        return Err(PriceAssetNotAccepted);
+    }
+
+    check_active_listings_cap(
+        deps.storage,
+        config.max_active_auctions_per_creator,
+        &creator,
+    )?;
+
+    if let Some(creation_deposit) = &config.creation_deposit {
+        take_creation_deposit(deps.storage, &creator, creation_deposit)?;
+    }
+
+    let first_token = tokens.first().ok_or(EmptyLot)?;
+
+    let auction_data = CreateAuctionData {
+        duration,
+        creator,
+        nft_contract: first_token.nft_contract.clone(),
+        track_token_id: first_token.token_id.clone(),
+        minimum_bid_amount,
+        buyout_price,
+        price_asset,
+        fee_bps: config.fee_bps,
+        creation_deposit: config.creation_deposit,
+        bid_increment,
+        anti_sniping: config.anti_sniping.for_duration(&duration),
+        starts_at,
+        unrevealed_bid_penalty: match kind {
+            AuctionKind::SealedBid(_) => Some(config.unrevealed_bid_penalty),
+            _ => None,
+        },
+        kind: kind.clone(),
+        second_price,
+        reserve_price,
+        lot_id,
+        relist_attempts: relist_attempts.clone(),
+        relisted_from: None,
+    };
+
+    let id = save_new_auction(deps.storage, env.block, auction_data)?;
+
+    let mut response = Response::new()
+        .add_attribute("action", "create_auction")
+        .add_attribute("auction_id", id.to_string());
+
+    if let Some(lot_id) = lot_id {
+        start_lot(deps.storage, lot_id, id)?;
+        response = response
+            .add_attribute("lot_id", lot_id.to_string())
+            .add_attribute("lot_size", tokens.len().to_string());
+    }
+
+    if start.is_some() {
+        response = response.add_attribute("starts_at", starts_at.to_string());
+    }
+
+    match kind {
+        AuctionKind::English => {}
+        AuctionKind::Dutch(dutch_auction) => {
+            response = response
+                .add_attribute("auction_kind", "dutch")
+                .add_attribute("start_price", dutch_auction.start_price.to_string());
+        }
+        AuctionKind::SealedBid(sealed_bid_auction) => {
+            response = response
+                .add_attribute("auction_kind", "sealed_bid")
+                .add_attribute(
+                    "reveal_duration",
+                    sealed_bid_auction.reveal_duration.to_string(),
+                );
+        }
+    }
+
+    if second_price {
+        response = response.add_attribute("second_price", "true");
+    }
+
+    // only disclose that there is a reserve price, not the amount
+    if reserve_price.is_some() {
+        response = response.add_attribute("has_reserve_price", "true");
+    }
+
+    if !relist_attempts.is_empty() {
+        response = response.add_attribute("relist_attempts", relist_attempts.len().to_string());
+    }
+
+    Ok(response)
+}
+
+pub fn open_lot(deps: DepsMut, info: MessageInfo) -> AuctionResult<Response> {
+    ensure_not_paused(deps.storage)?;
+
+    let id = save_new_lot(deps.storage, info.sender)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "open_lot")
+        .add_attribute("lot_id", id.to_string()))
+}
+
+pub fn start_lot_auction(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    lot_id: LotId,
+    terms: AuctionTerms,
+) -> AuctionResult<Response> {
+    let lot = load_draft_lot(deps.storage, lot_id)?;
+
+    if lot.creator != info.sender {
+        return Err(Unauthorized);
+    }
+
+    if lot.tokens.is_empty() {
+        return Err(EmptyLot);
+    }
+
+    create_auction(deps, env, lot.creator, &lot.tokens, Some(lot_id), terms)
+}
+
+pub fn discard_lot(deps: DepsMut, info: MessageInfo, lot_id: LotId) -> AuctionResult<Response> {
+    let lot = load_draft_lot(deps.storage, lot_id)?;
+
+    if lot.creator != info.sender {
+        return Err(Unauthorized);
+    }
+
+    remove_lot(deps.storage, lot_id);
+
+    let send_nfts_back_submsgs = lot
+        .tokens
+        .iter()
+        .map(|token| transfer_nft_msg(&token.nft_contract, &lot.creator, &token.token_id))
+        .collect::<AuctionResult<Vec<SubMsg>>>()?;
+
+    Ok(Response::new()
+        .add_attribute("action", "discard_lot")
+        .add_attribute("lot_id", lot_id.to_string())
+        .add_submessages(send_nfts_back_submsgs))
+}
+
+fn check_listing_price(price: Uint128, floor: Option<Uint128>) -> AuctionResult<()> {
+    if price.is_zero() {
+        return Err(InvalidListingPrice);
+    }
+
+    if let Some(floor) = floor {
+        if price < floor {
+            return Err(ListingPriceBelowCollectionFloor { floor });
+        }
+    }
+
+    Ok(())
+}
+
 pub fn receive_cw20(
     deps: DepsMut,
     env: Env,
@@ -88,7 +555,123 @@ pub fn receive_cw20(
                 auction_id,
                 bid_asset,
                 bid_received_amount,
-                bid_amount,
+                BidAmount::Exact(bid_amount),
+            )
+        }
+        Ok(Cw20HookMsg::ProxyBid {
+            auction_id,
+            max_amount,
+        }) => {
+            let bid_asset = PriceAsset::cw20(info.sender.clone());
+            let bid_received_amount = cw20_msg.amount;
+
+            let bidder = deps.api.addr_validate(&cw20_msg.sender)?;
+            resolve_bid(
+                deps,
+                env,
+                bidder,
+                auction_id,
+                bid_asset,
+                bid_received_amount,
+                BidAmount::UpTo(max_amount),
+            )
+        }
+        Ok(Cw20HookMsg::CommitBid {
+            auction_id,
+            commitment,
+        }) => {
+            let collateral_asset = PriceAsset::cw20(info.sender.clone());
+            let collateral = cw20_msg.amount;
+
+            let bidder = deps.api.addr_validate(&cw20_msg.sender)?;
+            resolve_commit_bid(
+                deps,
+                env,
+                bidder,
+                auction_id,
+                collateral_asset,
+                collateral,
+                commitment,
+            )
+        }
+        Ok(Cw20HookMsg::Buy { listing_id, price }) => {
+            let payment_asset = PriceAsset::cw20(info.sender.clone());
+            let payment_amount = cw20_msg.amount;
+
+            let buyer = deps.api.addr_validate(&cw20_msg.sender)?;
+            resolve_buy(
+                deps,
+                buyer,
+                listing_id,
+                payment_asset,
+                payment_amount,
+                price,
+            )
+        }
+        Ok(Cw20HookMsg::MakeOffer {
+            nft_contract,
+            token_id,
+            expires,
+        }) => {
+            let offer_asset = PriceAsset::cw20(info.sender.clone());
+            let offer_amount = cw20_msg.amount;
+
+            let offer_data = MakeOfferData {
+                offerer: deps.api.addr_validate(&cw20_msg.sender)?,
+                nft_contract: deps.api.addr_validate(&nft_contract)?,
+                track_token_id: token_id,
+                amount: offer_amount,
+                price_asset: offer_asset,
+                expires,
+            };
+            resolve_make_offer(deps, env, offer_data)
+        }
+        Ok(Cw20HookMsg::MakeCollectionOffer {
+            nft_contract,
+            target,
+            price_per_token,
+            quantity,
+            expires,
+        }) => {
+            let offer_asset = PriceAsset::cw20(info.sender.clone());
+            let offer_amount = cw20_msg.amount;
+
+            let offerer = deps.api.addr_validate(&cw20_msg.sender)?;
+            resolve_make_collection_offer(
+                deps,
+                env,
+                offerer,
+                offer_asset,
+                offer_amount,
+                CollectionOfferTerms {
+                    nft_contract,
+                    target,
+                    price_per_token,
+                    quantity,
+                    expires,
+                },
+            )
+        }
+        Ok(Cw20HookMsg::MintFromDrop { drop_id }) => {
+            let payment_asset = PriceAsset::cw20(info.sender.clone());
+            let payment_amount = cw20_msg.amount;
+
+            let buyer = deps.api.addr_validate(&cw20_msg.sender)?;
+            resolve_mint_from_drop(deps, env, buyer, drop_id, payment_asset, payment_amount)
+        }
+        Ok(Cw20HookMsg::BuyTickets { raffle_id, count }) => {
+            let payment_asset = PriceAsset::cw20(info.sender.clone());
+            let payment_amount = cw20_msg.amount;
+
+            let buyer = deps.api.addr_validate(&cw20_msg.sender)?;
+            resolve_buy_tickets(
+                deps,
+                env,
+                buyer,
+                raffle_id,
+                count,
+                payment_asset,
+                payment_amount,
             )
         }
         _ => Err(StdError::generic_err("unknown CW20 hook message received").into()),
@@ -118,10 +701,55 @@ pub fn bid(
         auction_id,
         bid_asset,
         bid_received_amount,
-        bid_amount,
+        BidAmount::Exact(bid_amount),
+    )
+}
+
+pub fn proxy_bid(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    auction_id: AuctionId,
+    max_amount: Uint128,
+) -> AuctionResult<Response> {
+    let bid_funds = match &info.funds[..] {
+        [coin] => coin.clone(),
+        [] => return Err(NoBidFundsSupplied),
+        _ => return Err(UnnecessaryAssetsForBid),
+    };
+
+    let bid_asset = PriceAsset::native(&bid_funds.denom);
+    let bid_received_amount = bid_funds.amount;
+
+    resolve_bid(
+        deps,
+        env,
+        info.sender,
+        auction_id,
+        bid_asset,
+        bid_received_amount,
+        BidAmount::UpTo(max_amount),
     )
 }
 
+/// Amount a bidder bids on an auction.
+#[derive(Clone, Copy)]
+enum BidAmount {
+    /// A bid of exactly this amount.
+    Exact(Uint128),
+    /// A proxy bid, which the contract places and raises on the bidder's behalf up to this maximum.
+    UpTo(Uint128),
+}
+
+impl BidAmount {
+    /// The most the bid can cost, which is what the bidder escrows.
+    fn limit(self) -> Uint128 {
+        match self {
+            BidAmount::Exact(amount) | BidAmount::UpTo(amount) => amount,
+        }
+    }
+}
+
 fn resolve_bid(
     deps: DepsMut,
     env: Env,
@@ -129,12 +757,17 @@ fn resolve_bid(
     auction_id: AuctionId,
     bid_asset: PriceAsset,
     bid_received_amount: Uint128,
-    bid_amount: Uint128,
+    bid_amount: BidAmount,
 ) -> AuctionResult<Response> {
-    // TODO: sending more funds than the bid states will pass this check. it's debatable whether that should happen
-    if bid_received_amount < bid_amount {
+    // covers both native and CW20 bids
+    ensure_not_paused(deps.storage)?;
+
+    let bid_limit = bid_amount.limit();
+    if bid_received_amount < bid_limit {
         return Err(InsufficientFundsForBid);
     }
+    // anything sent on top of the bid is returned to the bidder
+    let excess_amount = bid_received_amount - bid_limit;
 
     let auction = load_auction(deps.storage, auction_id)?.ok_or(AuctionIdNotFound)?;
 
@@ -143,71 +776,1389 @@ fn resolve_bid(
         return Err(Unauthorized);
     }
 
+    if let AuctionKind::SealedBid(_) = auction.kind {
+        return Err(SealedBidsMustBeCommitted);
+    }
+
+    // the first bid wins a Dutch auction, and second-price auctions already charge the winner
+    // no more than it takes to win, so proxy bids would have nothing to do in either
+    let dutch = matches!(auction.kind, AuctionKind::Dutch(_));
+    if matches!(bid_amount, BidAmount::UpTo(_)) && (dutch || auction.second_price) {
+        return Err(ProxyBidsNotSupported);
+    }
+
     // TODO: should we forbid bidding on top of one's own bid?
 
+    if !auction.has_started(&env.block) {
+        return Err(AuctionNotStarted {
+            starts_at: auction.starts_at,
+        });
+    }
+
     if auction.has_ended(&env.block) {
         return Err(BiddingAfterAuctionEnded);
     }
 
-    let config = load_config(deps.storage)?;
-
-    if config.price_asset != bid_asset {
+    if auction.price_asset != bid_asset {
         return Err(BidWrongAsset);
-    } else if bid_amount < auction.minimum_next_bid_amount() {
-        return Err(BidLowerThanMinimum);
     }
 
-    // if buyout price is reached, end the auction here
+    // the first bid at or above the current price wins a Dutch auction, paying that price
+    if let Some(current_price) = auction.current_price(&env.block) {
+        if bid_limit < current_price {
+            return Err(BidLowerThanMinimum {
+                minimum: current_price,
+            });
+        }
+        let excess_amount = bid_received_amount - current_price;
+        return instant_sale(
+            deps,
+            auction,
+            bidder,
+            bid_asset,
+            current_price,
+            excess_amount,
+            "dutch_auction_sale",
+        );
+    }
+
+    let minimum_bid_amount = auction.minimum_next_bid_amount()?;
+    if bid_limit < minimum_bid_amount {
+        return Err(BidLowerThanMinimum {
+            minimum: minimum_bid_amount,
+        });
+    }
+
+    // if buyout price is reached, end the auction here,
+    // where a proxy bid pays just the buyout price
     if let Some(buyout_price) = auction.buyout_price {
-        if buyout_price <= bid_amount {
-            return buyout_auction(deps, auction, bidder, bid_asset, bid_amount);
+        if buyout_price <= bid_limit {
+            let sale_amount = match bid_amount {
+                BidAmount::Exact(amount) => amount,
+                BidAmount::UpTo(_) => buyout_price,
+            };
+            return instant_sale(
+                deps,
+                auction,
+                bidder,
+                bid_asset,
+                sale_amount,
+                bid_received_amount - sale_amount,
+                "instant_buyout",
+            );
         }
     }
 
+    let excess_refund = refund_excess_msg(&bid_asset, &bidder, excess_amount)?;
+
+    // bids close to the end give other bidders time to respond
+    let extended_end = auction.extended_end(&env.block);
+
+    let active_bid = compete_bids(deps.storage, &auction, &bidder, bid_amount)?;
+
+    let action = match bid_amount {
+        BidAmount::Exact(_) => "bid",
+        BidAmount::UpTo(_) => "proxy_bid",
+    };
+    let mut response = Response::new()
+        .add_attribute("action", action)
+        .add_attribute("auction_id", auction_id.to_string());
+
+    let refund_msgs = if active_bid.bidder == bidder {
+        response = response.add_attribute("bid_amount", active_bid.amount.to_string());
+        // if there was an active bid before this, refund it
+        refund_previous_bid_msg(deps.storage, &auction)?
+    } else {
+        // the active proxy bid was raised above the new bid, which is refunded right away
+        response = response
+            .add_attribute("bid_amount", bid_limit.to_string())
+            .add_attribute("outbid_by", "proxy_bid")
+            .add_attribute("active_bid_amount", active_bid.amount.to_string());
+        vec![SubMsg::new(
+            Asset::new(bid_asset.to_asset_info(), bid_limit).transfer_msg(bidder.to_string())?,
+        )]
+    };
+
     update_active_bid(
         deps.storage,
         auction_id,
         Bid {
-            amount: bid_amount,
-            asset: config.price_asset,
+            amount: active_bid.amount,
+            asset: auction.price_asset.clone(),
+            bidder: active_bid.bidder,
+            posted_at: env.block,
+        },
+        active_bid.proxy_maximum,
+    )?;
+
+    if let Some(ends_at) = extended_end {
+        extend_auction(deps.storage, auction_id, ends_at)?;
+        response = response.add_attribute("extended_until", ends_at.to_string());
+    }
+
+    Ok(response
+        .add_attributes(excess_refund.attributes)
+        .add_submessages(refund_msgs)
+        .add_submessages(excess_refund.messages))
+}
+
+/// Active bid of an auction after a new bid competed with it.
+struct CompetedBid {
+    bidder: Addr,
+    /// Amount of the bid as shown by queries.
+    amount: Uint128,
+    /// Maximum of the bid if it's a proxy bid, which is kept hidden.
+    proxy_maximum: Option<Uint128>,
+}
+
+/// Decides which bid is active after a new bid on the auction, and for how much.
+///
+/// A proxy bid is placed at the lowest amount that beats the other bid, so that it doesn't
+/// disclose the reserve price, which its maximum only has to reach when the auction is resolved.
+/// An active proxy bid holds against new bids up to its maximum, rising to one increment above
+/// them, so between two proxy bids the higher maximum wins, and the earlier one wins a tie.
+fn compete_bids(
+    storage: &dyn Storage,
+    auction: &TrackAuction,
+    bidder: &Addr,
+    bid_amount: BidAmount,
+) -> AuctionResult<CompetedBid> {
+    let one_increment_over = |amount: Uint128| -> AuctionResult<Uint128> {
+        Ok(amount
+            .checked_add(auction.bid_increment.increment_over(amount))
+            .map_err(StdError::from)?)
+    };
+    let active_bid_escrow = load_active_bid_escrow(storage, auction)?;
+
+    // only proxy bids escrow more than their amount, so only they can hold
+    if let (Some(active_bid), Some(escrow)) = (&auction.active_bid, active_bid_escrow) {
+        if active_bid.bidder != *bidder && bid_amount.limit() <= escrow {
+            return Ok(CompetedBid {
+                bidder: active_bid.bidder.clone(),
+                amount: one_increment_over(bid_amount.limit())?.min(escrow),
+                proxy_maximum: Some(escrow),
+            });
+        }
+    }
+
+    let (amount, proxy_maximum) = match bid_amount {
+        BidAmount::Exact(amount) => (amount, None),
+        BidAmount::UpTo(maximum) => {
+            let lowest_winning_amount = match (&auction.active_bid, active_bid_escrow) {
+                // raising the maximum of one's own bid leaves its amount as it is
+                (Some(active_bid), _) if active_bid.bidder == *bidder => active_bid.amount,
+                (_, Some(escrow)) => one_increment_over(escrow)?,
+                (_, None) => auction.minimum_bid_amount,
+            };
+            (lowest_winning_amount.min(maximum), Some(maximum))
+        }
+    };
+
+    Ok(CompetedBid {
+        bidder: bidder.clone(),
+        amount,
+        proxy_maximum,
+    })
+}
+
+pub fn commit_bid(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    auction_id: AuctionId,
+    commitment: HexBinary,
+) -> AuctionResult<Response> {
+    let collateral_funds = match &info.funds[..] {
+        [coin] => coin.clone(),
+        [] => return Err(NoBidFundsSupplied),
+        _ => return Err(UnnecessaryAssetsForBid),
+    };
+
+    resolve_commit_bid(
+        deps,
+        env,
+        info.sender,
+        auction_id,
+        PriceAsset::native(&collateral_funds.denom),
+        collateral_funds.amount,
+        commitment,
+    )
+}
+
+fn resolve_commit_bid(
+    deps: DepsMut,
+    env: Env,
+    bidder: Addr,
+    auction_id: AuctionId,
+    collateral_asset: PriceAsset,
+    collateral: Uint128,
+    commitment: HexBinary,
+) -> AuctionResult<Response> {
+    // covers both native and CW20 collateral
+    ensure_not_paused(deps.storage)?;
+
+    let auction = load_auction(deps.storage, auction_id)?.ok_or(AuctionIdNotFound)?;
+
+    if bidder == auction.creator {
+        return Err(Unauthorized);
+    }
+
+    if !matches!(auction.kind, AuctionKind::SealedBid(_)) {
+        return Err(NotSealedBidAuction);
+    }
+
+    match auction.status {
+        Resolved => return Err(AuctionResolved),
+        Canceled => return Err(AuctionCanceled),
+        Active => {}
+    }
+
+    if !auction.has_started(&env.block) {
+        return Err(AuctionNotStarted {
+            starts_at: auction.starts_at,
+        });
+    }
+
+    if auction.has_ended(&env.block) {
+        return Err(BiddingAfterAuctionEnded);
+    }
+
+    if auction.price_asset != collateral_asset {
+        return Err(BidWrongAsset);
+    }
+
+    // the collateral has to be able to cover at least the minimum bid
+    if collateral < auction.minimum_bid_amount {
+        return Err(BidLowerThanMinimum {
+            minimum: auction.minimum_bid_amount,
+        });
+    }
+
+    if load_sealed_bid(deps.storage, auction_id, &bidder)?.is_some() {
+        return Err(SealedBidAlreadyCommitted);
+    }
+
+    // all collateral is settled at once when the auction finishes
+    let held_bids = count_sealed_bids(deps.storage, auction_id, MAX_SEALED_BIDS_PER_AUCTION)?;
+    if held_bids >= MAX_SEALED_BIDS_PER_AUCTION {
+        return Err(TooManySealedBids {
+            max: MAX_SEALED_BIDS_PER_AUCTION,
+        });
+    }
+
+    save_sealed_bid(
+        deps.storage,
+        &auction,
+        &SealedBid {
             bidder,
+            commitment,
+            collateral,
+            committed_at: env.block,
+            revealed: false,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "commit_bid")
+        .add_attribute("auction_id", auction_id.to_string())
+        .add_attribute("collateral", collateral.to_string()))
+}
+
+pub fn reveal_bid(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    auction_id: AuctionId,
+    amount: Uint128,
+    salt: String,
+) -> AuctionResult<Response> {
+    let auction = load_auction(deps.storage, auction_id)?.ok_or(AuctionIdNotFound)?;
+
+    if !matches!(auction.kind, AuctionKind::SealedBid(_)) {
+        return Err(NotSealedBidAuction);
+    }
+
+    match auction.status {
+        Resolved => return Err(AuctionResolved),
+        Canceled => return Err(AuctionCanceled),
+        Active => {}
+    }
+
+    if !auction.has_ended(&env.block) || auction.is_resolvable(&env.block) {
+        return Err(NotInRevealPhase);
+    }
+
+    let sealed_bid =
+        load_sealed_bid(deps.storage, auction_id, &info.sender)?.ok_or(SealedBidNotFound)?;
+
+    if sealed_bid.revealed {
+        return Err(SealedBidAlreadyRevealed);
+    }
+
+    if sealed_bid_commitment(amount, &salt, &info.sender) != sealed_bid.commitment {
+        return Err(CommitmentMismatch);
+    }
+
+    if amount > sealed_bid.collateral {
+        return Err(RevealedBidExceedsCollateral);
+    }
+
+    let response = Response::new()
+        .add_attribute("action", "reveal_bid")
+        .add_attribute("auction_id", auction_id.to_string())
+        .add_attribute("bid_amount", amount.to_string());
+
+    // ties go to the bid revealed first
+    let is_highest = match &auction.active_bid {
+        Some(bid) => amount > bid.amount,
+        None => amount >= auction.minimum_bid_amount,
+    };
+
+    if !is_highest {
+        // the highest bid below the winning one sets the price of second-price auctions
+        if amount >= auction.minimum_bid_amount {
+            update_runner_up_amount(deps.storage, auction_id, amount)?;
+        }
+
+        remove_sealed_bid(deps.storage, &auction, &info.sender)?;
+        return Ok(response
+            .add_attribute("highest_bid", "false")
+            .add_submessage(transfer_collateral_msg(
+                &auction.price_asset,
+                &info.sender,
+                sealed_bid.collateral,
+            )?));
+    }
+
+    // the previous highest bid is now outbid, so its collateral is no longer needed
+    let mut refund_previous_bid_msgs = vec![];
+    if let Some(previous_bid) = &auction.active_bid {
+        let previous_sealed_bid = load_sealed_bid(deps.storage, auction_id, &previous_bid.bidder)?
+            .ok_or(SealedBidNotFound)?;
+        remove_sealed_bid(deps.storage, &auction, &previous_bid.bidder)?;
+        refund_previous_bid_msgs.push(transfer_collateral_msg(
+            &auction.price_asset,
+            &previous_bid.bidder,
+            previous_sealed_bid.collateral,
+        )?);
+    }
+
+    save_sealed_bid(
+        deps.storage,
+        &auction,
+        &SealedBid {
+            revealed: true,
+            ..sealed_bid
+        },
+    )?;
+    update_active_bid(
+        deps.storage,
+        auction_id,
+        Bid {
+            amount,
+            asset: auction.price_asset.clone(),
+            bidder: info.sender,
             posted_at: env.block,
         },
+        None,
+    )?;
+
+    Ok(response
+        .add_attribute("highest_bid", "true")
+        .add_submessages(refund_previous_bid_msgs))
+}
+
+/// Ends the auction right away, selling the NFT to the bidder at the given amount.
+fn instant_sale(
+    deps: DepsMut,
+    auction: TrackAuction,
+    bidder: Addr,
+    bid_asset: PriceAsset,
+    bid_amount: Uint128,
+    excess_amount: Uint128,
+    action: &str,
+) -> AuctionResult<Response> {
+    let excess_refund = refund_excess_msg(&bid_asset, &bidder, excess_amount)?;
+
+    let send_nft_to_buyer_msgs = transfer_auction_nfts_msgs(deps.storage, &auction, &bidder)?;
+
+    let refund_previous_bid_msg = refund_previous_bid_msg(deps.storage, &auction)?;
+
+    let payout = sale_payout(
+        deps.storage,
+        &bid_asset,
+        bid_amount,
+        auction.fee_bps,
+        &auction.creator,
+    )?;
+
+    let deposit = settle_creation_deposit(
+        deps.storage,
+        &auction.creator,
+        &auction.creation_deposit,
+        false,
+    )?;
+
+    finish_auction(deps.storage, auction.id, Resolved)?;
+
+    Ok(Response::new()
+        .add_attribute("action", action)
+        .add_attribute("auction_id", auction.id.to_string())
+        .add_attribute("bid_amount", bid_amount.to_string())
+        .add_attributes(payout.attributes)
+        .add_attributes(deposit.attributes)
+        .add_attributes(excess_refund.attributes)
+        .add_submessages(payout.messages)
+        .add_submessages(send_nft_to_buyer_msgs)
+        .add_submessages(refund_previous_bid_msg)
+        .add_submessages(deposit.messages)
+        .add_submessages(excess_refund.messages))
+}
+
+pub fn buy(
+    deps: DepsMut,
+    info: MessageInfo,
+    listing_id: ListingId,
+    price: Uint128,
+) -> AuctionResult<Response> {
+    let payment_funds = match &info.funds[..] {
+        [coin] => coin.clone(),
+        [] => return Err(NoPaymentFundsSupplied),
+        _ => return Err(UnnecessaryAssetsForPayment),
+    };
+
+    resolve_buy(
+        deps,
+        info.sender,
+        listing_id,
+        PriceAsset::native(&payment_funds.denom),
+        payment_funds.amount,
+        price,
+    )
+}
+
+fn resolve_buy(
+    deps: DepsMut,
+    buyer: Addr,
+    listing_id: ListingId,
+    payment_asset: PriceAsset,
+    payment_amount: Uint128,
+    price: Uint128,
+) -> AuctionResult<Response> {
+    // covers both native and CW20 payments
+    ensure_not_paused(deps.storage)?;
+
+    let listing = load_listing(deps.storage, listing_id)?.ok_or(ListingIdNotFound)?;
+
+    if buyer == listing.seller {
+        return Err(Unauthorized);
+    }
+
+    if listing.price != price {
+        return Err(ListingPriceMismatch {
+            price: listing.price,
+        });
+    }
+
+    if listing.price_asset != payment_asset {
+        return Err(PaymentWrongAsset);
+    }
+
+    if payment_amount < listing.price {
+        return Err(InsufficientFundsForPurchase);
+    }
+    // anything sent on top of the price is returned to the buyer
+    let excess_refund = refund_excess_msg(&payment_asset, &buyer, payment_amount - listing.price)?;
+
+    let send_nft_to_buyer_msg =
+        transfer_nft_msg(&listing.nft_contract, buyer, &listing.track_token_id)?;
+
+    let payout = sale_payout(
+        deps.storage,
+        &payment_asset,
+        listing.price,
+        listing.fee_bps,
+        &listing.seller,
+    )?;
+
+    remove_listing(deps.storage, listing_id);
+
+    Ok(Response::new()
+        .add_attribute("action", "buy")
+        .add_attribute("listing_id", listing_id.to_string())
+        .add_attribute("price", listing.price.to_string())
+        .add_attributes(payout.attributes)
+        .add_attributes(excess_refund.attributes)
+        .add_submessages(payout.messages)
+        .add_submessage(send_nft_to_buyer_msg)
+        .add_submessages(excess_refund.messages))
+}
+
+pub fn update_price(
+    deps: DepsMut,
+    info: MessageInfo,
+    listing_id: ListingId,
+    price: Uint128,
+) -> AuctionResult<Response> {
+    let listing = load_listing(deps.storage, listing_id)?.ok_or(ListingIdNotFound)?;
+
+    if listing.seller != info.sender {
+        return Err(Unauthorized);
+    }
+
+    let floor = load_collection(deps.storage, &listing.nft_contract)?
+        .and_then(|settings| settings.minimum_bid_floor);
+    check_listing_price(price, floor)?;
+
+    update_listing_price(deps.storage, listing_id, price)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_price")
+        .add_attribute("listing_id", listing_id.to_string())
+        .add_attribute("price", price.to_string()))
+}
+
+pub fn delist(deps: DepsMut, info: MessageInfo, listing_id: ListingId) -> AuctionResult<Response> {
+    let listing = load_listing(deps.storage, listing_id)?.ok_or(ListingIdNotFound)?;
+
+    if listing.seller != info.sender {
+        return Err(Unauthorized);
+    }
+
+    remove_listing(deps.storage, listing_id);
+
+    let send_nft_back_submsg =
+        transfer_nft_msg(listing.nft_contract, listing.seller, listing.track_token_id)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "delist")
+        .add_attribute("listing_id", listing_id.to_string())
+        .add_submessage(send_nft_back_submsg))
+}
+
+pub fn make_offer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    nft_contract: String,
+    token_id: String,
+    expires: Expiration,
+) -> AuctionResult<Response> {
+    let offer_funds = match &info.funds[..] {
+        [coin] => coin.clone(),
+        [] => return Err(NoOfferFundsSupplied),
+        _ => return Err(UnnecessaryAssetsForOffer),
+    };
+
+    let offer_data = MakeOfferData {
+        offerer: info.sender,
+        nft_contract: deps.api.addr_validate(&nft_contract)?,
+        track_token_id: token_id,
+        amount: offer_funds.amount,
+        price_asset: PriceAsset::native(&offer_funds.denom),
+        expires,
+    };
+
+    resolve_make_offer(deps, env, offer_data)
+}
+
+fn resolve_make_offer(
+    deps: DepsMut,
+    env: Env,
+    offer_data: MakeOfferData,
+) -> AuctionResult<Response> {
+    // covers both native and CW20 offers
+    ensure_not_paused(deps.storage)?;
+
+    let collection_settings =
+        load_collection(deps.storage, &offer_data.nft_contract)?.ok_or(Cw721NotWhitelisted)?;
+
+    if !load_config(deps.storage)?
+        .price_assets
+        .contains(&offer_data.price_asset)
+    {
+        return Err(PriceAssetNotAccepted);
+    }
+
+    if offer_data.amount.is_zero() {
+        return Err(InvalidOfferAmount);
+    }
+
+    if let Some(floor) = collection_settings.minimum_bid_floor {
+        if offer_data.amount < floor {
+            return Err(OfferBelowCollectionFloor { floor });
+        }
+    }
+
+    if offer_data.expires.is_expired(&env.block) {
+        return Err(InvalidOfferExpiration);
+    }
+
+    let amount = offer_data.amount;
+    let id = save_new_offer(deps.storage, env.block, offer_data)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "make_offer")
+        .add_attribute("offer_id", id.to_string())
+        .add_attribute("amount", amount.to_string()))
+}
+
+pub fn retract_offer(
+    deps: DepsMut,
+    info: MessageInfo,
+    offer_id: OfferId,
+) -> AuctionResult<Response> {
+    let offer = load_offer(deps.storage, offer_id)?.ok_or(OfferIdNotFound)?;
+
+    if offer.offerer != info.sender {
+        return Err(Unauthorized);
+    }
+
+    remove_offer(deps.storage, offer_id)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "retract_offer")
+        .add_attribute("offer_id", offer_id.to_string())
+        .add_submessage(refund_offer_msg(&offer)?))
+}
+
+pub fn refund_expired_offer(deps: DepsMut, env: Env, offer_id: OfferId) -> AuctionResult<Response> {
+    let offer = load_offer(deps.storage, offer_id)?.ok_or(OfferIdNotFound)?;
+
+    if !offer.expires.is_expired(&env.block) {
+        return Err(OfferNotExpired);
+    }
+
+    remove_offer(deps.storage, offer_id)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "refund_expired_offer")
+        .add_attribute("offer_id", offer_id.to_string())
+        .add_submessage(refund_offer_msg(&offer)?))
+}
+
+/// Terms of a collection offer, as received in the message making it.
+pub struct CollectionOfferTerms {
+    pub nft_contract: String,
+    pub target: OfferTarget,
+    pub price_per_token: Uint128,
+    pub quantity: u32,
+    pub expires: Expiration,
+}
+
+pub fn make_collection_offer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    terms: CollectionOfferTerms,
+) -> AuctionResult<Response> {
+    let offer_funds = match &info.funds[..] {
+        [coin] => coin.clone(),
+        [] => return Err(NoOfferFundsSupplied),
+        _ => return Err(UnnecessaryAssetsForOffer),
+    };
+
+    resolve_make_collection_offer(
+        deps,
+        env,
+        info.sender,
+        PriceAsset::native(&offer_funds.denom),
+        offer_funds.amount,
+        terms,
+    )
+}
+
+fn resolve_make_collection_offer(
+    deps: DepsMut,
+    env: Env,
+    offerer: Addr,
+    offer_asset: PriceAsset,
+    received_amount: Uint128,
+    terms: CollectionOfferTerms,
+) -> AuctionResult<Response> {
+    // covers both native and CW20 offers
+    ensure_not_paused(deps.storage)?;
+
+    let offer_data = MakeCollectionOfferData {
+        offerer,
+        nft_contract: deps.api.addr_validate(&terms.nft_contract)?,
+        target: validate_offer_target(deps.api, terms.target)?,
+        price_per_token: terms.price_per_token,
+        price_asset: offer_asset,
+        quantity: terms.quantity,
+        expires: terms.expires,
+    };
+
+    let collection_settings =
+        load_collection(deps.storage, &offer_data.nft_contract)?.ok_or(Cw721NotWhitelisted)?;
+
+    if !load_config(deps.storage)?
+        .price_assets
+        .contains(&offer_data.price_asset)
+    {
+        return Err(PriceAssetNotAccepted);
+    }
+
+    if offer_data.price_per_token.is_zero() {
+        return Err(InvalidOfferAmount);
+    }
+
+    if offer_data.quantity == 0 {
+        return Err(InvalidOfferQuantity);
+    }
+
+    if let Some(floor) = collection_settings.minimum_bid_floor {
+        if offer_data.price_per_token < floor {
+            return Err(OfferBelowCollectionFloor { floor });
+        }
+    }
+
+    if offer_data.expires.is_expired(&env.block) {
+        return Err(InvalidOfferExpiration);
+    }
+
+    let total_price = offer_data
+        .price_per_token
+        .checked_mul(offer_data.quantity.into())
+        .map_err(StdError::from)?;
+    if received_amount < total_price {
+        return Err(InsufficientFundsForOffer);
+    }
+    // anything sent on top of the total price is returned to the offerer
+    let excess_refund = refund_excess_msg(
+        &offer_data.price_asset,
+        &offer_data.offerer,
+        received_amount - total_price,
+    )?;
+
+    let price_per_token = offer_data.price_per_token;
+    let quantity = offer_data.quantity;
+    let id = save_new_collection_offer(deps.storage, env.block, offer_data)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "make_collection_offer")
+        .add_attribute("offer_id", id.to_string())
+        .add_attribute("price_per_token", price_per_token.to_string())
+        .add_attribute("quantity", quantity.to_string())
+        .add_attributes(excess_refund.attributes)
+        .add_submessages(excess_refund.messages))
+}
+
+pub fn retract_collection_offer(
+    deps: DepsMut,
+    info: MessageInfo,
+    offer_id: CollectionOfferId,
+) -> AuctionResult<Response> {
+    let offer = load_collection_offer(deps.storage, offer_id)?.ok_or(CollectionOfferIdNotFound)?;
+
+    if offer.offerer != info.sender {
+        return Err(Unauthorized);
+    }
+
+    remove_collection_offer(deps.storage, offer_id)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "retract_collection_offer")
+        .add_attribute("offer_id", offer_id.to_string())
+        .add_submessage(refund_collection_offer_msg(&offer)?))
+}
+
+pub fn refund_expired_collection_offer(
+    deps: DepsMut,
+    env: Env,
+    offer_id: CollectionOfferId,
+) -> AuctionResult<Response> {
+    let offer = load_collection_offer(deps.storage, offer_id)?.ok_or(CollectionOfferIdNotFound)?;
+
+    if !offer.expires.is_expired(&env.block) {
+        return Err(OfferNotExpired);
+    }
+
+    remove_collection_offer(deps.storage, offer_id)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "refund_expired_collection_offer")
+        .add_attribute("offer_id", offer_id.to_string())
+        .add_submessage(refund_collection_offer_msg(&offer)?))
+}
+
+fn refund_collection_offer_msg(offer: &CollectionOffer) -> AuctionResult<SubMsg> {
+    Ok(SubMsg::new(
+        Asset::new(offer.price_asset.to_asset_info(), offer.escrowed_amount())
+            .transfer_msg(offer.offerer.to_string())?,
+    ))
+}
+
+fn refund_offer_msg(offer: &Offer) -> AuctionResult<SubMsg> {
+    Ok(SubMsg::new(
+        Asset::new(offer.price_asset.to_asset_info(), offer.amount)
+            .transfer_msg(offer.offerer.to_string())?,
+    ))
+}
+
+/// Terms of a drop, as received in the message creating it.
+pub struct DropTerms {
+    pub nft_contract: String,
+    pub metadata: TrackMetadata,
+    pub price: Uint128,
+    pub price_asset: PriceAssetUnchecked,
+    pub starts_at: Timestamp,
+    pub ends_at: Timestamp,
+    pub supply_cap: Option<u32>,
+}
+
+pub fn create_drop(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    terms: DropTerms,
+) -> AuctionResult<Response> {
+    ensure_not_paused(deps.storage)?;
+
+    let nft_contract = deps.api.addr_validate(&terms.nft_contract)?;
+    let collection_settings =
+        load_collection(deps.storage, &nft_contract)?.ok_or(Cw721NotWhitelisted)?;
+
+    if terms.price.is_zero() {
+        return Err(InvalidDropPrice);
+    }
+
+    if let Some(floor) = collection_settings.minimum_bid_floor {
+        if terms.price < floor {
+            return Err(DropPriceBelowCollectionFloor { floor });
+        }
+    }
+
+    if terms.ends_at <= terms.starts_at || terms.ends_at <= env.block.time {
+        return Err(InvalidDropWindow);
+    }
+
+    if terms.supply_cap == Some(0) {
+        return Err(InvalidDropSupplyCap);
+    }
+
+    // the marketplace mints the editions, so it can't be attributed to another artist address,
+    // and edition numbers are assigned on each purchase
+    if terms.metadata.artist_address.is_some() || terms.metadata.edition.is_some() {
+        return Err(InvalidDropMetadata);
+    }
+
+    let config = load_config(deps.storage)?;
+
+    let price_asset = terms.price_asset.check(deps.api)?;
+    if !config.price_assets.contains(&price_asset) {
+        return Err(PriceAssetNotAccepted);
+    }
+
+    let drop_id = save_new_drop(
+        deps.storage,
+        env.block,
+        CreateDropData {
+            artist: info.sender,
+            nft_contract,
+            metadata: terms.metadata,
+            price: terms.price,
+            price_asset,
+            fee_bps: config.fee_bps,
+            starts_at: terms.starts_at,
+            ends_at: terms.ends_at,
+            supply_cap: terms.supply_cap,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "create_drop")
+        .add_attribute("drop_id", drop_id.to_string()))
+}
+
+pub fn mint_from_drop(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    drop_id: DropId,
+) -> AuctionResult<Response> {
+    let payment_funds = match &info.funds[..] {
+        [coin] => coin.clone(),
+        [] => return Err(NoPaymentFundsSupplied),
+        _ => return Err(UnnecessaryAssetsForPayment),
+    };
+
+    resolve_mint_from_drop(
+        deps,
+        env,
+        info.sender,
+        drop_id,
+        PriceAsset::native(&payment_funds.denom),
+        payment_funds.amount,
+    )
+}
+
+fn resolve_mint_from_drop(
+    deps: DepsMut,
+    env: Env,
+    buyer: Addr,
+    drop_id: DropId,
+    payment_asset: PriceAsset,
+    payment_amount: Uint128,
+) -> AuctionResult<Response> {
+    // covers both native and CW20 payments
+    ensure_not_paused(deps.storage)?;
+
+    let drop = load_drop(deps.storage, drop_id)?.ok_or(DropIdNotFound)?;
+
+    if env.block.time < drop.starts_at {
+        return Err(DropNotStarted);
+    }
+
+    if env.block.time >= drop.ends_at {
+        return Err(DropEnded);
+    }
+
+    if drop.remaining_supply() == Some(0) {
+        return Err(DropSoldOut);
+    }
+
+    if drop.price_asset != payment_asset {
+        return Err(PaymentWrongAsset);
+    }
+
+    if payment_amount < drop.price {
+        return Err(InsufficientFundsForPurchase);
+    }
+    // anything sent on top of the price is returned to the buyer
+    let excess_refund = refund_excess_msg(&payment_asset, &buyer, payment_amount - drop.price)?;
+
+    let edition = record_drop_sale(deps.storage, drop_id)?;
+
+    // namespaced with the marketplace's address, which the NFT contract reserves for it
+    let token_id = format!("{}/drop-{}-{}", env.contract.address, drop_id, edition);
+
+    let mint_msg = SubMsg::new(wasm_execute(
+        &drop.nft_contract,
+        &Cw721TracksExecuteMsg::Mint {
+            token_id: token_id.clone(),
+            owner: buyer.to_string(),
+            token_uri: None,
+            extension: TrackMetadata {
+                edition: Some(edition),
+                ..drop.metadata
+            },
+        },
+        vec![],
+    )?);
+
+    let payout = sale_payout(
+        deps.storage,
+        &payment_asset,
+        drop.price,
+        drop.fee_bps,
+        &drop.artist,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "mint_from_drop")
+        .add_attribute("drop_id", drop_id.to_string())
+        .add_attribute("edition", edition.to_string())
+        .add_attribute("token_id", token_id)
+        .add_attribute("price", drop.price.to_string())
+        .add_attributes(payout.attributes)
+        .add_attributes(excess_refund.attributes)
+        .add_submessages(payout.messages)
+        .add_submessage(mint_msg)
+        .add_submessages(excess_refund.messages))
+}
+
+/// Terms of a raffle, as received in the message creating it.
+pub struct RaffleTerms {
+    pub ticket_price: Uint128,
+    pub price_asset: PriceAssetUnchecked,
+    pub ends_at: Timestamp,
+    pub max_tickets: Option<u32>,
+}
+
+fn create_raffle(
+    deps: DepsMut,
+    env: Env,
+    creator: Addr,
+    token: LotToken,
+    minimum_bid_floor: Option<Uint128>,
+    terms: RaffleTerms,
+) -> AuctionResult<Response> {
+    ensure_not_paused(deps.storage)?;
+
+    let config = load_config(deps.storage)?;
+    let beacon = config
+        .randomness_beacon
+        .ok_or(RandomnessBeaconNotConfigured)?;
+
+    if terms.ticket_price.is_zero() {
+        return Err(InvalidTicketPrice);
+    }
+
+    if let Some(floor) = minimum_bid_floor {
+        if terms.ticket_price < floor {
+            return Err(TicketPriceBelowCollectionFloor { floor });
+        }
+    }
+
+    if terms.ends_at <= env.block.time {
+        return Err(InvalidRaffleEnd);
+    }
+
+    if terms.max_tickets == Some(0) {
+        return Err(InvalidMaxTickets);
+    }
+
+    let price_asset = terms.price_asset.check(deps.api)?;
+    if !config.price_assets.contains(&price_asset) {
+        return Err(PriceAssetNotAccepted);
+    }
+
+    check_active_listings_cap(
+        deps.storage,
+        config.max_active_auctions_per_creator,
+        &creator,
+    )?;
+
+    if let Some(creation_deposit) = &config.creation_deposit {
+        take_creation_deposit(deps.storage, &creator, creation_deposit)?;
+    }
+
+    let raffle_id = save_new_raffle(
+        deps.storage,
+        env.block,
+        CreateRaffleData {
+            creator,
+            nft_contract: token.nft_contract,
+            track_token_id: token.token_id,
+            ticket_price: terms.ticket_price,
+            price_asset,
+            fee_bps: config.fee_bps,
+            creation_deposit: config.creation_deposit,
+            ends_at: terms.ends_at,
+            max_tickets: terms.max_tickets,
+            beacon,
+        },
     )?;
+    let raffle = load_raffle(deps.storage, raffle_id)?.ok_or(RaffleIdNotFound)?;
 
     Ok(Response::new()
-        .add_attribute("action", "bid")
-        .add_attribute("auction_id", auction_id.to_string())
-        .add_attribute("bid_amount", bid_amount.to_string())
-        // if there was an active bid before this, refund it
-        .add_submessages(refund_previous_bid_msg(&auction)?))
+        .add_attribute("action", "create_raffle")
+        .add_attribute("raffle_id", raffle_id.to_string())
+        .add_attribute("draw_round", raffle.draw_round.to_string()))
 }
 
-fn buyout_auction(
+pub fn commit_raffle_draw(
     deps: DepsMut,
-    auction: TrackAuction,
-    bidder: Addr,
-    bid_asset: PriceAsset,
-    bid_amount: Uint128,
+    env: Env,
+    raffle_id: RaffleId,
+    commitment: HexBinary,
+    signature: HexBinary,
 ) -> AuctionResult<Response> {
-    let send_nft_to_buyer_msg =
-        transfer_nft_msg(&auction.nft_contract, bidder, &auction.track_token_id)?;
+    let raffle = load_raffle(deps.storage, raffle_id)?.ok_or(RaffleIdNotFound)?;
 
-    let refund_previous_bid_msg = refund_previous_bid_msg(&auction)?;
+    if raffle.status != RaffleStatus::Open {
+        return Err(RaffleNotOpen);
+    }
 
-    let send_bid_amount_msg = SubMsg::new(
-        Asset::new(bid_asset.to_asset_info(), bid_amount).transfer_msg(auction.creator)?,
-    );
+    if env.block.time >= raffle.ends_at {
+        return Err(RaffleEnded);
+    }
 
-    finish_auction(deps.storage, auction.id, Resolved)?;
+    // once tickets are sold, a new commitment could be chosen knowing the buyers
+    if raffle.draw_commitment.is_some() || raffle.tickets_sold > 0 {
+        return Err(RaffleDrawAlreadyCommitted);
+    }
+
+    verify_draw_commitment(
+        deps.api,
+        &raffle.beacon_public_key,
+        raffle_id,
+        &commitment,
+        &signature,
+    )?;
+
+    save_draw_commitment(deps.storage, raffle_id, commitment.clone())?;
 
     Ok(Response::new()
-        .add_attribute("action", "instant_buyout")
-        .add_attribute("auction_id", auction.id.to_string())
-        .add_attribute("bid_amount", bid_amount.to_string())
-        .add_submessage(send_bid_amount_msg)
-        .add_submessage(send_nft_to_buyer_msg)
-        .add_submessages(refund_previous_bid_msg))
+        .add_attribute("action", "commit_raffle_draw")
+        .add_attribute("raffle_id", raffle_id.to_string())
+        .add_attribute("draw_commitment", commitment.to_hex()))
+}
+
+pub fn buy_tickets(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    raffle_id: RaffleId,
+    count: u32,
+) -> AuctionResult<Response> {
+    let payment_funds = match &info.funds[..] {
+        [coin] => coin.clone(),
+        [] => return Err(NoPaymentFundsSupplied),
+        _ => return Err(UnnecessaryAssetsForPayment),
+    };
+
+    resolve_buy_tickets(
+        deps,
+        env,
+        info.sender,
+        raffle_id,
+        count,
+        PriceAsset::native(&payment_funds.denom),
+        payment_funds.amount,
+    )
+}
+
+fn resolve_buy_tickets(
+    deps: DepsMut,
+    env: Env,
+    buyer: Addr,
+    raffle_id: RaffleId,
+    count: u32,
+    payment_asset: PriceAsset,
+    payment_amount: Uint128,
+) -> AuctionResult<Response> {
+    // covers both native and CW20 payments
+    ensure_not_paused(deps.storage)?;
+
+    let raffle = load_raffle(deps.storage, raffle_id)?.ok_or(RaffleIdNotFound)?;
+
+    if raffle.status != RaffleStatus::Open {
+        return Err(RaffleNotOpen);
+    }
+
+    if env.block.time >= raffle.ends_at {
+        return Err(RaffleEnded);
+    }
+
+    if raffle.draw_commitment.is_none() {
+        return Err(RaffleDrawNotCommitted);
+    }
+
+    if buyer == raffle.creator {
+        return Err(Unauthorized);
+    }
+
+    if count == 0 {
+        return Err(InvalidTicketCount);
+    }
+
+    let remaining = raffle.max_tickets.unwrap_or(u32::MAX) - raffle.tickets_sold;
+    if count > remaining {
+        return Err(NotEnoughTicketsLeft { remaining });
+    }
+
+    if raffle.price_asset != payment_asset {
+        return Err(PaymentWrongAsset);
+    }
+
+    // all purchases are refunded at once if the raffle is voided
+    let purchases =
+        count_ticket_purchases(deps.storage, raffle_id, MAX_TICKET_PURCHASES_PER_RAFFLE)?;
+    if purchases >= MAX_TICKET_PURCHASES_PER_RAFFLE {
+        return Err(TooManyTicketPurchases {
+            max: MAX_TICKET_PURCHASES_PER_RAFFLE,
+        });
+    }
+
+    // also makes sure the sales of all the raffle's tickets can be added up
+    raffle
+        .ticket_price
+        .checked_mul(Uint128::from(raffle.tickets_sold + count))
+        .map_err(StdError::from)?;
+    let price = raffle.ticket_price * Uint128::from(count);
+
+    if payment_amount < price {
+        return Err(InsufficientFundsForPurchase);
+    }
+    // anything sent on top of the price is returned to the buyer
+    let excess_refund = refund_excess_msg(&payment_asset, &buyer, payment_amount - price)?;
+
+    let purchase = save_ticket_purchase(deps.storage, &env.block, raffle_id, buyer, count)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "buy_tickets")
+        .add_attribute("raffle_id", raffle_id.to_string())
+        .add_attribute("first_ticket", purchase.first_ticket.to_string())
+        .add_attribute("count", count.to_string())
+        .add_attribute("price", price.to_string())
+        .add_attributes(excess_refund.attributes)
+        .add_submessages(excess_refund.messages))
+}
+
+pub fn draw_raffle(
+    deps: DepsMut,
+    env: Env,
+    raffle_id: RaffleId,
+    signature: HexBinary,
+) -> AuctionResult<Response> {
+    let raffle = load_raffle(deps.storage, raffle_id)?.ok_or(RaffleIdNotFound)?;
+
+    if raffle.status != RaffleStatus::Open {
+        return Err(RaffleNotOpen);
+    }
+
+    if env.block.time < raffle.ends_at {
+        return Err(RaffleStillInProgress);
+    }
+
+    if raffle.tickets_sold == 0 {
+        return Err(NoTicketsSold);
+    }
+
+    verify_beacon_signature(
+        deps.api,
+        &raffle.beacon_public_key,
+        raffle.draw_round,
+        &signature,
+    )?;
+
+    // tickets were only sold after the commitment, so it's always set here
+    if raffle.draw_commitment != Some(draw_commitment(&signature)) {
+        return Err(DrawCommitmentMismatch);
+    }
+
+    let winning_ticket = draw_winning_ticket(&raffle, &signature);
+    let winner = load_ticket_owner(deps.storage, raffle_id, winning_ticket)?;
+
+    finish_raffle(
+        deps.storage,
+        raffle_id,
+        RaffleStatus::Drawn,
+        Some(winning_ticket),
+        Some(winner.clone()),
+    )?;
+
+    let send_nft_to_winner_msg =
+        transfer_nft_msg(&raffle.nft_contract, &winner, &raffle.track_token_id)?;
+
+    let payout = sale_payout(
+        deps.storage,
+        &raffle.price_asset,
+        raffle.ticket_sales(),
+        raffle.fee_bps,
+        &raffle.creator,
+    )?;
+    let deposit = settle_creation_deposit(
+        deps.storage,
+        &raffle.creator,
+        &raffle.creation_deposit,
+        false,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "draw_raffle")
+        .add_attribute("raffle_id", raffle_id.to_string())
+        .add_attribute("draw_round", raffle.draw_round.to_string())
+        .add_attribute("winning_ticket", winning_ticket.to_string())
+        .add_attribute("winner", winner)
+        .add_attribute("ticket_sales", raffle.ticket_sales().to_string())
+        .add_attributes(payout.attributes)
+        .add_attributes(deposit.attributes)
+        .add_submessage(send_nft_to_winner_msg)
+        .add_submessages(payout.messages)
+        .add_submessages(deposit.messages))
+}
+
+pub fn cancel_raffle(
+    deps: DepsMut,
+    info: MessageInfo,
+    raffle_id: RaffleId,
+) -> AuctionResult<Response> {
+    let raffle = load_raffle(deps.storage, raffle_id)?.ok_or(RaffleIdNotFound)?;
+
+    if raffle.creator != info.sender {
+        return Err(Unauthorized);
+    }
+
+    if raffle.status != RaffleStatus::Open {
+        return Err(RaffleNotOpen);
+    }
+
+    if raffle.tickets_sold > 0 {
+        return Err(RaffleHasTickets);
+    }
+
+    finish_raffle(deps.storage, raffle_id, RaffleStatus::Canceled, None, None)?;
+
+    let send_nft_back_submsg = transfer_nft_msg(
+        &raffle.nft_contract,
+        &raffle.creator,
+        &raffle.track_token_id,
+    )?;
+    // a canceled raffle sold nothing, like an auction canceled or ended without bids
+    let deposit = settle_creation_deposit(
+        deps.storage,
+        &raffle.creator,
+        &raffle.creation_deposit,
+        true,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_raffle")
+        .add_attribute("raffle_id", raffle_id.to_string())
+        .add_attributes(deposit.attributes)
+        .add_submessage(send_nft_back_submsg)
+        .add_submessages(deposit.messages))
+}
+
+pub fn void_raffle(deps: DepsMut, env: Env, raffle_id: RaffleId) -> AuctionResult<Response> {
+    let raffle = load_raffle(deps.storage, raffle_id)?.ok_or(RaffleIdNotFound)?;
+
+    if raffle.status != RaffleStatus::Open {
+        return Err(RaffleNotOpen);
+    }
+
+    if env.block.time <= raffle.draw_deadline {
+        return Err(RaffleDrawDeadlineNotReached);
+    }
+
+    finish_raffle(deps.storage, raffle_id, RaffleStatus::Voided, None, None)?;
+
+    // each buyer is refunded all of their tickets at once
+    let mut refunds: Vec<(Addr, Uint128)> = vec![];
+    for purchase in load_all_ticket_purchases(deps.storage, raffle_id)? {
+        let amount = raffle.ticket_price * Uint128::from(purchase.count);
+        match refunds
+            .iter_mut()
+            .find(|(buyer, _)| *buyer == purchase.buyer)
+        {
+            Some((_, refund)) => *refund += amount,
+            None => refunds.push((purchase.buyer, amount)),
+        }
+    }
+    let refund_msgs = refunds
+        .into_iter()
+        .map(|(buyer, amount)| {
+            Ok(SubMsg::new(
+                Asset::new(raffle.price_asset.to_asset_info(), amount)
+                    .transfer_msg(buyer.to_string())?,
+            ))
+        })
+        .collect::<AuctionResult<Vec<SubMsg>>>()?;
+
+    let send_nft_back_submsg = transfer_nft_msg(
+        &raffle.nft_contract,
+        &raffle.creator,
+        &raffle.track_token_id,
+    )?;
+    // the creator isn't to blame for a draw that never happened
+    let deposit = settle_creation_deposit(
+        deps.storage,
+        &raffle.creator,
+        &raffle.creation_deposit,
+        false,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "void_raffle")
+        .add_attribute("raffle_id", raffle_id.to_string())
+        .add_attribute("ticket_sales", raffle.ticket_sales().to_string())
+        .add_attributes(deposit.attributes)
+        .add_submessage(send_nft_back_submsg)
+        .add_submessages(refund_msgs)
+        .add_submessages(deposit.messages))
 }
 
 pub fn resolve_auction(
@@ -218,7 +2169,7 @@ pub fn resolve_auction(
 ) -> AuctionResult<Response> {
     let auction = load_auction(deps.storage, auction_id)?.ok_or(AuctionIdNotFound)?;
 
-    if !auction.has_ended(&env.block) {
+    if !auction.is_resolvable(&env.block) {
         return Err(AuctionStillInProgress);
     }
 
@@ -238,36 +2189,297 @@ pub fn resolve_auction(
         }
     }
 
+    // the hidden terms of the auction are removed once it's finished
+    let reserve_price = load_reserve_price(deps.storage, auction_id)?;
+    let active_bid_escrow = load_active_bid_escrow(deps.storage, &auction)?;
+    let reserve_price_met = is_reserve_price_met(deps.storage, &auction)?;
+    let no_sale_refund_msgs = refund_previous_bid_msg(deps.storage, &auction)?;
     finish_auction(deps.storage, auction_id, Resolved)?;
 
-    let base_response = Response::new()
+    let mut base_response = Response::new()
         .add_attribute("action", "resolve_auction")
         .add_attribute("auction_id", auction_id.to_string());
 
-    match auction.active_bid {
-        Some(bid) => {
+    let mut auction = auction;
+    if let Some(reserve_price) = reserve_price {
+        match &mut auction.active_bid {
+            Some(_) if !reserve_price_met => {
+                // no sale, the highest bid is refunded like the NFT is returned,
+                // sealed bids are refunded out of their collateral below
+                if !matches!(auction.kind, AuctionKind::SealedBid(_)) {
+                    base_response = base_response.add_submessages(no_sale_refund_msgs);
+                }
+                base_response =
+                    base_response.add_attribute("no_sale_reason", "reserve_price_not_met");
+                auction.active_bid = None;
+            }
+            // a proxy bid whose maximum reaches the reserve price buys at the reserve price
+            Some(bid) if bid.amount < reserve_price => {
+                bid.amount = reserve_price;
+            }
+            // the reserve acts as a bid of the creator, so second-price sales never go below it
+            Some(_) if auction.second_price => {
+                let runner_up = auction
+                    .runner_up_amount
+                    .unwrap_or(auction.minimum_bid_amount);
+                auction.runner_up_amount = Some(runner_up.max(reserve_price));
+            }
+            _ => {}
+        }
+    }
+
+    let unsold = auction.active_bid.is_none();
+    if unsold && !auction.relist_attempts.is_empty() {
+        // the NFT and the creation deposit stay escrowed for the new auction
+        let sealed_bids = settle_sealed_bids(deps.storage, &auction, true)?;
+        let relisted_id = relist_auction(deps.storage, env.block, &auction, reserve_price)?;
+        return Ok(base_response
+            .add_attribute("relisted_as", relisted_id.to_string())
+            .add_attribute(
+                "relist_attempts_left",
+                (auction.relist_attempts.len() - 1).to_string(),
+            )
+            .add_attributes(sealed_bids.attributes)
+            .add_submessages(sealed_bids.messages));
+    }
+
+    let deposit = settle_creation_deposit(
+        deps.storage,
+        &auction.creator,
+        &auction.creation_deposit,
+        unsold,
+    )?;
+    // release the collateral of sealed bids, keeping only the winning bid
+    let sealed_bids = settle_sealed_bids(deps.storage, &auction, true)?;
+    let base_response = base_response
+        .add_attributes(deposit.attributes)
+        .add_attributes(sealed_bids.attributes)
+        .add_submessages(deposit.messages)
+        .add_submessages(sealed_bids.messages);
+
+    let sale_price = auction.sale_price();
+    match (auction.active_bid.clone(), sale_price) {
+        (Some(bid), Some(sale_price)) => {
+            // the winner of a second-price auction gets back what they bid above the price,
+            // and the winner of a proxy bid what they escrowed above it,
+            // sealed bids are refunded out of their collateral above
+            let mut winner_refund_msgs = vec![];
+            let mut base_response = base_response;
+            let escrow = active_bid_escrow.unwrap_or(bid.amount);
+            if auction.second_price || escrow > bid.amount {
+                base_response = base_response.add_attribute("sale_price", sale_price.to_string());
+                // the refund of a sealed bid's collateral is reported with the sealed bids above
+                if !matches!(auction.kind, AuctionKind::SealedBid(_)) {
+                    let winner_refund = escrow - sale_price;
+                    base_response =
+                        base_response.add_attribute("winner_refund", winner_refund.to_string());
+                    if !winner_refund.is_zero() {
+                        winner_refund_msgs.push(SubMsg::new(
+                            Asset::new(bid.asset.to_asset_info(), winner_refund)
+                                .transfer_msg(bid.bidder.to_string())?,
+                        ));
+                    }
+                }
+            }
+
             // send NFT to the highest bidder
-            let send_nft_to_bidder_msg =
-                transfer_nft_msg(auction.nft_contract, bid.bidder, auction.track_token_id)?;
-            // send funds to the auction creator
-            let send_bid_to_auction_creator_msg = SubMsg::new(
-                Asset::new(bid.asset.to_asset_info(), bid.amount).transfer_msg(auction.creator)?,
-            );
+            let send_nft_to_bidder_msgs =
+                transfer_auction_nfts_msgs(deps.storage, &auction, &bid.bidder)?;
+            // send funds to the auction creator, minus the marketplace fee
+            let payout = sale_payout(
+                deps.storage,
+                &bid.asset,
+                sale_price,
+                auction.fee_bps,
+                &auction.creator,
+            )?;
 
             Ok(base_response
-                .add_submessage(send_nft_to_bidder_msg)
-                .add_submessage(send_bid_to_auction_creator_msg))
+                .add_attributes(payout.attributes)
+                .add_submessages(send_nft_to_bidder_msgs)
+                .add_submessages(payout.messages)
+                .add_submessages(winner_refund_msgs))
         }
-        None => {
+        _ => {
             // received no bids, simply return the NFT to the auction creator
-            let return_nft_submsg = transfer_nft_msg(
-                auction.nft_contract,
-                auction.creator,
-                auction.track_token_id,
-            )?;
-            Ok(base_response.add_submessage(return_nft_submsg))
+            let return_nft_submsgs =
+                transfer_auction_nfts_msgs(deps.storage, &auction, &auction.creator)?;
+            Ok(base_response.add_submessages(return_nft_submsgs))
+        }
+    }
+}
+
+/// Rolls an auction that ended without a sale into a new auction for the same NFT,
+/// using its next relist attempt and the same reserve price. Returns the ID of the new auction.
+fn relist_auction(
+    storage: &mut dyn Storage,
+    current_block: BlockInfo,
+    auction: &TrackAuction,
+    reserve_price: Option<Uint128>,
+) -> AuctionResult<AuctionId> {
+    let (attempt, remaining_attempts) = auction
+        .relist_attempts
+        .split_first()
+        .ok_or_else(|| StdError::generic_err("auction has no relist attempts left"))?;
+
+    let auction_data = CreateAuctionData {
+        duration: attempt.duration,
+        creator: auction.creator.clone(),
+        nft_contract: auction.nft_contract.clone(),
+        track_token_id: auction.track_token_id.clone(),
+        minimum_bid_amount: attempt
+            .minimum_bid_amount
+            .unwrap_or(auction.minimum_bid_amount),
+        buyout_price: auction.buyout_price,
+        price_asset: auction.price_asset.clone(),
+        fee_bps: auction.fee_bps,
+        creation_deposit: auction.creation_deposit.clone(),
+        bid_increment: auction.bid_increment.clone(),
+        anti_sniping: auction.anti_sniping.clone(),
+        starts_at: check_auction_start(None, attempt.duration, &current_block)?,
+        kind: auction.kind.clone(),
+        unrevealed_bid_penalty: auction.unrevealed_bid_penalty.clone(),
+        second_price: auction.second_price,
+        reserve_price,
+        lot_id: auction.lot_id,
+        relist_attempts: remaining_attempts.to_vec(),
+        relisted_from: Some(auction.id),
+    };
+
+    let id = save_new_auction(storage, current_block, auction_data)?;
+
+    if let Some(lot_id) = auction.lot_id {
+        relist_lot(storage, lot_id, id)?;
+    }
+
+    Ok(id)
+}
+
+pub fn update_auction(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    auction_id: AuctionId,
+    minimum_bid_amount: Option<Uint128>,
+    buyout_price: Option<BuyoutPriceUpdate>,
+    duration: Option<Duration>,
+) -> AuctionResult<Response> {
+    let auction = load_auction(deps.storage, auction_id)?.ok_or(AuctionIdNotFound)?;
+
+    if auction.creator != info.sender {
+        return Err(Unauthorized);
+    }
+
+    match auction.status {
+        Resolved => return Err(AuctionResolved),
+        Canceled => return Err(AuctionCanceled),
+        Active => {
+            // no-op
+        }
+    }
+
+    if auction.has_ended(&env.block) {
+        return Err(AuctionExpired);
+    }
+
+    let new_minimum_bid_amount = minimum_bid_amount.unwrap_or(auction.minimum_bid_amount);
+    let new_buyout_price = match &buyout_price {
+        Some(BuyoutPriceUpdate::Set { amount }) => Some(*amount),
+        Some(BuyoutPriceUpdate::Remove) => None,
+        None => auction.buyout_price,
+    };
+    let new_duration = duration.unwrap_or(auction.duration);
+
+    // extending the duration is allowed with bids, as it only gives bidders more time to
+    // compete, while shortening it is rejected below for every auction
+    let has_bids = auction.active_bid.is_some() || has_sealed_bids(deps.storage, auction_id)?;
+    if has_bids {
+        let favors_creator = new_minimum_bid_amount > auction.minimum_bid_amount
+            || match (auction.buyout_price, new_buyout_price) {
+                (Some(old), Some(new)) => new > old,
+                (None, None) => false,
+                // adding or removing a buyout price
+                _ => true,
+            };
+        if favors_creator {
+            return Err(AuctionUpdateNotAllowed);
+        }
+    }
+
+    let config = load_config(deps.storage)?;
+
+    if duration.is_some() {
+        let extended = match (auction.duration, new_duration) {
+            (Duration::Time(old), Duration::Time(new)) => new >= old,
+            (Duration::Height(old), Duration::Height(new)) => new >= old,
+            _ => false,
+        };
+        if !extended {
+            return Err(InvalidDurationExtension);
+        }
+        check_auction_duration(&config.duration_limits, new_duration)?;
+    }
+
+    // the updated auction has to be as valid as a newly created one
+    validate_auction_kind(
+        auction.kind.clone(),
+        new_duration,
+        new_minimum_bid_amount,
+        new_buyout_price,
+    )?;
+    validate_reserve_price(
+        load_reserve_price(deps.storage, auction_id)?,
+        &auction.kind,
+        new_minimum_bid_amount,
+        new_buyout_price,
+    )?;
+    let lowest_minimum_bid_amount = validate_relist_attempts(
+        &config.duration_limits,
+        &auction.relist_attempts,
+        &auction.kind,
+        new_duration,
+        new_minimum_bid_amount,
+        new_buyout_price,
+    )?;
+    // collections removed from the whitelist since the auction was created no longer have a floor
+    let mut whitelisted_tokens = vec![];
+    for token in auction_tokens(deps.storage, &auction)? {
+        if load_collection(deps.storage, &token.nft_contract)?.is_some() {
+            whitelisted_tokens.push(token);
         }
     }
+    check_collection_floors(deps.storage, &whitelisted_tokens, lowest_minimum_bid_amount)?;
+
+    let updated_auction = update_auction_terms(
+        deps.storage,
+        auction_id,
+        new_minimum_bid_amount,
+        new_buyout_price,
+        new_duration,
+    )?;
+
+    let mut response = Response::new()
+        .add_attribute("action", "update_auction")
+        .add_attribute("auction_id", auction_id.to_string());
+
+    if minimum_bid_amount.is_some() {
+        response = response.add_attribute("minimum_bid_amount", new_minimum_bid_amount.to_string());
+    }
+
+    if buyout_price.is_some() {
+        response = response.add_attribute(
+            "buyout_price",
+            new_buyout_price.map_or("none".to_string(), |it| it.to_string()),
+        );
+    }
+
+    if duration.is_some() {
+        response = response
+            .add_attribute("duration", new_duration.to_string())
+            .add_attribute("ends_at", updated_auction.ends_at.to_string());
+    }
+
+    Ok(response)
 }
 
 pub fn cancel_auction(
@@ -294,33 +2506,180 @@ pub fn cancel_auction(
         return Err(AuctionExpired);
     }
 
-    let refund_previous_bid_msg = refund_previous_bid_msg(&auction)?;
+    let refund_previous_bid_msg = refund_previous_bid_msg(deps.storage, &auction)?;
+
+    // canceling an auction before it starts never forfeits the deposit
+    let forfeitable = auction.has_started(&env.block);
+    let deposit = settle_creation_deposit(
+        deps.storage,
+        &auction.creator,
+        &auction.creation_deposit,
+        forfeitable,
+    )?;
+    let sealed_bids = settle_sealed_bids(deps.storage, &auction, false)?;
 
     finish_auction(deps.storage, auction_id, Canceled)?;
 
-    let send_nft_back_submsg = transfer_nft_msg(
-        auction.nft_contract,
-        auction.creator,
-        auction.track_token_id,
-    )?;
+    let send_nft_back_submsgs =
+        transfer_auction_nfts_msgs(deps.storage, &auction, &auction.creator)?;
     Ok(Response::new()
         .add_attribute("action", "cancel_auction")
         .add_attribute("auction_id", auction_id.to_string())
-        .add_submessage(send_nft_back_submsg)
-        .add_submessages(refund_previous_bid_msg))
+        .add_attributes(deposit.attributes)
+        .add_attributes(sealed_bids.attributes)
+        .add_submessages(send_nft_back_submsgs)
+        .add_submessages(refund_previous_bid_msg)
+        .add_submessages(deposit.messages)
+        .add_submessages(sealed_bids.messages))
+}
+
+pub fn fund_creation_deposit(deps: DepsMut, info: MessageInfo) -> AuctionResult<Response> {
+    let creation_deposit = load_config(deps.storage)?
+        .creation_deposit
+        .ok_or(InvalidCreationDepositFunds)?;
+
+    let funds = match &info.funds[..] {
+        [coin] if coin.denom == creation_deposit.amount.denom => coin,
+        _ => return Err(InvalidCreationDepositFunds),
+    };
+
+    add_to_deposit_balance(deps.storage, &info.sender, funds)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "fund_creation_deposit")
+        .add_attribute("amount", funds.to_string()))
+}
+
+pub fn withdraw_creation_deposit(
+    deps: DepsMut,
+    info: MessageInfo,
+    denom: String,
+    amount: Option<Uint128>,
+) -> AuctionResult<Response> {
+    let amount = match amount {
+        Some(amount) => amount,
+        None => load_deposit_balance(deps.storage, &info.sender, &denom)?,
+    };
+    let withdrawn = coin(amount.u128(), denom);
+
+    remove_from_deposit_balance(deps.storage, &info.sender, &withdrawn)?;
+
+    let mut response = Response::new()
+        .add_attribute("action", "withdraw_creation_deposit")
+        .add_attribute("amount", withdrawn.to_string());
+
+    // nothing was deposited in this denom, so there is nothing to send
+    if !withdrawn.amount.is_zero() {
+        response = response.add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![withdrawn],
+        });
+    }
+
+    Ok(response)
 }
 
-fn refund_previous_bid_msg(auction: &TrackAuction) -> AuctionResult<Vec<SubMsg>> {
-    let refund_previous_bid_msg = match &auction.active_bid {
-        Some(bid) => vec![SubMsg::new(
-            Asset::new(bid.asset.to_asset_info(), bid.amount)
-                .transfer_msg(bid.bidder.to_string())?,
+/// Refunds everything escrowed for the active bid, including the unused part of a proxy bid.
+fn refund_previous_bid_msg(
+    storage: &dyn Storage,
+    auction: &TrackAuction,
+) -> AuctionResult<Vec<SubMsg>> {
+    let escrow = load_active_bid_escrow(storage, auction)?;
+    let refund_previous_bid_msg = match (&auction.active_bid, escrow) {
+        (Some(bid), Some(escrow)) => vec![SubMsg::new(
+            Asset::new(bid.asset.to_asset_info(), escrow).transfer_msg(bid.bidder.to_string())?,
         )],
-        None => vec![],
+        _ => vec![],
     };
     Ok(refund_previous_bid_msg)
 }
 
+/// Messages and attributes returning funds sent on top of a bid.
+struct ExcessRefund {
+    messages: Vec<SubMsg>,
+    attributes: Vec<Attribute>,
+}
+
+fn refund_excess_msg(
+    asset: &PriceAsset,
+    bidder: &Addr,
+    excess_amount: Uint128,
+) -> AuctionResult<ExcessRefund> {
+    if excess_amount.is_zero() {
+        return Ok(ExcessRefund {
+            messages: vec![],
+            attributes: vec![],
+        });
+    }
+
+    Ok(ExcessRefund {
+        messages: vec![SubMsg::new(
+            Asset::new(asset.to_asset_info(), excess_amount).transfer_msg(bidder.to_string())?,
+        )],
+        attributes: vec![Attribute::new("excess_refund", excess_amount.to_string())],
+    })
+}
+
+/// Messages transferring an auction's NFT, or all the lot's tokens for bundle auctions.
+fn transfer_auction_nfts_msgs(
+    storage: &dyn Storage,
+    auction: &TrackAuction,
+    recipient: &Addr,
+) -> AuctionResult<Vec<SubMsg>> {
+    auction_tokens(storage, auction)?
+        .iter()
+        .map(|token| transfer_nft_msg(&token.nft_contract, recipient, &token.token_id))
+        .collect()
+}
+
+/// Tokens escrowed for an auction, all the lot's tokens for bundle auctions.
+fn auction_tokens(storage: &dyn Storage, auction: &TrackAuction) -> AuctionResult<Vec<LotToken>> {
+    match auction.lot_id {
+        Some(lot_id) => Ok(load_lot(storage, lot_id)?.ok_or(LotIdNotFound)?.tokens),
+        None => Ok(vec![LotToken {
+            nft_contract: auction.nft_contract.clone(),
+            token_id: auction.track_token_id.clone(),
+        }]),
+    }
+}
+
+/// Checks that the creator can have one more active auction or open raffle, which count
+/// towards the same cap.
+fn check_active_listings_cap(
+    storage: &dyn Storage,
+    max_active_per_creator: Option<u32>,
+    creator: &Addr,
+) -> AuctionResult<()> {
+    if let Some(max) = max_active_per_creator {
+        let active_count = load_active_auction_count(storage, creator)?
+            + load_open_raffle_count(storage, creator)?;
+        if active_count >= max {
+            return Err(TooManyActiveAuctions { max });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the minimum bid amount reaches the floor of every collection of the tokens.
+fn check_collection_floors(
+    storage: &dyn Storage,
+    tokens: &[LotToken],
+    minimum_bid_amount: Uint128,
+) -> AuctionResult<()> {
+    for token in tokens {
+        let collection_settings =
+            load_collection(storage, &token.nft_contract)?.ok_or(Cw721NotWhitelisted)?;
+        if let Some(floor) = collection_settings.minimum_bid_floor {
+            if minimum_bid_amount < floor {
+                return Err(MinimumBidBelowCollectionFloor { floor });
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn transfer_nft_msg(
     nft_contract: impl Into<String>,
     recipient: impl Into<String>,
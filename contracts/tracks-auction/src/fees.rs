@@ -0,0 +1,66 @@
+use crate::config::load_config;
+use cosmwasm_std::{Addr, Attribute, Storage, SubMsg, Uint128};
+use cw_asset::Asset;
+use tracks_auction_api::api::PriceAsset;
+use tracks_auction_api::error::AuctionError::FeeTooHigh;
+use tracks_auction_api::error::AuctionResult;
+
+/// Highest marketplace fee the admin can set, 25% of the sale proceeds.
+pub const MAX_FEE_BPS: u16 = 2_500;
+
+const BPS_DENOMINATOR: u128 = 10_000;
+
+pub fn validate_fee_bps(fee_bps: u16) -> AuctionResult<u16> {
+    if fee_bps > MAX_FEE_BPS {
+        return Err(FeeTooHigh {
+            max_fee_bps: MAX_FEE_BPS,
+        });
+    }
+    Ok(fee_bps)
+}
+
+/// Messages and attributes paying out the proceeds of a sale.
+pub struct SalePayout {
+    pub messages: Vec<SubMsg>,
+    pub attributes: Vec<Attribute>,
+}
+
+/// Splits the proceeds of a sale between the fee collector and the seller.
+///
+/// The fee is rounded down, so the remainder of the division always goes to the seller.
+/// Rounding is the same for native and CW20 assets, since both are integer amounts.
+pub fn sale_payout(
+    storage: &dyn Storage,
+    price_asset: &PriceAsset,
+    amount: Uint128,
+    fee_bps: u16,
+    seller: &Addr,
+) -> AuctionResult<SalePayout> {
+    let fee_amount = amount.multiply_ratio(fee_bps, BPS_DENOMINATOR);
+    let seller_amount = amount - fee_amount;
+
+    let mut messages = vec![];
+
+    // zero-amount transfers fail for both native and CW20 assets, so those are skipped
+    if !seller_amount.is_zero() {
+        messages.push(SubMsg::new(
+            Asset::new(price_asset.to_asset_info(), seller_amount).transfer_msg(seller)?,
+        ));
+    }
+
+    if !fee_amount.is_zero() {
+        let fee_collector = load_config(storage)?.fee_collector;
+        messages.push(SubMsg::new(
+            Asset::new(price_asset.to_asset_info(), fee_amount).transfer_msg(fee_collector)?,
+        ));
+    }
+
+    Ok(SalePayout {
+        messages,
+        attributes: vec![
+            Attribute::new("fee_bps", fee_bps.to_string()),
+            Attribute::new("fee_amount", fee_amount.to_string()),
+            Attribute::new("creator_amount", seller_amount.to_string()),
+        ],
+    })
+}
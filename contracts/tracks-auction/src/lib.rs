@@ -1,8 +1,24 @@
 pub mod contract;
 
+mod admin;
+mod anti_sniping;
 mod auctions;
+mod bid_increments;
+mod collection_offers;
+mod collections;
 mod config;
+mod deposits;
+mod drops;
+mod escrow;
 mod execute;
+mod fees;
+mod limits;
+mod listings;
+mod lots;
+mod migrate;
+mod offers;
 mod query;
+mod raffles;
+mod sealed_bids;
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,94 @@
+use cosmwasm_std::{Attribute, BlockInfo};
+use cw_utils::Duration::{Height, Time};
+use cw_utils::{Duration, Scheduled};
+use tracks_auction_api::api::DurationLimits;
+use tracks_auction_api::error::AuctionError::{
+    AuctionDurationTooLong, AuctionDurationTooShort, InvalidAuctionDuration, InvalidAuctionStart,
+    InvalidDurationLimits,
+};
+use tracks_auction_api::error::AuctionResult;
+
+pub fn validate_duration_limits(limits: DurationLimits) -> AuctionResult<DurationLimits> {
+    let bounds_valid = |min: Option<u64>, max: Option<u64>| match (min, max) {
+        (Some(min), Some(max)) => min <= max,
+        _ => true,
+    };
+
+    if !bounds_valid(limits.min_time, limits.max_time)
+        || !bounds_valid(limits.min_height, limits.max_height)
+    {
+        return Err(InvalidDurationLimits);
+    }
+
+    Ok(limits)
+}
+
+/// Treats a zero limit the same as not having one.
+pub fn check_max_active_auctions(max_active_auctions: Option<u32>) -> Option<u32> {
+    max_active_auctions.filter(|max| *max > 0)
+}
+
+/// Checks that the duration is non-zero and within the bounds of its own kind.
+pub fn check_auction_duration(limits: &DurationLimits, duration: Duration) -> AuctionResult<()> {
+    let (length, min, max) = match duration {
+        Time(seconds) => (seconds, limits.min_time, limits.max_time),
+        Height(blocks) => (blocks, limits.min_height, limits.max_height),
+    };
+    // bounds are reported in the same kind as the duration being checked
+    let to_duration = |length: u64| match duration {
+        Time(_) => Time(length),
+        Height(_) => Height(length),
+    };
+
+    if length == 0 {
+        return Err(InvalidAuctionDuration);
+    }
+
+    if let Some(min) = min {
+        if length < min {
+            return Err(AuctionDurationTooShort {
+                min: to_duration(min),
+            });
+        }
+    }
+
+    if let Some(max) = max {
+        if length > max {
+            return Err(AuctionDurationTooLong {
+                max: to_duration(max),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Resolves when an auction with the given duration starts, checking the requested start.
+pub fn check_auction_start(
+    start: Option<Scheduled>,
+    duration: Duration,
+    current_block: &BlockInfo,
+) -> AuctionResult<Scheduled> {
+    match (start, duration) {
+        (None, Time(_)) => Ok(Scheduled::AtTime(current_block.time)),
+        (None, Height(_)) => Ok(Scheduled::AtHeight(current_block.height)),
+        (Some(Scheduled::AtTime(time)), Time(_)) if time >= current_block.time => {
+            Ok(Scheduled::AtTime(time))
+        }
+        (Some(Scheduled::AtHeight(height)), Height(_)) if height >= current_block.height => {
+            Ok(Scheduled::AtHeight(height))
+        }
+        _ => Err(InvalidAuctionStart),
+    }
+}
+
+pub fn duration_limits_attributes(limits: &DurationLimits) -> Vec<Attribute> {
+    let bound = |value: Option<u64>| value.map_or("none".to_string(), |it| it.to_string());
+
+    vec![
+        Attribute::new("min_duration_time", bound(limits.min_time)),
+        Attribute::new("max_duration_time", bound(limits.max_time)),
+        Attribute::new("min_duration_height", bound(limits.min_height)),
+        Attribute::new("max_duration_height", bound(limits.max_height)),
+    ]
+}
@@ -0,0 +1,99 @@
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{Addr, BlockInfo, StdResult, Storage, Uint128};
+use cw_storage_plus::{Bound, Item, Map};
+use tracks_auction_api::api::{Listing, ListingId, PriceAsset};
+use tracks_auction_api::error::AuctionError::ListingIdNotFound;
+use tracks_auction_api::error::AuctionResult;
+
+const DEFAULT_LISTINGS_QUERY_LIMIT: u32 = 20;
+const MAX_LISTINGS_QUERY_LIMIT: u32 = 100;
+
+const NEXT_LISTING_ID: Item<u64> = Item::new("next_listing_id");
+
+/// Open listings. Listings are removed once they are bought or delisted.
+const LISTINGS: Map<ListingId, Listing> = Map::new("listings");
+
+pub struct CreateListingData {
+    pub seller: Addr,
+    pub nft_contract: Addr,
+    pub track_token_id: String,
+    pub price: Uint128,
+    pub price_asset: PriceAsset,
+    pub fee_bps: u16,
+}
+
+pub fn save_new_listing(
+    storage: &mut dyn Storage,
+    current_block: BlockInfo,
+    listing_data: CreateListingData,
+) -> AuctionResult<ListingId> {
+    let next_listing_id = NEXT_LISTING_ID.may_load(storage)?.unwrap_or_default();
+    NEXT_LISTING_ID.save(storage, &(next_listing_id + 1))?;
+
+    LISTINGS.save(
+        storage,
+        next_listing_id,
+        &Listing {
+            id: next_listing_id,
+            created_at: current_block,
+            seller: listing_data.seller,
+            nft_contract: listing_data.nft_contract,
+            track_token_id: listing_data.track_token_id,
+            price: listing_data.price,
+            price_asset: listing_data.price_asset,
+            fee_bps: listing_data.fee_bps,
+        },
+    )?;
+
+    Ok(next_listing_id)
+}
+
+pub fn load_listing(storage: &dyn Storage, id: ListingId) -> AuctionResult<Option<Listing>> {
+    Ok(LISTINGS.may_load(storage, id)?)
+}
+
+pub fn update_listing_price(
+    storage: &mut dyn Storage,
+    id: ListingId,
+    price: Uint128,
+) -> AuctionResult<()> {
+    let listing = load_listing(storage, id)?.ok_or(ListingIdNotFound)?;
+
+    LISTINGS.save(storage, id, &Listing { price, ..listing })?;
+
+    Ok(())
+}
+
+pub fn remove_listing(storage: &mut dyn Storage, id: ListingId) {
+    LISTINGS.remove(storage, id);
+}
+
+pub fn load_listings(
+    storage: &dyn Storage,
+    seller: Option<Addr>,
+    nft_contract: Option<Addr>,
+    start_after: Option<ListingId>,
+    limit: Option<u32>,
+) -> AuctionResult<Vec<Listing>> {
+    let start_after = start_after.map(Bound::exclusive);
+    let limit = limit
+        .unwrap_or(DEFAULT_LISTINGS_QUERY_LIMIT)
+        .min(MAX_LISTINGS_QUERY_LIMIT);
+
+    Ok(LISTINGS
+        .range(storage, start_after, None, Ascending)
+        .map(|res| res.map(|(_, listing)| listing))
+        .filter(|res| match res {
+            Ok(listing) => {
+                seller
+                    .as_ref()
+                    .is_none_or(|seller| listing.seller == *seller)
+                    && nft_contract
+                        .as_ref()
+                        .is_none_or(|nft_contract| listing.nft_contract == *nft_contract)
+            }
+            Err(_) => true,
+        })
+        .take(limit as usize)
+        .collect::<StdResult<Vec<Listing>>>()?)
+}
@@ -0,0 +1,148 @@
+use crate::auctions::validate_auction_kind;
+use crate::limits::check_auction_duration;
+use cosmwasm_std::{Addr, Storage, Uint128};
+use cw_storage_plus::{Item, Map};
+use cw_utils::Duration;
+use cw_utils::Duration::{Height, Time};
+use tracks_auction_api::api::{
+    AuctionId, AuctionKind, DurationLimits, Lot, LotId, LotToken, RelistAttempt,
+};
+use tracks_auction_api::error::AuctionError::{
+    InvalidRelistAttempt, LotAlreadyStarted, LotFull, LotIdNotFound, TooManyRelistAttempts,
+};
+use tracks_auction_api::error::AuctionResult;
+
+/// Maximum number of tokens in a lot, so settling its auction stays within gas limits.
+pub const MAX_LOT_SIZE: u32 = 50;
+
+/// Most rounds an auction can be relisted for after ending without a sale.
+pub const MAX_RELIST_ATTEMPTS: u32 = 10;
+
+const NEXT_LOT_ID: Item<u64> = Item::new("next_lot_id");
+
+/// Draft lots, and lots whose auction was started. Discarded drafts are removed.
+const LOTS: Map<LotId, Lot> = Map::new("lots");
+
+pub fn save_new_lot(storage: &mut dyn Storage, creator: Addr) -> AuctionResult<LotId> {
+    let next_lot_id = NEXT_LOT_ID.may_load(storage)?.unwrap_or_default();
+    NEXT_LOT_ID.save(storage, &(next_lot_id + 1))?;
+
+    LOTS.save(
+        storage,
+        next_lot_id,
+        &Lot {
+            id: next_lot_id,
+            creator,
+            tokens: vec![],
+            auction_id: None,
+        },
+    )?;
+
+    Ok(next_lot_id)
+}
+
+pub fn load_lot(storage: &dyn Storage, id: LotId) -> AuctionResult<Option<Lot>> {
+    Ok(LOTS.may_load(storage, id)?)
+}
+
+/// Loads a lot that is still a draft.
+pub fn load_draft_lot(storage: &dyn Storage, id: LotId) -> AuctionResult<Lot> {
+    let lot = load_lot(storage, id)?.ok_or(LotIdNotFound)?;
+
+    if lot.auction_id.is_some() {
+        return Err(LotAlreadyStarted);
+    }
+
+    Ok(lot)
+}
+
+pub fn add_to_lot(storage: &mut dyn Storage, id: LotId, token: LotToken) -> AuctionResult<()> {
+    let mut lot = load_draft_lot(storage, id)?;
+
+    if lot.tokens.len() >= MAX_LOT_SIZE as usize {
+        return Err(LotFull { max: MAX_LOT_SIZE });
+    }
+
+    lot.tokens.push(token);
+    LOTS.save(storage, id, &lot)?;
+
+    Ok(())
+}
+
+pub fn start_lot(storage: &mut dyn Storage, id: LotId, auction_id: AuctionId) -> AuctionResult<()> {
+    let lot = load_draft_lot(storage, id)?;
+
+    LOTS.save(
+        storage,
+        id,
+        &Lot {
+            auction_id: Some(auction_id),
+            ..lot
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Checks that each relist attempt would be a valid auction of the same kind, with a duration
+/// of the same kind as the original one, and a minimum bid no higher than the previous round's.
+/// Returns the lowest minimum bid amount of all the rounds.
+pub fn validate_relist_attempts(
+    limits: &DurationLimits,
+    attempts: &[RelistAttempt],
+    kind: &AuctionKind,
+    duration: Duration,
+    minimum_bid_amount: Uint128,
+    buyout_price: Option<Uint128>,
+) -> AuctionResult<Uint128> {
+    if attempts.len() > MAX_RELIST_ATTEMPTS as usize {
+        return Err(TooManyRelistAttempts {
+            max: MAX_RELIST_ATTEMPTS,
+        });
+    }
+
+    let mut previous_minimum = minimum_bid_amount;
+    for attempt in attempts {
+        if !matches!(
+            (duration, attempt.duration),
+            (Time(_), Time(_)) | (Height(_), Height(_))
+        ) {
+            return Err(InvalidRelistAttempt);
+        }
+        check_auction_duration(limits, attempt.duration)?;
+
+        let minimum = attempt.minimum_bid_amount.unwrap_or(previous_minimum);
+        if minimum > previous_minimum {
+            return Err(InvalidRelistAttempt);
+        }
+        validate_auction_kind(kind.clone(), attempt.duration, minimum, buyout_price)?;
+
+        previous_minimum = minimum;
+    }
+
+    Ok(previous_minimum)
+}
+
+/// Moves a started lot over to the auction it was relisted as.
+pub fn relist_lot(
+    storage: &mut dyn Storage,
+    id: LotId,
+    auction_id: AuctionId,
+) -> AuctionResult<()> {
+    let lot = load_lot(storage, id)?.ok_or(LotIdNotFound)?;
+
+    LOTS.save(
+        storage,
+        id,
+        &Lot {
+            auction_id: Some(auction_id),
+            ..lot
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn remove_lot(storage: &mut dyn Storage, id: LotId) {
+    LOTS.remove(storage, id);
+}
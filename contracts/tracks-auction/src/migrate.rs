@@ -0,0 +1,133 @@
+use crate::auctions::{escrow_active_bids, rebuild_active_auction_counts, rewrite_auctions};
+use crate::collections::save_collection;
+use crate::config::save_config;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, BlockInfo, DepsMut, Env, Uint128};
+use cw_storage_plus::Item;
+use cw_utils::{Duration, Scheduled};
+use semver::Version;
+use tracks_auction_api::api::{
+    AntiSniping, AuctionId, AuctionKind, AuctionStatus, Bid, BidIncrement, CollectionSettings,
+    Config, DurationLimits, PriceAsset, TrackAuction, UnrevealedBidPenalty,
+};
+use tracks_auction_api::error::AuctionResult;
+
+/// Config layout used before the admin role was introduced.
+#[cw_serde]
+struct ConfigV0_1 {
+    whitelisted_nft: Addr,
+    price_asset: PriceAsset,
+}
+
+const CONFIG_V0_1: Item<ConfigV0_1> = Item::new("config");
+
+/// TrackAuction layout used before marketplace fees were introduced.
+#[cw_serde]
+struct TrackAuctionV0_1 {
+    status: AuctionStatus,
+    created_at: BlockInfo,
+    duration: Duration,
+    id: AuctionId,
+    creator: Addr,
+    nft_contract: Addr,
+    track_token_id: String,
+    minimum_bid_amount: Uint128,
+    price_asset: PriceAsset,
+    active_bid: Option<Bid>,
+    buyout_price: Option<Uint128>,
+}
+
+/// Runs all the storage migration steps needed to bring the storage from the given version
+/// to the current one.
+/// Steps are run in order, each one expecting the storage layout produced by the previous.
+pub fn migrate_storage(
+    mut deps: DepsMut,
+    env: &Env,
+    stored_version: &Version,
+) -> AuctionResult<()> {
+    if *stored_version < Version::new(0, 2, 0) {
+        migrate_config_from_v0_1(deps.branch(), env)?;
+        migrate_auctions_from_v0_1(deps.branch())?;
+        rebuild_active_auction_counts(deps.storage)?;
+        escrow_active_bids(deps.storage)?;
+    }
+
+    Ok(())
+}
+
+/// Adds the admin to the config, moves the single whitelisted NFT into the collection registry,
+/// and makes the single price asset the only accepted one.
+/// The contract's chain-level admin (the one performing the migration) becomes the admin,
+/// and also the fee collector, with fees turned off.
+fn migrate_config_from_v0_1(deps: DepsMut, env: &Env) -> AuctionResult<()> {
+    let legacy_config = CONFIG_V0_1.load(deps.storage)?;
+
+    let contract_info = deps
+        .querier
+        .query_wasm_contract_info(env.contract.address.to_string())?;
+    let admin = contract_info.admin.unwrap_or(contract_info.creator);
+
+    let admin = deps.api.addr_validate(&admin)?;
+
+    save_config(
+        deps.storage,
+        &Config {
+            admin: admin.clone(),
+            price_assets: vec![legacy_config.price_asset],
+            fee_bps: 0,
+            fee_collector: admin,
+            creation_deposit: None,
+            duration_limits: DurationLimits::default(),
+            max_active_auctions_per_creator: None,
+            bid_increment: BidIncrement::default(),
+            anti_sniping: AntiSniping::default(),
+            unrevealed_bid_penalty: UnrevealedBidPenalty::default(),
+            randomness_beacon: None,
+        },
+    )?;
+    save_collection(
+        deps.storage,
+        &legacy_config.whitelisted_nft,
+        &CollectionSettings::default(),
+    )?;
+
+    Ok(())
+}
+
+/// Auctions created before fees were introduced are settled without a fee.
+/// Their end is calculated the same way it was before, and is never extended.
+fn migrate_auctions_from_v0_1(deps: DepsMut) -> AuctionResult<()> {
+    rewrite_auctions(deps.storage, |auction: TrackAuctionV0_1| {
+        let starts_at = match auction.duration {
+            Duration::Height(_) => Scheduled::AtHeight(auction.created_at.height),
+            Duration::Time(_) => Scheduled::AtTime(auction.created_at.time),
+        };
+        let ends_at = auction.duration.after(&auction.created_at);
+        TrackAuction {
+            status: auction.status,
+            created_at: auction.created_at,
+            duration: auction.duration,
+            id: auction.id,
+            creator: auction.creator,
+            nft_contract: auction.nft_contract,
+            track_token_id: auction.track_token_id,
+            minimum_bid_amount: auction.minimum_bid_amount,
+            price_asset: auction.price_asset,
+            active_bid: auction.active_bid,
+            buyout_price: auction.buyout_price,
+            fee_bps: 0,
+            creation_deposit: None,
+            bid_increment: BidIncrement::default(),
+            ends_at,
+            anti_sniping: None,
+            starts_at,
+            kind: AuctionKind::English,
+            unrevealed_bid_penalty: None,
+            second_price: false,
+            runner_up_amount: None,
+            lot_id: None,
+            relist_attempts: vec![],
+            relisted_from: None,
+        }
+    })
+}
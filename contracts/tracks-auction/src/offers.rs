@@ -0,0 +1,115 @@
+use crate::escrow::{add_to_escrow, remove_from_escrow};
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{Addr, BlockInfo, StdResult, Storage, Uint128};
+use cw_storage_plus::{Bound, Item, Map};
+use cw_utils::Expiration;
+use tracks_auction_api::api::{Offer, OfferId, PriceAsset};
+use tracks_auction_api::error::AuctionResult;
+
+const DEFAULT_OFFERS_QUERY_LIMIT: u32 = 20;
+const MAX_OFFERS_QUERY_LIMIT: u32 = 100;
+
+const NEXT_OFFER_ID: Item<u64> = Item::new("next_offer_id");
+
+/// Open offers. Offers are removed once they are accepted, retracted or refunded.
+const OFFERS: Map<OfferId, Offer> = Map::new("offers");
+
+pub struct MakeOfferData {
+    pub offerer: Addr,
+    pub nft_contract: Addr,
+    pub track_token_id: String,
+    pub amount: Uint128,
+    pub price_asset: PriceAsset,
+    pub expires: Expiration,
+}
+
+pub fn save_new_offer(
+    storage: &mut dyn Storage,
+    current_block: BlockInfo,
+    offer_data: MakeOfferData,
+) -> AuctionResult<OfferId> {
+    let next_offer_id = NEXT_OFFER_ID.may_load(storage)?.unwrap_or_default();
+    NEXT_OFFER_ID.save(storage, &(next_offer_id + 1))?;
+
+    add_to_escrow(storage, &offer_data.price_asset, offer_data.amount)?;
+
+    OFFERS.save(
+        storage,
+        next_offer_id,
+        &Offer {
+            id: next_offer_id,
+            created_at: current_block,
+            offerer: offer_data.offerer,
+            nft_contract: offer_data.nft_contract,
+            track_token_id: offer_data.track_token_id,
+            amount: offer_data.amount,
+            price_asset: offer_data.price_asset,
+            expires: offer_data.expires,
+        },
+    )?;
+
+    Ok(next_offer_id)
+}
+
+pub fn load_offer(storage: &dyn Storage, id: OfferId) -> AuctionResult<Option<Offer>> {
+    Ok(OFFERS.may_load(storage, id)?)
+}
+
+pub fn remove_offer(storage: &mut dyn Storage, id: OfferId) -> AuctionResult<()> {
+    if let Some(offer) = load_offer(storage, id)? {
+        remove_from_escrow(storage, &offer.price_asset, offer.amount)?;
+        OFFERS.remove(storage, id);
+    }
+    Ok(())
+}
+
+fn load_filtered_offers(
+    storage: &dyn Storage,
+    filter: impl Fn(&Offer) -> bool,
+    start_after: Option<OfferId>,
+    limit: Option<u32>,
+) -> AuctionResult<Vec<Offer>> {
+    let start_after = start_after.map(Bound::exclusive);
+    let limit = limit
+        .unwrap_or(DEFAULT_OFFERS_QUERY_LIMIT)
+        .min(MAX_OFFERS_QUERY_LIMIT);
+
+    Ok(OFFERS
+        .range(storage, start_after, None, Ascending)
+        .map(|res| res.map(|(_, offer)| offer))
+        .filter(|res| match res {
+            Ok(offer) => filter(offer),
+            Err(_) => true,
+        })
+        .take(limit as usize)
+        .collect::<StdResult<Vec<Offer>>>()?)
+}
+
+pub fn load_offers_by_token(
+    storage: &dyn Storage,
+    nft_contract: &Addr,
+    token_id: &str,
+    start_after: Option<OfferId>,
+    limit: Option<u32>,
+) -> AuctionResult<Vec<Offer>> {
+    load_filtered_offers(
+        storage,
+        |offer| offer.nft_contract == *nft_contract && offer.track_token_id == token_id,
+        start_after,
+        limit,
+    )
+}
+
+pub fn load_offers_by_offerer(
+    storage: &dyn Storage,
+    offerer: &Addr,
+    start_after: Option<OfferId>,
+    limit: Option<u32>,
+) -> AuctionResult<Vec<Offer>> {
+    load_filtered_offers(
+        storage,
+        |offer| offer.offerer == *offerer,
+        start_after,
+        limit,
+    )
+}
@@ -1,8 +1,31 @@
-use crate::auctions::{load_auction, load_auctions};
-use crate::config::load_config;
-use cosmwasm_std::Deps;
-use tracks_auction_api::api::{AuctionId, AuctionResponse, AuctionsResponse, ConfigResponse};
-use tracks_auction_api::error::AuctionError::AuctionIdNotFound;
+use crate::auctions::{is_reserve_price_met, load_auction, load_auctions, load_reserve_price};
+use crate::collection_offers::{load_collection_offer, load_collection_offers};
+use crate::collections::{load_collection, load_collections};
+use crate::config::{load_config, load_paused, load_pending_admin};
+use crate::deposits::load_deposit_balances;
+use crate::drops::{load_drop, load_drops};
+use crate::escrow::load_escrow_totals;
+use crate::listings::{load_listing, load_listings};
+use crate::lots::load_lot;
+use crate::offers::{load_offer, load_offers_by_offerer, load_offers_by_token};
+use crate::raffles::{load_raffle, load_raffle_tickets, load_raffles};
+use crate::sealed_bids::{load_sealed_bid, sealed_bid_commitment};
+use cosmwasm_std::{Deps, Env, Uint128};
+use cw20::{BalanceResponse, Cw20QueryMsg};
+use tracks_auction_api::api::{
+    AssetBalance, AuctionId, AuctionResponse, AuctionsResponse, CollectionOfferId,
+    CollectionOfferResponse, CollectionOffersResponse, CollectionResponse, CollectionsResponse,
+    ConfigResponse, CreationDepositBalanceResponse, CurrentPriceResponse, DropId, DropResponse,
+    DropsResponse, FeeInfoResponse, ListingId, ListingResponse, ListingsResponse, LotId,
+    LotResponse, OfferId, OfferResponse, OffersResponse, PauseStatusResponse, PendingAdminResponse,
+    PriceAsset, RaffleId, RaffleResponse, RaffleTicketsResponse, RafflesResponse,
+    ReservePriceResponse, SealedBidCommitmentResponse, SealedBidResponse,
+    UnaccountedBalancesResponse, WhitelistedCollection,
+};
+use tracks_auction_api::error::AuctionError::{
+    AuctionIdNotFound, CollectionOfferIdNotFound, Cw721NotWhitelisted, DropIdNotFound,
+    ListingIdNotFound, LotIdNotFound, NotDutchAuction, OfferIdNotFound, RaffleIdNotFound,
+};
 use tracks_auction_api::error::AuctionResult;
 
 pub fn query_config(deps: Deps) -> AuctionResult<ConfigResponse> {
@@ -10,18 +33,319 @@ pub fn query_config(deps: Deps) -> AuctionResult<ConfigResponse> {
     Ok(ConfigResponse { config })
 }
 
+pub fn query_fee_info(deps: Deps) -> AuctionResult<FeeInfoResponse> {
+    let config = load_config(deps.storage)?;
+    Ok(FeeInfoResponse {
+        fee_bps: config.fee_bps,
+        fee_collector: config.fee_collector,
+    })
+}
+
+pub fn query_pause_status(deps: Deps) -> AuctionResult<PauseStatusResponse> {
+    let paused = load_paused(deps.storage)?;
+    Ok(PauseStatusResponse { paused })
+}
+
+pub fn query_creation_deposit_balance(
+    deps: Deps,
+    address: String,
+) -> AuctionResult<CreationDepositBalanceResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let balances = load_deposit_balances(deps.storage, &address)?;
+    Ok(CreationDepositBalanceResponse { balances })
+}
+
+fn asset_balance_entry<'a>(
+    balances: &'a mut Vec<AssetBalance>,
+    asset: &PriceAsset,
+) -> &'a mut AssetBalance {
+    let position = match balances.iter().position(|balance| balance.asset == *asset) {
+        Some(position) => position,
+        None => {
+            balances.push(AssetBalance {
+                asset: asset.clone(),
+                held: Uint128::zero(),
+                accounted: Uint128::zero(),
+                unaccounted: Uint128::zero(),
+            });
+            balances.len() - 1
+        }
+    };
+    &mut balances[position]
+}
+
+pub fn query_unaccounted_balances(
+    deps: Deps,
+    env: Env,
+) -> AuctionResult<UnaccountedBalancesResponse> {
+    let mut balances: Vec<AssetBalance> = vec![];
+
+    // escrowed funds are tracked as they come in and go out, so nothing has to be iterated
+    for (asset, total) in load_escrow_totals(deps.storage)? {
+        asset_balance_entry(&mut balances, &asset).accounted = total;
+    }
+
+    for coin in deps.querier.query_all_balances(&env.contract.address)? {
+        asset_balance_entry(&mut balances, &PriceAsset::native(&coin.denom)).held = coin.amount;
+    }
+
+    // CW20 balances can only be queried for known tokens
+    for asset in load_config(deps.storage)?.price_assets {
+        if let PriceAsset::Cw20 { .. } = asset {
+            asset_balance_entry(&mut balances, &asset);
+        }
+    }
+
+    for balance in balances.iter_mut() {
+        if let PriceAsset::Cw20 { contract } = &balance.asset {
+            let response: BalanceResponse = deps.querier.query_wasm_smart(
+                contract,
+                &Cw20QueryMsg::Balance {
+                    address: env.contract.address.to_string(),
+                },
+            )?;
+            balance.held = response.balance;
+        }
+        balance.unaccounted = balance.held.saturating_sub(balance.accounted);
+    }
+
+    Ok(UnaccountedBalancesResponse { balances })
+}
+
+pub fn query_pending_admin(deps: Deps) -> AuctionResult<PendingAdminResponse> {
+    let pending_admin = load_pending_admin(deps.storage)?;
+    Ok(PendingAdminResponse { pending_admin })
+}
+
+pub fn query_collection(deps: Deps, nft_contract: String) -> AuctionResult<CollectionResponse> {
+    let nft_contract = deps.api.addr_validate(&nft_contract)?;
+    let settings = load_collection(deps.storage, &nft_contract)?.ok_or(Cw721NotWhitelisted)?;
+
+    Ok(CollectionResponse {
+        collection: WhitelistedCollection {
+            nft_contract,
+            settings,
+        },
+    })
+}
+
+pub fn query_collections(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> AuctionResult<CollectionsResponse> {
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let collections = load_collections(deps.storage, start_after, limit)?;
+    Ok(CollectionsResponse { collections })
+}
+
 pub fn query_auction(deps: Deps, id: AuctionId) -> AuctionResult<AuctionResponse> {
     let auction = load_auction(deps.storage, id)?.ok_or(AuctionIdNotFound)?;
 
     Ok(AuctionResponse { auction })
 }
 
+pub fn query_current_price(
+    deps: Deps,
+    env: Env,
+    auction_id: AuctionId,
+) -> AuctionResult<CurrentPriceResponse> {
+    let auction = load_auction(deps.storage, auction_id)?.ok_or(AuctionIdNotFound)?;
+    let price = auction.current_price(&env.block).ok_or(NotDutchAuction)?;
+
+    Ok(CurrentPriceResponse {
+        price,
+        asset: auction.price_asset,
+    })
+}
+
+pub fn query_reserve_price(
+    deps: Deps,
+    auction_id: AuctionId,
+) -> AuctionResult<ReservePriceResponse> {
+    let auction = load_auction(deps.storage, auction_id)?.ok_or(AuctionIdNotFound)?;
+
+    Ok(ReservePriceResponse {
+        has_reserve_price: load_reserve_price(deps.storage, auction_id)?.is_some(),
+        reserve_met: is_reserve_price_met(deps.storage, &auction)?,
+    })
+}
+
+pub fn query_sealed_bid(
+    deps: Deps,
+    auction_id: AuctionId,
+    bidder: String,
+) -> AuctionResult<SealedBidResponse> {
+    let bidder = deps.api.addr_validate(&bidder)?;
+    let sealed_bid = load_sealed_bid(deps.storage, auction_id, &bidder)?;
+
+    Ok(SealedBidResponse { sealed_bid })
+}
+
+pub fn query_sealed_bid_commitment(
+    deps: Deps,
+    amount: Uint128,
+    salt: String,
+    bidder: String,
+) -> AuctionResult<SealedBidCommitmentResponse> {
+    let bidder = deps.api.addr_validate(&bidder)?;
+    let commitment = sealed_bid_commitment(amount, &salt, &bidder);
+
+    Ok(SealedBidCommitmentResponse { commitment })
+}
+
+pub fn query_listing(deps: Deps, id: ListingId) -> AuctionResult<ListingResponse> {
+    let listing = load_listing(deps.storage, id)?.ok_or(ListingIdNotFound)?;
+
+    Ok(ListingResponse { listing })
+}
+
+pub fn query_listings(
+    deps: Deps,
+    seller: Option<String>,
+    nft_contract: Option<String>,
+    start_after: Option<ListingId>,
+    limit: Option<u32>,
+) -> AuctionResult<ListingsResponse> {
+    let seller = seller
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let nft_contract = nft_contract
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let listings = load_listings(deps.storage, seller, nft_contract, start_after, limit)?;
+    Ok(ListingsResponse { listings })
+}
+
+pub fn query_lot(deps: Deps, id: LotId) -> AuctionResult<LotResponse> {
+    let lot = load_lot(deps.storage, id)?.ok_or(LotIdNotFound)?;
+
+    Ok(LotResponse { lot })
+}
+
+pub fn query_drop(deps: Deps, id: DropId) -> AuctionResult<DropResponse> {
+    let drop = load_drop(deps.storage, id)?.ok_or(DropIdNotFound)?;
+
+    Ok(DropResponse { drop })
+}
+
+pub fn query_drops(
+    deps: Deps,
+    artist: Option<String>,
+    start_after: Option<DropId>,
+    limit: Option<u32>,
+) -> AuctionResult<DropsResponse> {
+    let artist = artist
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let drops = load_drops(deps.storage, artist, start_after, limit)?;
+    Ok(DropsResponse { drops })
+}
+
+pub fn query_raffle(deps: Deps, id: RaffleId) -> AuctionResult<RaffleResponse> {
+    let raffle = load_raffle(deps.storage, id)?.ok_or(RaffleIdNotFound)?;
+
+    Ok(RaffleResponse { raffle })
+}
+
+pub fn query_raffles(
+    deps: Deps,
+    creator: Option<String>,
+    start_after: Option<RaffleId>,
+    limit: Option<u32>,
+) -> AuctionResult<RafflesResponse> {
+    let creator = creator
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let raffles = load_raffles(deps.storage, creator, start_after, limit)?;
+    Ok(RafflesResponse { raffles })
+}
+
+pub fn query_raffle_tickets(
+    deps: Deps,
+    raffle_id: RaffleId,
+    start_after: Option<u32>,
+    limit: Option<u32>,
+) -> AuctionResult<RaffleTicketsResponse> {
+    load_raffle(deps.storage, raffle_id)?.ok_or(RaffleIdNotFound)?;
+
+    let purchases = load_raffle_tickets(deps.storage, raffle_id, start_after, limit)?;
+    Ok(RaffleTicketsResponse { purchases })
+}
+
+pub fn query_offer(deps: Deps, id: OfferId) -> AuctionResult<OfferResponse> {
+    let offer = load_offer(deps.storage, id)?.ok_or(OfferIdNotFound)?;
+
+    Ok(OfferResponse { offer })
+}
+
+pub fn query_offers_by_token(
+    deps: Deps,
+    nft_contract: String,
+    token_id: String,
+    start_after: Option<OfferId>,
+    limit: Option<u32>,
+) -> AuctionResult<OffersResponse> {
+    let nft_contract = deps.api.addr_validate(&nft_contract)?;
+    let offers = load_offers_by_token(deps.storage, &nft_contract, &token_id, start_after, limit)?;
+    Ok(OffersResponse { offers })
+}
+
+pub fn query_offers_by_offerer(
+    deps: Deps,
+    offerer: String,
+    start_after: Option<OfferId>,
+    limit: Option<u32>,
+) -> AuctionResult<OffersResponse> {
+    let offerer = deps.api.addr_validate(&offerer)?;
+    let offers = load_offers_by_offerer(deps.storage, &offerer, start_after, limit)?;
+    Ok(OffersResponse { offers })
+}
+
+pub fn query_collection_offer(
+    deps: Deps,
+    id: CollectionOfferId,
+) -> AuctionResult<CollectionOfferResponse> {
+    let offer = load_collection_offer(deps.storage, id)?.ok_or(CollectionOfferIdNotFound)?;
+
+    Ok(CollectionOfferResponse { offer })
+}
+
+pub fn query_collection_offers(
+    deps: Deps,
+    nft_contract: Option<String>,
+    offerer: Option<String>,
+    start_after: Option<CollectionOfferId>,
+    limit: Option<u32>,
+) -> AuctionResult<CollectionOffersResponse> {
+    let nft_contract = nft_contract
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let offerer = offerer
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let offers = load_collection_offers(deps.storage, nft_contract, offerer, start_after, limit)?;
+    Ok(CollectionOffersResponse { offers })
+}
+
 pub fn query_auctions(
     deps: Deps,
     active_auctions: bool,
+    nft_contract: Option<String>,
     start_after: Option<AuctionId>,
     limit: Option<u32>,
 ) -> AuctionResult<AuctionsResponse> {
-    let auctions = load_auctions(deps.storage, active_auctions, start_after, limit)?;
+    let nft_contract = nft_contract
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let auctions = load_auctions(
+        deps.storage,
+        active_auctions,
+        nft_contract,
+        start_after,
+        limit,
+    )?;
     Ok(AuctionsResponse { auctions })
 }
@@ -0,0 +1,377 @@
+use crate::escrow::{add_to_escrow, remove_from_escrow};
+use cosmwasm_std::Order::{Ascending, Descending};
+use cosmwasm_std::{
+    Addr, Api, Attribute, BlockInfo, HexBinary, StdError, StdResult, Storage, Timestamp, Uint128,
+};
+use cw_storage_plus::{Bound, Item, Map};
+use sha2::{Digest, Sha256};
+use tracks_auction_api::api::{
+    CreationDeposit, PriceAsset, Raffle, RaffleId, RaffleStatus, RandomnessBeacon, TicketPurchase,
+};
+use tracks_auction_api::error::AuctionError::{
+    InvalidBeaconSignature, InvalidDrawCommitment, InvalidRandomnessBeacon, RaffleIdNotFound,
+};
+use tracks_auction_api::error::AuctionResult;
+
+/// Most ticket purchases a raffle can hold, so refunding them when it's voided stays within
+/// gas limits.
+pub const MAX_TICKET_PURCHASES_PER_RAFFLE: u32 = 100;
+
+/// Seconds after a raffle ends that its winner has to be drawn in, before anyone can void it.
+pub const RAFFLE_DRAW_TIMEOUT: u64 = 7 * 24 * 60 * 60;
+
+const DEFAULT_RAFFLES_QUERY_LIMIT: u32 = 20;
+const MAX_RAFFLES_QUERY_LIMIT: u32 = 100;
+
+const NEXT_RAFFLE_ID: Item<u64> = Item::new("next_raffle_id");
+
+/// All raffles, kept after they're drawn so their winners stay queryable.
+const RAFFLES: Map<RaffleId, Raffle> = Map::new("raffles");
+
+/// Number of open raffles per creator.
+const OPEN_RAFFLE_COUNTS: Map<&Addr, u32> = Map::new("open_raffle_counts");
+
+/// Ticket purchases of each raffle, keyed by raffle ID and the number of the first ticket bought.
+const RAFFLE_TICKETS: Map<(RaffleId, u32), TicketPurchase> = Map::new("raffle_tickets");
+
+pub fn validate_randomness_beacon(beacon: RandomnessBeacon) -> AuctionResult<RandomnessBeacon> {
+    let valid_public_key = match beacon.public_key.as_slice() {
+        [0x02 | 0x03, rest @ ..] => rest.len() == 32,
+        [0x04, rest @ ..] => rest.len() == 64,
+        _ => false,
+    };
+
+    if !valid_public_key || beacon.period == 0 {
+        return Err(InvalidRandomnessBeacon);
+    }
+
+    Ok(beacon)
+}
+
+pub fn randomness_beacon_attributes(beacon: &RandomnessBeacon) -> Vec<Attribute> {
+    vec![
+        Attribute::new("randomness_beacon_public_key", beacon.public_key.to_hex()),
+        Attribute::new(
+            "randomness_beacon_genesis_time",
+            beacon.genesis_time.seconds().to_string(),
+        ),
+        Attribute::new("randomness_beacon_period", beacon.period.to_string()),
+    ]
+}
+
+/// Checks that the signature is the beacon's signature of the round, which is the signature
+/// of the SHA-256 hash of the big-endian round number, as in drand's unchained scheme.
+pub fn verify_beacon_signature(
+    api: &dyn Api,
+    public_key: &HexBinary,
+    round: u64,
+    signature: &HexBinary,
+) -> AuctionResult<()> {
+    let message_hash = Sha256::digest(round.to_be_bytes());
+
+    match api.secp256k1_verify(&message_hash, signature, public_key) {
+        Ok(true) => Ok(()),
+        _ => Err(InvalidBeaconSignature { round }),
+    }
+}
+
+/// Checks that the commitment to a raffle's draw is signed by the beacon, which is the signature
+/// of the SHA-256 hash of the big-endian raffle ID followed by the commitment.
+pub fn verify_draw_commitment(
+    api: &dyn Api,
+    public_key: &HexBinary,
+    id: RaffleId,
+    commitment: &HexBinary,
+    signature: &HexBinary,
+) -> AuctionResult<()> {
+    let message_hash = Sha256::new()
+        .chain_update(id.to_be_bytes())
+        .chain_update(commitment.as_slice())
+        .finalize();
+
+    match api.secp256k1_verify(&message_hash, signature, public_key) {
+        Ok(true) => Ok(()),
+        _ => Err(InvalidDrawCommitment),
+    }
+}
+
+/// Hash a signature is committed to before a raffle's tickets go on sale.
+pub fn draw_commitment(signature: &HexBinary) -> HexBinary {
+    HexBinary::from(&Sha256::digest(signature.as_slice())[..])
+}
+
+/// Draws the winning ticket of a raffle from the beacon's signature of its draw round,
+/// mixed with the entropy of its ticket purchases.
+///
+/// ECDSA signatures are not unique, so the signature has to match the commitment made before
+/// any ticket was sold, leaving the beacon no choice between signatures once it knows the buyers.
+/// The beacon's operator still knows the signature all along, so it can predict the winner of
+/// any purchases: the draw is only fair if the operator doesn't take part in the raffle.
+pub fn draw_winning_ticket(raffle: &Raffle, signature: &HexBinary) -> u32 {
+    let randomness = Sha256::new()
+        .chain_update(signature.as_slice())
+        .chain_update(raffle.id.to_be_bytes())
+        .chain_update(raffle.ticket_entropy.as_slice())
+        .finalize();
+
+    let mut random_bytes = [0u8; 8];
+    random_bytes.copy_from_slice(&randomness[..8]);
+
+    (u64::from_be_bytes(random_bytes) % u64::from(raffle.tickets_sold)) as u32
+}
+
+pub struct CreateRaffleData {
+    pub creator: Addr,
+    pub nft_contract: Addr,
+    pub track_token_id: String,
+    pub ticket_price: Uint128,
+    pub price_asset: PriceAsset,
+    pub fee_bps: u16,
+    pub creation_deposit: Option<CreationDeposit>,
+    pub ends_at: Timestamp,
+    pub max_tickets: Option<u32>,
+    pub beacon: RandomnessBeacon,
+}
+
+pub fn save_new_raffle(
+    storage: &mut dyn Storage,
+    current_block: BlockInfo,
+    raffle_data: CreateRaffleData,
+) -> AuctionResult<RaffleId> {
+    let next_raffle_id = NEXT_RAFFLE_ID.may_load(storage)?.unwrap_or_default();
+    NEXT_RAFFLE_ID.save(storage, &(next_raffle_id + 1))?;
+
+    let open_raffle_count = load_open_raffle_count(storage, &raffle_data.creator)?;
+    OPEN_RAFFLE_COUNTS.save(storage, &raffle_data.creator, &(open_raffle_count + 1))?;
+
+    if let Some(deposit) = &raffle_data.creation_deposit {
+        add_to_escrow(
+            storage,
+            &PriceAsset::native(&deposit.amount.denom),
+            deposit.amount.amount,
+        )?;
+    }
+
+    RAFFLES.save(
+        storage,
+        next_raffle_id,
+        &Raffle {
+            id: next_raffle_id,
+            created_at: current_block,
+            creator: raffle_data.creator,
+            nft_contract: raffle_data.nft_contract,
+            track_token_id: raffle_data.track_token_id,
+            ticket_price: raffle_data.ticket_price,
+            price_asset: raffle_data.price_asset,
+            fee_bps: raffle_data.fee_bps,
+            creation_deposit: raffle_data.creation_deposit,
+            ends_at: raffle_data.ends_at,
+            max_tickets: raffle_data.max_tickets,
+            tickets_sold: 0,
+            draw_round: raffle_data.beacon.first_round_after(raffle_data.ends_at),
+            beacon_public_key: raffle_data.beacon.public_key,
+            status: RaffleStatus::Open,
+            winning_ticket: None,
+            winner: None,
+            draw_deadline: raffle_data.ends_at.plus_seconds(RAFFLE_DRAW_TIMEOUT),
+            draw_commitment: None,
+            ticket_entropy: HexBinary::default(),
+        },
+    )?;
+
+    Ok(next_raffle_id)
+}
+
+pub fn load_raffle(storage: &dyn Storage, id: RaffleId) -> AuctionResult<Option<Raffle>> {
+    Ok(RAFFLES.may_load(storage, id)?)
+}
+
+pub fn save_draw_commitment(
+    storage: &mut dyn Storage,
+    id: RaffleId,
+    commitment: HexBinary,
+) -> AuctionResult<()> {
+    let raffle = load_raffle(storage, id)?.ok_or(RaffleIdNotFound)?;
+
+    RAFFLES.save(
+        storage,
+        id,
+        &Raffle {
+            draw_commitment: Some(commitment),
+            ..raffle
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Records the purchase of the next `count` tickets of the raffle,
+/// mixing the purchase into the raffle's ticket entropy.
+pub fn save_ticket_purchase(
+    storage: &mut dyn Storage,
+    current_block: &BlockInfo,
+    id: RaffleId,
+    buyer: Addr,
+    count: u32,
+) -> AuctionResult<TicketPurchase> {
+    let raffle = load_raffle(storage, id)?.ok_or(RaffleIdNotFound)?;
+
+    let ticket_entropy = Sha256::new()
+        .chain_update(raffle.ticket_entropy.as_slice())
+        .chain_update(buyer.as_bytes())
+        .chain_update(count.to_be_bytes())
+        .chain_update(current_block.height.to_be_bytes())
+        .chain_update(current_block.time.nanos().to_be_bytes())
+        .finalize();
+
+    let purchase = TicketPurchase {
+        buyer,
+        first_ticket: raffle.tickets_sold,
+        count,
+    };
+    RAFFLE_TICKETS.save(storage, (id, purchase.first_ticket), &purchase)?;
+    add_to_escrow(
+        storage,
+        &raffle.price_asset,
+        raffle.ticket_price * Uint128::from(count),
+    )?;
+
+    RAFFLES.save(
+        storage,
+        id,
+        &Raffle {
+            tickets_sold: raffle.tickets_sold + count,
+            ticket_entropy: HexBinary::from(&ticket_entropy[..]),
+            ..raffle
+        },
+    )?;
+
+    Ok(purchase)
+}
+
+/// Counts the ticket purchases of the raffle, up to the given limit.
+pub fn count_ticket_purchases(
+    storage: &dyn Storage,
+    id: RaffleId,
+    limit: u32,
+) -> AuctionResult<u32> {
+    Ok(RAFFLE_TICKETS
+        .prefix(id)
+        .keys(storage, None, None, Ascending)
+        .take(limit as usize)
+        .count() as u32)
+}
+
+/// Loads all ticket purchases of the raffle, which are capped per raffle.
+pub fn load_all_ticket_purchases(
+    storage: &dyn Storage,
+    id: RaffleId,
+) -> AuctionResult<Vec<TicketPurchase>> {
+    Ok(RAFFLE_TICKETS
+        .prefix(id)
+        .range(storage, None, None, Ascending)
+        .map(|res| res.map(|(_, purchase)| purchase))
+        .collect::<StdResult<Vec<TicketPurchase>>>()?)
+}
+
+/// Loads the address that bought the given ticket of the raffle.
+pub fn load_ticket_owner(storage: &dyn Storage, id: RaffleId, ticket: u32) -> AuctionResult<Addr> {
+    // the purchase holding the ticket is the last one starting at or before it
+    let purchase = RAFFLE_TICKETS
+        .prefix(id)
+        .range(storage, None, Some(Bound::inclusive(ticket)), Descending)
+        .next()
+        .transpose()?
+        .map(|(_, purchase)| purchase)
+        .ok_or_else(|| StdError::not_found("raffle ticket"))?;
+
+    Ok(purchase.buyer)
+}
+
+pub fn load_open_raffle_count(storage: &dyn Storage, creator: &Addr) -> AuctionResult<u32> {
+    let count = OPEN_RAFFLE_COUNTS
+        .may_load(storage, creator)?
+        .unwrap_or_default();
+    Ok(count)
+}
+
+/// Moves an open raffle to one of the final statuses.
+pub fn finish_raffle(
+    storage: &mut dyn Storage,
+    id: RaffleId,
+    status: RaffleStatus,
+    winning_ticket: Option<u32>,
+    winner: Option<Addr>,
+) -> AuctionResult<Raffle> {
+    let raffle = load_raffle(storage, id)?.ok_or(RaffleIdNotFound)?;
+
+    let open_raffle_count = load_open_raffle_count(storage, &raffle.creator)?;
+    if open_raffle_count <= 1 {
+        OPEN_RAFFLE_COUNTS.remove(storage, &raffle.creator);
+    } else {
+        OPEN_RAFFLE_COUNTS.save(storage, &raffle.creator, &(open_raffle_count - 1))?;
+    }
+
+    // ticket sales are paid out or refunded, and the creation deposit settled
+    remove_from_escrow(storage, &raffle.price_asset, raffle.ticket_sales())?;
+    if let Some(deposit) = &raffle.creation_deposit {
+        remove_from_escrow(
+            storage,
+            &PriceAsset::native(&deposit.amount.denom),
+            deposit.amount.amount,
+        )?;
+    }
+
+    let raffle = Raffle {
+        status,
+        winning_ticket,
+        winner,
+        ..raffle
+    };
+    RAFFLES.save(storage, id, &raffle)?;
+
+    Ok(raffle)
+}
+
+pub fn load_raffles(
+    storage: &dyn Storage,
+    creator: Option<Addr>,
+    start_after: Option<RaffleId>,
+    limit: Option<u32>,
+) -> AuctionResult<Vec<Raffle>> {
+    let start_after = start_after.map(Bound::exclusive);
+    let limit = limit
+        .unwrap_or(DEFAULT_RAFFLES_QUERY_LIMIT)
+        .min(MAX_RAFFLES_QUERY_LIMIT);
+
+    Ok(RAFFLES
+        .range(storage, start_after, None, Ascending)
+        .map(|res| res.map(|(_, raffle)| raffle))
+        .filter(|res| match res {
+            Ok(raffle) => creator
+                .as_ref()
+                .is_none_or(|creator| raffle.creator == *creator),
+            Err(_) => true,
+        })
+        .take(limit as usize)
+        .collect::<StdResult<Vec<Raffle>>>()?)
+}
+
+pub fn load_raffle_tickets(
+    storage: &dyn Storage,
+    id: RaffleId,
+    start_after: Option<u32>,
+    limit: Option<u32>,
+) -> AuctionResult<Vec<TicketPurchase>> {
+    let start_after = start_after.map(Bound::exclusive);
+    let limit = limit
+        .unwrap_or(DEFAULT_RAFFLES_QUERY_LIMIT)
+        .min(MAX_RAFFLES_QUERY_LIMIT);
+
+    Ok(RAFFLE_TICKETS
+        .prefix(id)
+        .range(storage, start_after, None, Ascending)
+        .map(|res| res.map(|(_, purchase)| purchase))
+        .take(limit as usize)
+        .collect::<StdResult<Vec<TicketPurchase>>>()?)
+}
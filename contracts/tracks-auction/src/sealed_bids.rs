@@ -0,0 +1,234 @@
+use crate::config::load_config;
+use crate::escrow::{add_to_escrow, remove_from_escrow};
+use cosmwasm_std::Order::Ascending;
+use cosmwasm_std::{Addr, Attribute, HexBinary, StdError, StdResult, Storage, SubMsg, Uint128};
+use cw_asset::Asset;
+use cw_storage_plus::Map;
+use sha2::{Digest, Sha256};
+use tracks_auction_api::api::{
+    AuctionId, AuctionKind, PriceAsset, SealedBid, SealedBidAuction, TrackAuction,
+    UnrevealedBidPenalty,
+};
+use tracks_auction_api::error::AuctionError::{
+    InvalidSealedBidAuction, InvalidUnrevealedBidPenalty,
+};
+use tracks_auction_api::error::AuctionResult;
+
+/// Most sealed bids an auction can hold, so settling its collateral stays within gas limits.
+pub const MAX_SEALED_BIDS_PER_AUCTION: u32 = 100;
+
+/// Sealed bids whose collateral is still held, keyed by auction ID and bidder.
+const SEALED_BIDS: Map<(AuctionId, &Addr), SealedBid> = Map::new("sealed_bids");
+
+/// Checks that a sealed-bid auction has a reveal phase.
+/// Bids are hidden, so there is nothing to buy out during the bidding phase.
+pub fn validate_sealed_bid_auction(
+    sealed_bid_auction: &SealedBidAuction,
+    buyout_price: Option<Uint128>,
+) -> AuctionResult<()> {
+    if sealed_bid_auction.reveal_duration == 0 || buyout_price.is_some() {
+        return Err(InvalidSealedBidAuction);
+    }
+
+    Ok(())
+}
+
+pub fn validate_unrevealed_bid_penalty(
+    penalty: UnrevealedBidPenalty,
+) -> AuctionResult<UnrevealedBidPenalty> {
+    if let UnrevealedBidPenalty::Forfeit { bps } = penalty {
+        if bps > 10_000 {
+            return Err(InvalidUnrevealedBidPenalty);
+        }
+    }
+
+    Ok(penalty)
+}
+
+pub fn unrevealed_bid_penalty_attributes(penalty: &UnrevealedBidPenalty) -> Vec<Attribute> {
+    match penalty {
+        UnrevealedBidPenalty::Refund => vec![Attribute::new("unrevealed_bid_penalty", "refund")],
+        UnrevealedBidPenalty::Forfeit { bps } => vec![
+            Attribute::new("unrevealed_bid_penalty", "forfeit"),
+            Attribute::new("unrevealed_bid_penalty_bps", bps.to_string()),
+        ],
+    }
+}
+
+pub fn sealed_bid_commitment(amount: Uint128, salt: &str, bidder: &Addr) -> HexBinary {
+    let hash = Sha256::new()
+        .chain_update(amount.to_be_bytes())
+        .chain_update(salt.as_bytes())
+        .chain_update(bidder.as_bytes())
+        .finalize();
+    HexBinary::from(&hash[..])
+}
+
+pub fn load_sealed_bid(
+    storage: &dyn Storage,
+    auction_id: AuctionId,
+    bidder: &Addr,
+) -> AuctionResult<Option<SealedBid>> {
+    Ok(SEALED_BIDS.may_load(storage, (auction_id, bidder))?)
+}
+
+pub fn save_sealed_bid(
+    storage: &mut dyn Storage,
+    auction: &TrackAuction,
+    sealed_bid: &SealedBid,
+) -> AuctionResult<()> {
+    let previous_collateral = load_sealed_bid(storage, auction.id, &sealed_bid.bidder)?
+        .map(|previous| previous.collateral)
+        .unwrap_or_default();
+    remove_from_escrow(storage, &auction.price_asset, previous_collateral)?;
+    add_to_escrow(storage, &auction.price_asset, sealed_bid.collateral)?;
+
+    SEALED_BIDS.save(storage, (auction.id, &sealed_bid.bidder), sealed_bid)?;
+    Ok(())
+}
+
+pub fn remove_sealed_bid(
+    storage: &mut dyn Storage,
+    auction: &TrackAuction,
+    bidder: &Addr,
+) -> AuctionResult<()> {
+    if let Some(sealed_bid) = load_sealed_bid(storage, auction.id, bidder)? {
+        remove_from_escrow(storage, &auction.price_asset, sealed_bid.collateral)?;
+        SEALED_BIDS.remove(storage, (auction.id, bidder));
+    }
+    Ok(())
+}
+
+pub fn has_sealed_bids(storage: &dyn Storage, auction_id: AuctionId) -> AuctionResult<bool> {
+    Ok(SEALED_BIDS
+        .prefix(auction_id)
+        .keys(storage, None, None, Ascending)
+        .next()
+        .is_some())
+}
+
+/// Counts the sealed bids held for the auction, up to the given limit.
+pub fn count_sealed_bids(
+    storage: &dyn Storage,
+    auction_id: AuctionId,
+    limit: u32,
+) -> AuctionResult<u32> {
+    Ok(SEALED_BIDS
+        .prefix(auction_id)
+        .keys(storage, None, None, Ascending)
+        .take(limit as usize)
+        .count() as u32)
+}
+
+fn load_sealed_bids(storage: &dyn Storage, auction_id: AuctionId) -> AuctionResult<Vec<SealedBid>> {
+    Ok(SEALED_BIDS
+        .prefix(auction_id)
+        .range(storage, None, None, Ascending)
+        .map(|res| res.map(|(_, sealed_bid)| sealed_bid))
+        .collect::<StdResult<Vec<SealedBid>>>()?)
+}
+
+pub fn transfer_collateral_msg(
+    asset: &PriceAsset,
+    recipient: &Addr,
+    amount: Uint128,
+) -> AuctionResult<SubMsg> {
+    Ok(SubMsg::new(
+        Asset::new(asset.to_asset_info(), amount).transfer_msg(recipient.to_string())?,
+    ))
+}
+
+/// Messages and attributes settling the collateral of a sealed-bid auction's bids.
+pub struct SealedBidSettlement {
+    pub messages: Vec<SubMsg>,
+    pub attributes: Vec<Attribute>,
+}
+
+/// Settles the collateral of all the bids still held for a finished sealed-bid auction.
+/// The winner gets back what's left of their collateral after paying the sale price,
+/// unrevealed bids are handled according to the auction's penalty if it ended,
+/// and all other collateral is refunded. Does nothing for other kinds of auctions.
+pub fn settle_sealed_bids(
+    storage: &mut dyn Storage,
+    auction: &TrackAuction,
+    auction_ended: bool,
+) -> AuctionResult<SealedBidSettlement> {
+    if !matches!(auction.kind, AuctionKind::SealedBid(_)) {
+        return Ok(SealedBidSettlement {
+            messages: vec![],
+            attributes: vec![],
+        });
+    }
+
+    let mut messages = vec![];
+    let mut unrevealed_bids = 0u32;
+    let mut forfeited_amount = Uint128::zero();
+    let mut winner_refund = None;
+
+    let winner = auction.active_bid.as_ref();
+    let penalty = auction.unrevealed_bid_penalty.clone().unwrap_or_default();
+
+    for sealed_bid in load_sealed_bids(storage, auction.id)? {
+        remove_sealed_bid(storage, auction, &sealed_bid.bidder)?;
+
+        let refund = match winner {
+            Some(bid) if bid.bidder == sealed_bid.bidder => {
+                let sale_price = auction.sale_price().unwrap_or(bid.amount);
+                let refund = sealed_bid
+                    .collateral
+                    .checked_sub(sale_price)
+                    .map_err(StdError::from)?;
+                winner_refund = Some(refund);
+                refund
+            }
+            // bids are only penalized for not being revealed once the reveal phase is over
+            _ if auction_ended && !sealed_bid.revealed => {
+                unrevealed_bids += 1;
+                let forfeit = match penalty {
+                    UnrevealedBidPenalty::Refund => Uint128::zero(),
+                    UnrevealedBidPenalty::Forfeit { bps } => {
+                        sealed_bid.collateral.multiply_ratio(bps, 10_000u16)
+                    }
+                };
+                forfeited_amount += forfeit;
+                sealed_bid.collateral - forfeit
+            }
+            _ => sealed_bid.collateral,
+        };
+
+        if !refund.is_zero() {
+            messages.push(transfer_collateral_msg(
+                &auction.price_asset,
+                &sealed_bid.bidder,
+                refund,
+            )?);
+        }
+    }
+
+    if !forfeited_amount.is_zero() {
+        let fee_collector = load_config(storage)?.fee_collector;
+        messages.push(transfer_collateral_msg(
+            &auction.price_asset,
+            &fee_collector,
+            forfeited_amount,
+        )?);
+    }
+
+    let mut attributes = if auction_ended {
+        vec![
+            Attribute::new("unrevealed_bids", unrevealed_bids.to_string()),
+            Attribute::new("forfeited_collateral", forfeited_amount.to_string()),
+        ]
+    } else {
+        vec![]
+    };
+    // the winner gets back the collateral they put up above the sale price
+    if let Some(winner_refund) = winner_refund {
+        attributes.push(Attribute::new("winner_refund", winner_refund.to_string()));
+    }
+
+    Ok(SealedBidSettlement {
+        messages,
+        attributes,
+    })
+}
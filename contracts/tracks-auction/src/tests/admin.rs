@@ -0,0 +1,253 @@
+use crate::query::{query_auction, query_config, query_pending_admin};
+use crate::tests::helpers::{
+    create_test_auction, default_duration, instantiate_with_native_price_asset,
+    test_accept_admin_role, test_propose_new_admin, test_update_config, ADMIN, CW20_ADDR, NFT_ADDR,
+    TOKEN1, UANDR, UATOM, USER1, USER2,
+};
+use cosmwasm_std::testing::{mock_dependencies, mock_env};
+use cosmwasm_std::{attr, Addr};
+use tracks_auction_api::api::{
+    AntiSniping, BidIncrement, Config, DurationLimits, PriceAsset, PriceAssetUnchecked,
+    UnrevealedBidPenalty,
+};
+use tracks_auction_api::error::AuctionError::{NoPriceAssets, Unauthorized};
+
+#[test]
+fn update_config_by_non_admin_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let result = test_update_config(
+        deps.as_mut(),
+        USER1,
+        Some(vec![PriceAssetUnchecked::native(UATOM)]),
+    );
+
+    assert_eq!(result, Err(Unauthorized));
+
+    Ok(())
+}
+
+#[test]
+fn update_config_updates_price_assets() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let response = test_update_config(deps.as_mut(), ADMIN, None)?;
+
+    assert_eq!(response.attributes, vec![attr("action", "update_config")]);
+    assert_eq!(
+        query_config(deps.as_ref())?.config.price_assets,
+        vec![PriceAsset::native(UANDR)]
+    );
+
+    let response = test_update_config(
+        deps.as_mut(),
+        ADMIN,
+        Some(vec![PriceAssetUnchecked::cw20(CW20_ADDR)]),
+    )?;
+
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "update_config"),
+            attr("price_asset", "cw20"),
+            attr("price_asset_cw20_contract", CW20_ADDR),
+        ]
+    );
+    assert_eq!(
+        query_config(deps.as_ref())?.config,
+        Config {
+            admin: Addr::unchecked(ADMIN),
+            price_assets: vec![PriceAsset::cw20(Addr::unchecked(CW20_ADDR))],
+            fee_bps: 0,
+            fee_collector: Addr::unchecked(ADMIN),
+            creation_deposit: None,
+            duration_limits: DurationLimits::default(),
+            max_active_auctions_per_creator: None,
+            bid_increment: BidIncrement::default(),
+            anti_sniping: AntiSniping::default(),
+            unrevealed_bid_penalty: UnrevealedBidPenalty::default(),
+            randomness_beacon: None,
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn update_config_only_applies_to_new_auctions() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        None,
+    )?;
+
+    test_update_config(
+        deps.as_mut(),
+        ADMIN,
+        Some(vec![PriceAssetUnchecked::cw20(CW20_ADDR)]),
+    )?;
+
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        "2",
+        USER1,
+        default_duration(),
+        5,
+        None,
+    )?;
+
+    // existing auction keeps its price asset, new auction uses the updated one
+    assert_eq!(
+        query_auction(deps.as_ref(), 0)?.auction.price_asset,
+        PriceAsset::native(UANDR)
+    );
+    assert_eq!(
+        query_auction(deps.as_ref(), 1)?.auction.price_asset,
+        PriceAsset::cw20(Addr::unchecked(CW20_ADDR))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn propose_new_admin_by_non_admin_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let result = test_propose_new_admin(deps.as_mut(), USER1, USER1);
+
+    assert_eq!(result, Err(Unauthorized));
+    assert_eq!(query_pending_admin(deps.as_ref())?.pending_admin, None);
+
+    Ok(())
+}
+
+#[test]
+fn accept_admin_role_by_non_proposed_address_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    // no proposal yet
+    let result = test_accept_admin_role(deps.as_mut(), USER1);
+    assert_eq!(result, Err(Unauthorized));
+
+    test_propose_new_admin(deps.as_mut(), ADMIN, USER1)?;
+
+    let result = test_accept_admin_role(deps.as_mut(), USER2);
+    assert_eq!(result, Err(Unauthorized));
+
+    Ok(())
+}
+
+#[test]
+fn admin_transfer_takes_effect_after_acceptance() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let response = test_propose_new_admin(deps.as_mut(), ADMIN, USER1)?;
+
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "propose_new_admin"),
+            attr("pending_admin", USER1),
+        ]
+    );
+    assert_eq!(
+        query_pending_admin(deps.as_ref())?.pending_admin,
+        Some(Addr::unchecked(USER1))
+    );
+
+    // admin role is not transferred until accepted
+    assert_eq!(
+        query_config(deps.as_ref())?.config.admin,
+        Addr::unchecked(ADMIN)
+    );
+    let result = test_update_config(deps.as_mut(), USER1, None);
+    assert_eq!(result, Err(Unauthorized));
+
+    let response = test_accept_admin_role(deps.as_mut(), USER1)?;
+
+    assert_eq!(
+        response.attributes,
+        vec![attr("action", "accept_admin_role"), attr("admin", USER1),]
+    );
+    assert_eq!(
+        query_config(deps.as_ref())?.config.admin,
+        Addr::unchecked(USER1)
+    );
+    assert_eq!(query_pending_admin(deps.as_ref())?.pending_admin, None);
+
+    // new admin can update the config, old one cannot
+    test_update_config(deps.as_mut(), USER1, None)?;
+    let result = test_update_config(deps.as_mut(), ADMIN, None);
+    assert_eq!(result, Err(Unauthorized));
+
+    Ok(())
+}
+
+#[test]
+fn update_config_with_no_price_assets_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let result = test_update_config(deps.as_mut(), ADMIN, Some(vec![]));
+
+    assert_eq!(result, Err(NoPriceAssets));
+
+    Ok(())
+}
+
+#[test]
+fn update_config_removes_duplicate_price_assets() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    test_update_config(
+        deps.as_mut(),
+        ADMIN,
+        Some(vec![
+            PriceAssetUnchecked::native(UATOM),
+            PriceAssetUnchecked::cw20(CW20_ADDR),
+            PriceAssetUnchecked::native(UATOM),
+        ]),
+    )?;
+
+    assert_eq!(
+        query_config(deps.as_ref())?.config.price_assets,
+        vec![
+            PriceAsset::native(UATOM),
+            PriceAsset::cw20(Addr::unchecked(CW20_ADDR)),
+        ]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,257 @@
+use crate::query::{query_auction, query_config};
+use crate::tests::helpers::{
+    after_height, after_seconds, create_test_auction, instantiate_with_native_price_asset,
+    test_bid, test_resolve_auction, test_update_anti_sniping, ADMIN, NFT_ADDR, TOKEN1, UANDR,
+    USER1, USER2, USER3,
+};
+use cosmwasm_std::testing::{mock_dependencies, mock_env};
+use cosmwasm_std::{attr, coins, DepsMut, Env, Response};
+use cw_utils::Duration;
+use cw_utils::Duration::{Height, Time};
+use cw_utils::Expiration;
+use tracks_auction_api::api::{AntiSniping, ExtensionWindow};
+use tracks_auction_api::error::AuctionError::{
+    AuctionStillInProgress, BiddingAfterAuctionEnded, InvalidAntiSniping,
+};
+use tracks_auction_api::error::AuctionResult;
+
+fn create_auction_with_duration(
+    deps: DepsMut,
+    env: Env,
+    duration: Duration,
+) -> AuctionResult<Response> {
+    create_test_auction(deps, env, NFT_ADDR, TOKEN1, USER1, duration, 5, None)
+}
+
+fn anti_sniping() -> AntiSniping {
+    AntiSniping {
+        time: Some(ExtensionWindow {
+            window: 60,
+            extension: 120,
+        }),
+        height: Some(ExtensionWindow {
+            window: 10,
+            extension: 20,
+        }),
+    }
+}
+
+#[test]
+fn update_anti_sniping_by_admin_updates_config() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let response = test_update_anti_sniping(
+        deps.as_mut(),
+        ADMIN,
+        AntiSniping {
+            height: None,
+            ..anti_sniping()
+        },
+    )?;
+
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "update_config"),
+            attr("anti_sniping_time_window", "60"),
+            attr("anti_sniping_time_extension", "120"),
+            attr("anti_sniping_height_window", "none"),
+            attr("anti_sniping_height_extension", "none"),
+        ]
+    );
+    assert_eq!(
+        query_config(deps.as_ref())?.config.anti_sniping,
+        AntiSniping {
+            height: None,
+            ..anti_sniping()
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn update_anti_sniping_with_zero_values_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    for rule in [
+        ExtensionWindow {
+            window: 0,
+            extension: 10,
+        },
+        ExtensionWindow {
+            window: 10,
+            extension: 0,
+        },
+    ] {
+        let result = test_update_anti_sniping(
+            deps.as_mut(),
+            ADMIN,
+            AntiSniping {
+                time: None,
+                height: Some(rule),
+            },
+        );
+        assert_eq!(result, Err(InvalidAntiSniping));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn bid_within_time_window_extends_auction() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_anti_sniping(deps.as_mut(), ADMIN, anti_sniping())?;
+
+    create_auction_with_duration(deps.as_mut(), env.clone(), Time(600))?;
+
+    let original_end = env.block.time.plus_seconds(600);
+    assert_eq!(
+        query_auction(deps.as_ref(), 0)?.auction.ends_at,
+        Expiration::AtTime(original_end)
+    );
+
+    // outside of the window, the end stays the same
+    let response = test_bid(
+        deps.as_mut(),
+        after_seconds(&env, 540),
+        USER2,
+        0,
+        5,
+        &coins(5, UANDR),
+    )?;
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "bid"),
+            attr("auction_id", "0"),
+            attr("bid_amount", "5"),
+        ]
+    );
+    assert_eq!(
+        query_auction(deps.as_ref(), 0)?.auction.ends_at,
+        Expiration::AtTime(original_end)
+    );
+
+    // within the last 60 seconds, the end is pushed out by 120 seconds
+    let extended_end = Expiration::AtTime(original_end.plus_seconds(120));
+    let response = test_bid(
+        deps.as_mut(),
+        after_seconds(&env, 541),
+        USER3,
+        0,
+        6,
+        &coins(6, UANDR),
+    )?;
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "bid"),
+            attr("auction_id", "0"),
+            attr("bid_amount", "6"),
+            attr("extended_until", extended_end.to_string()),
+        ]
+    );
+    assert_eq!(
+        query_auction(deps.as_ref(), 0)?.auction.ends_at,
+        extended_end
+    );
+
+    // bids are still accepted after the original end
+    test_bid(
+        deps.as_mut(),
+        after_seconds(&env, 700),
+        USER2,
+        0,
+        7,
+        &coins(7, UANDR),
+    )?;
+
+    let result = test_resolve_auction(deps.as_mut(), after_seconds(&env, 800), USER1, 0);
+    assert_eq!(result, Err(AuctionStillInProgress));
+
+    // the last bid extended the auction once more
+    test_resolve_auction(deps.as_mut(), after_seconds(&env, 841), USER1, 0)?;
+
+    Ok(())
+}
+
+#[test]
+fn bid_within_height_window_extends_auction() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_anti_sniping(deps.as_mut(), ADMIN, anti_sniping())?;
+
+    create_auction_with_duration(deps.as_mut(), env.clone(), Height(100))?;
+
+    test_bid(
+        deps.as_mut(),
+        after_height(&env, 95),
+        USER2,
+        0,
+        5,
+        &coins(5, UANDR),
+    )?;
+
+    assert_eq!(
+        query_auction(deps.as_ref(), 0)?.auction.ends_at,
+        Expiration::AtHeight(env.block.height + 120)
+    );
+
+    let result = test_resolve_auction(deps.as_mut(), after_height(&env, 120), USER1, 0);
+    assert_eq!(result, Err(AuctionStillInProgress));
+
+    let result = test_bid(
+        deps.as_mut(),
+        after_height(&env, 121),
+        USER3,
+        0,
+        6,
+        &coins(6, UANDR),
+    );
+    assert_eq!(result, Err(BiddingAfterAuctionEnded));
+
+    test_resolve_auction(deps.as_mut(), after_height(&env, 121), USER1, 0)?;
+
+    Ok(())
+}
+
+#[test]
+fn anti_sniping_rule_is_fixed_at_auction_creation() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    create_auction_with_duration(deps.as_mut(), env.clone(), Height(100))?;
+    assert_eq!(query_auction(deps.as_ref(), 0)?.auction.anti_sniping, None);
+
+    test_update_anti_sniping(deps.as_mut(), ADMIN, anti_sniping())?;
+
+    test_bid(
+        deps.as_mut(),
+        after_height(&env, 95),
+        USER2,
+        0,
+        5,
+        &coins(5, UANDR),
+    )?;
+
+    assert_eq!(
+        query_auction(deps.as_ref(), 0)?.auction.ends_at,
+        Expiration::AtHeight(env.block.height + 100)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,182 @@
+use crate::query::query_config;
+use crate::tests::helpers::{
+    after_height, create_test_auction, instantiate_with_native_price_asset, test_cancel_auction,
+    test_resolve_auction, test_update_auction_limits, ADMIN, NFT_ADDR, TOKEN1, UANDR, USER1, USER2,
+};
+use cosmwasm_std::testing::{mock_dependencies, mock_env};
+use cosmwasm_std::{attr, DepsMut, Env, Response};
+use cw_utils::Duration;
+use cw_utils::Duration::{Height, Time};
+use tracks_auction_api::api::DurationLimits;
+use tracks_auction_api::error::AuctionError::{
+    AuctionDurationTooLong, AuctionDurationTooShort, InvalidAuctionDuration, InvalidDurationLimits,
+    TooManyActiveAuctions,
+};
+use tracks_auction_api::error::AuctionResult;
+
+fn create_auction_with_duration(
+    deps: DepsMut,
+    env: Env,
+    token_id: &str,
+    creator: &str,
+    duration: Duration,
+) -> AuctionResult<Response> {
+    create_test_auction(deps, env, NFT_ADDR, token_id, creator, duration, 5, None)
+}
+
+fn bounded_durations() -> DurationLimits {
+    DurationLimits {
+        min_time: Some(60),
+        max_time: Some(3600),
+        min_height: Some(10),
+        max_height: None,
+    }
+}
+
+#[test]
+fn update_duration_limits_by_admin_updates_config() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let response =
+        test_update_auction_limits(deps.as_mut(), ADMIN, Some(bounded_durations()), Some(3))?;
+
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "update_config"),
+            attr("min_duration_time", "60"),
+            attr("max_duration_time", "3600"),
+            attr("min_duration_height", "10"),
+            attr("max_duration_height", "none"),
+            attr("max_active_auctions_per_creator", "3"),
+        ]
+    );
+
+    let config = query_config(deps.as_ref())?.config;
+    assert_eq!(config.duration_limits, bounded_durations());
+    assert_eq!(config.max_active_auctions_per_creator, Some(3));
+
+    // zero removes the limit
+    test_update_auction_limits(deps.as_mut(), ADMIN, None, Some(0))?;
+
+    let config = query_config(deps.as_ref())?.config;
+    assert_eq!(config.duration_limits, bounded_durations());
+    assert_eq!(config.max_active_auctions_per_creator, None);
+
+    Ok(())
+}
+
+#[test]
+fn update_duration_limits_with_min_above_max_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let result = test_update_auction_limits(
+        deps.as_mut(),
+        ADMIN,
+        Some(DurationLimits {
+            min_height: Some(11),
+            max_height: Some(10),
+            ..DurationLimits::default()
+        }),
+        None,
+    );
+
+    assert_eq!(result, Err(InvalidDurationLimits));
+
+    Ok(())
+}
+
+#[test]
+fn create_auction_outside_duration_limits_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_auction_limits(deps.as_mut(), ADMIN, Some(bounded_durations()), None)?;
+
+    let result = create_auction_with_duration(deps.as_mut(), env.clone(), TOKEN1, USER1, Time(59));
+    assert_eq!(result, Err(AuctionDurationTooShort { min: Time(60) }));
+
+    let result =
+        create_auction_with_duration(deps.as_mut(), env.clone(), TOKEN1, USER1, Time(3601));
+    assert_eq!(result, Err(AuctionDurationTooLong { max: Time(3600) }));
+
+    let result = create_auction_with_duration(deps.as_mut(), env.clone(), TOKEN1, USER1, Height(9));
+    assert_eq!(result, Err(AuctionDurationTooShort { min: Height(10) }));
+
+    let result = create_auction_with_duration(deps.as_mut(), env.clone(), TOKEN1, USER1, Height(0));
+    assert_eq!(result, Err(InvalidAuctionDuration));
+
+    Ok(())
+}
+
+#[test]
+fn create_auction_within_duration_limits_succeeds() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_auction_limits(deps.as_mut(), ADMIN, Some(bounded_durations()), None)?;
+
+    create_auction_with_duration(deps.as_mut(), env.clone(), "1", USER1, Time(60))?;
+    create_auction_with_duration(deps.as_mut(), env.clone(), "2", USER1, Time(3600))?;
+    create_auction_with_duration(deps.as_mut(), env.clone(), "3", USER1, Height(10))?;
+    // no maximum set for height
+    create_auction_with_duration(deps.as_mut(), env.clone(), "4", USER1, Height(1_000_000))?;
+
+    Ok(())
+}
+
+#[test]
+fn create_auction_over_active_auction_limit_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_auction_limits(deps.as_mut(), ADMIN, None, Some(2))?;
+
+    create_auction_with_duration(deps.as_mut(), env.clone(), "1", USER1, Height(15))?;
+    create_auction_with_duration(deps.as_mut(), env.clone(), "2", USER1, Height(15))?;
+
+    let result = create_auction_with_duration(deps.as_mut(), env.clone(), "3", USER1, Height(15));
+    assert_eq!(result, Err(TooManyActiveAuctions { max: 2 }));
+
+    // the limit is per creator
+    create_auction_with_duration(deps.as_mut(), env.clone(), "3", USER2, Height(15))?;
+
+    Ok(())
+}
+
+#[test]
+fn finished_auctions_do_not_count_towards_active_auction_limit() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_auction_limits(deps.as_mut(), ADMIN, None, Some(2))?;
+
+    create_auction_with_duration(deps.as_mut(), env.clone(), "1", USER1, Height(15))?;
+    create_auction_with_duration(deps.as_mut(), env.clone(), "2", USER1, Height(15))?;
+
+    test_cancel_auction(deps.as_mut(), env.clone(), USER1, 0)?;
+
+    create_auction_with_duration(deps.as_mut(), env.clone(), "1", USER1, Height(15))?;
+
+    let result = create_auction_with_duration(deps.as_mut(), env.clone(), "3", USER1, Height(15));
+    assert_eq!(result, Err(TooManyActiveAuctions { max: 2 }));
+
+    let env = after_height(&env, 16);
+    test_resolve_auction(deps.as_mut(), env.clone(), USER1, 1)?;
+    test_resolve_auction(deps.as_mut(), env.clone(), USER1, 2)?;
+
+    create_auction_with_duration(deps.as_mut(), env.clone(), "1", USER1, Height(15))?;
+    create_auction_with_duration(deps.as_mut(), env.clone(), "2", USER1, Height(15))?;
+
+    Ok(())
+}
@@ -1,14 +1,15 @@
 use crate::query::{query_auction, query_auctions};
 use crate::tests::helpers::{
-    after_height, after_seconds, create_test_auction, default_duration,
-    instantiate_with_native_price_asset, no_funds, test_bid, test_cw20_bid, transfer_native_funds,
-    transfer_nft_msg, ADMIN, CW20_ADDR, NFT_ADDR, TOKEN1, UANDR, UATOM, USER1, USER2, USER3,
+    after_height, after_seconds, create_test_auction, create_test_auction_in_asset,
+    default_duration, instantiate_with_native_price_asset, no_funds, test_bid, test_cw20_bid,
+    test_update_config, transfer_native_funds, transfer_nft_msg, ADMIN, CW20_ADDR, NFT_ADDR,
+    TOKEN1, UANDR, UATOM, USER1, USER2, USER3,
 };
 use cosmwasm_std::testing::{mock_dependencies, mock_env};
 use cosmwasm_std::{attr, coin, coins, Addr, BlockInfo, Env, Timestamp};
 use cw_utils::Duration::{Height, Time};
 use tracks_auction_api::api::AuctionStatus::Resolved;
-use tracks_auction_api::api::{Bid, PriceAsset};
+use tracks_auction_api::api::{Bid, PriceAsset, PriceAssetUnchecked};
 use tracks_auction_api::error::AuctionError::{
     AuctionIdNotFound, BidLowerThanMinimum, BidWrongAsset, BiddingAfterAuctionEnded,
     InsufficientFundsForBid, NoBidFundsSupplied, Unauthorized, UnnecessaryAssetsForBid,
@@ -96,7 +97,7 @@ fn bid_with_multiple_native_assets_fails() -> anyhow::Result<()> {
         USER2,
         0,
         5,
-        &vec![coin(5, UANDR), coin(1, UATOM)],
+        &[coin(5, UANDR), coin(1, UATOM)],
     );
 
     assert_eq!(result, Err(UnnecessaryAssetsForBid));
@@ -194,7 +195,12 @@ fn bid_less_than_minimum_bid_fails() -> anyhow::Result<()> {
         &coins(bid_amount.into(), UANDR),
     );
 
-    assert_eq!(result, Err(BidLowerThanMinimum));
+    assert_eq!(
+        result,
+        Err(BidLowerThanMinimum {
+            minimum: minimum_bid_amount.into()
+        })
+    );
 
     Ok(())
 }
@@ -408,7 +414,12 @@ fn bid_after_existing_bid_at_current_amount_fails() -> anyhow::Result<()> {
         &coins(second_bid_amount.into(), UANDR),
     );
 
-    assert_eq!(result, Err(BidLowerThanMinimum));
+    assert_eq!(
+        result,
+        Err(BidLowerThanMinimum {
+            minimum: (first_bid_amount + 1).into()
+        })
+    );
 
     Ok(())
 }
@@ -596,17 +607,20 @@ fn bid_buyout_price_instantly_wins_the_auction() -> anyhow::Result<()> {
         vec![
             attr("action", "instant_buyout"),
             attr("auction_id", "0"),
-            attr("bid_amount", buyout_price.to_string())
+            attr("bid_amount", buyout_price.to_string()),
+            attr("fee_bps", "0"),
+            attr("fee_amount", "0"),
+            attr("creator_amount", buyout_price.to_string())
         ],
     );
 
     assert_eq!(query_auction(deps.as_ref(), 0)?.auction.status, Resolved);
 
-    assert!(query_auctions(deps.as_ref(), true, None, None)?
+    assert!(query_auctions(deps.as_ref(), true, None, None, None)?
         .auctions
         .is_empty());
     assert_eq!(
-        query_auctions(deps.as_ref(), false, None, None)?.auctions[0].status,
+        query_auctions(deps.as_ref(), false, None, None, None)?.auctions[0].status,
         Resolved
     );
 
@@ -650,3 +664,57 @@ fn bid_buyout_price_refunds_previous_bid() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn bid_is_validated_against_auction_price_asset() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_config(
+        deps.as_mut(),
+        ADMIN,
+        Some(vec![
+            PriceAssetUnchecked::native(UANDR),
+            PriceAssetUnchecked::native(UATOM),
+        ]),
+    )?;
+
+    create_test_auction_in_asset(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        None,
+        PriceAssetUnchecked::native(UATOM),
+    )?;
+
+    // accepted by the config, but not the asset this auction is priced in
+    let result = test_bid(deps.as_mut(), env.clone(), USER2, 0, 5, &coins(5, UANDR));
+    assert_eq!(result, Err(BidWrongAsset));
+
+    test_bid(deps.as_mut(), env.clone(), USER2, 0, 5, &coins(5, UATOM))?;
+
+    assert_eq!(
+        query_auction(deps.as_ref(), 0)?.auction.active_bid,
+        Some(Bid {
+            amount: 5u8.into(),
+            asset: PriceAsset::native(UATOM),
+            bidder: Addr::unchecked(USER2),
+            posted_at: env.block,
+        })
+    );
+
+    // removing the asset from the config does not affect the existing auction
+    test_update_config(
+        deps.as_mut(),
+        ADMIN,
+        Some(vec![PriceAssetUnchecked::native(UANDR)]),
+    )?;
+    test_bid(deps.as_mut(), mock_env(), USER3, 0, 6, &coins(6, UATOM))?;
+
+    Ok(())
+}
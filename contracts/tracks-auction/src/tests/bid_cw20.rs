@@ -78,7 +78,7 @@ fn bid_cw20_with_insufficient_funds_fails() -> anyhow::Result<()> {
         0,
         bid_amount,
         funds_for_bid,
-        &CW20_ADDR,
+        CW20_ADDR,
     );
 
     assert_eq!(result, Err(InsufficientFundsForBid));
@@ -104,7 +104,7 @@ fn bid_cw20_on_own_auction_fails() -> anyhow::Result<()> {
         None,
     )?;
 
-    let result = test_cw20_bid(deps.as_mut(), env.clone(), USER1, 0, 5, 5, &CW20_ADDR);
+    let result = test_cw20_bid(deps.as_mut(), env.clone(), USER1, 0, 5, 5, CW20_ADDR);
 
     assert_eq!(result, Err(Unauthorized));
 
@@ -142,7 +142,12 @@ fn bid_cw20_less_than_minimum_bid_fails() -> anyhow::Result<()> {
         CW20_ADDR,
     );
 
-    assert_eq!(result, Err(BidLowerThanMinimum));
+    assert_eq!(
+        result,
+        Err(BidLowerThanMinimum {
+            minimum: minimum_bid_amount.into()
+        })
+    );
 
     Ok(())
 }
@@ -351,7 +356,12 @@ fn bid_cw20_after_existing_bid_at_current_amount_fails() -> anyhow::Result<()> {
         CW20_ADDR,
     );
 
-    assert_eq!(result, Err(BidLowerThanMinimum));
+    assert_eq!(
+        result,
+        Err(BidLowerThanMinimum {
+            minimum: (first_bid_amount + 1).into()
+        })
+    );
 
     Ok(())
 }
@@ -544,17 +554,20 @@ fn bid_cw20_buyout_price_instantly_wins_the_auction() -> anyhow::Result<()> {
         vec![
             attr("action", "instant_buyout"),
             attr("auction_id", "0"),
-            attr("bid_amount", buyout_price.to_string())
+            attr("bid_amount", buyout_price.to_string()),
+            attr("fee_bps", "0"),
+            attr("fee_amount", "0"),
+            attr("creator_amount", buyout_price.to_string())
         ],
     );
 
     assert_eq!(query_auction(deps.as_ref(), 0)?.auction.status, Resolved);
 
-    assert!(query_auctions(deps.as_ref(), true, None, None)?
+    assert!(query_auctions(deps.as_ref(), true, None, None, None)?
         .auctions
         .is_empty());
     assert_eq!(
-        query_auctions(deps.as_ref(), false, None, None)?.auctions[0].status,
+        query_auctions(deps.as_ref(), false, None, None, None)?.auctions[0].status,
         Resolved
     );
 
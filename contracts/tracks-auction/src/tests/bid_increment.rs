@@ -0,0 +1,277 @@
+use crate::execute::bid;
+use crate::query::{query_auction, query_config};
+use crate::tests::helpers::{
+    create_test_auction, default_duration, instantiate_with_native_price_asset, send_test_nft,
+    test_bid, test_update_bid_increment, ADMIN, NFT_ADDR, TOKEN1, UANDR, USER1, USER2, USER3,
+};
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{
+    attr, coins, DepsMut, Env, OverflowError, OverflowOperation, Response, StdError, Uint128,
+};
+use tracks_auction_api::api::{BidIncrement, PriceAssetUnchecked};
+use tracks_auction_api::error::AuctionError::{BidLowerThanMinimum, InvalidBidIncrement};
+use tracks_auction_api::error::AuctionResult;
+use tracks_auction_api::msg::Cw721HookMsg::CreateAuction;
+
+fn create_auction_with_increment(
+    deps: DepsMut,
+    env: Env,
+    bid_increment: Option<BidIncrement>,
+) -> AuctionResult<Response> {
+    send_test_nft(
+        deps,
+        env,
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        &CreateAuction {
+            duration: default_duration(),
+            start: None,
+            minimum_bid_amount: Uint128::new(5),
+            buyout_price: None,
+            price_asset: PriceAssetUnchecked::native(UANDR),
+            bid_increment,
+            kind: None,
+            second_price: None,
+            reserve_price: None,
+            relist: None,
+        },
+    )
+}
+
+#[test]
+fn auctions_use_increment_of_one_by_default() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        None,
+    )?;
+
+    assert_eq!(
+        query_auction(deps.as_ref(), 0)?.auction.bid_increment,
+        BidIncrement::Absolute {
+            amount: Uint128::one()
+        }
+    );
+
+    test_bid(deps.as_mut(), env.clone(), USER2, 0, 5, &coins(5, UANDR))?;
+    test_bid(deps.as_mut(), env.clone(), USER3, 0, 6, &coins(6, UANDR))?;
+
+    Ok(())
+}
+
+#[test]
+fn update_bid_increment_by_admin_updates_config() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let response =
+        test_update_bid_increment(deps.as_mut(), ADMIN, BidIncrement::Percentage { bps: 500 })?;
+
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "update_config"),
+            attr("bid_increment", "percentage"),
+            attr("bid_increment_bps", "500"),
+        ]
+    );
+    assert_eq!(
+        query_config(deps.as_ref())?.config.bid_increment,
+        BidIncrement::Percentage { bps: 500 }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn invalid_bid_increment_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    for bid_increment in [
+        BidIncrement::Absolute {
+            amount: Uint128::zero(),
+        },
+        BidIncrement::Percentage { bps: 0 },
+        BidIncrement::Percentage { bps: 10_001 },
+    ] {
+        let result = test_update_bid_increment(deps.as_mut(), ADMIN, bid_increment.clone());
+        assert_eq!(result, Err(InvalidBidIncrement));
+
+        let result = create_auction_with_increment(deps.as_mut(), env.clone(), Some(bid_increment));
+        assert_eq!(result, Err(InvalidBidIncrement));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn bid_below_percentage_increment_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_bid_increment(
+        deps.as_mut(),
+        ADMIN,
+        BidIncrement::Percentage { bps: 1_000 },
+    )?;
+
+    create_auction_with_increment(deps.as_mut(), env.clone(), None)?;
+
+    test_bid(
+        deps.as_mut(),
+        env.clone(),
+        USER2,
+        0,
+        100,
+        &coins(100, UANDR),
+    )?;
+
+    let result = test_bid(
+        deps.as_mut(),
+        env.clone(),
+        USER3,
+        0,
+        109,
+        &coins(109, UANDR),
+    );
+    assert_eq!(
+        result,
+        Err(BidLowerThanMinimum {
+            minimum: Uint128::new(110)
+        })
+    );
+
+    test_bid(
+        deps.as_mut(),
+        env.clone(),
+        USER3,
+        0,
+        110,
+        &coins(110, UANDR),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn percentage_increment_is_rounded_up() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    create_auction_with_increment(
+        deps.as_mut(),
+        env.clone(),
+        Some(BidIncrement::Percentage { bps: 1_000 }),
+    )?;
+
+    // 10% of 15 is 1.5, rounded up to 2
+    test_bid(deps.as_mut(), env.clone(), USER2, 0, 15, &coins(15, UANDR))?;
+
+    let result = test_bid(deps.as_mut(), env.clone(), USER3, 0, 16, &coins(16, UANDR));
+    assert_eq!(
+        result,
+        Err(BidLowerThanMinimum {
+            minimum: Uint128::new(17)
+        })
+    );
+
+    // 0.01% of 17 is rounded up to 1, so bids always have to be strictly higher
+    assert_eq!(
+        BidIncrement::Percentage { bps: 1 }.increment_over(Uint128::new(17)),
+        Uint128::one()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn per_auction_increment_overrides_default() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_bid_increment(
+        deps.as_mut(),
+        ADMIN,
+        BidIncrement::Percentage { bps: 1_000 },
+    )?;
+
+    let bid_increment = BidIncrement::Absolute {
+        amount: Uint128::new(10),
+    };
+    create_auction_with_increment(deps.as_mut(), env.clone(), Some(bid_increment.clone()))?;
+
+    assert_eq!(
+        query_auction(deps.as_ref(), 0)?.auction.bid_increment,
+        bid_increment
+    );
+
+    test_bid(deps.as_mut(), env.clone(), USER2, 0, 5, &coins(5, UANDR))?;
+
+    let result = test_bid(deps.as_mut(), env.clone(), USER3, 0, 14, &coins(14, UANDR));
+    assert_eq!(
+        result,
+        Err(BidLowerThanMinimum {
+            minimum: Uint128::new(15)
+        })
+    );
+
+    test_bid(deps.as_mut(), env.clone(), USER3, 0, 15, &coins(15, UANDR))?;
+
+    Ok(())
+}
+
+#[test]
+fn bid_over_maximum_active_bid_fails_instead_of_overflowing() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_auction_with_increment(deps.as_mut(), env.clone(), None)?;
+
+    bid(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(USER2, &coins(u128::MAX, UANDR)),
+        0,
+        Uint128::MAX,
+    )?;
+
+    let result = bid(
+        deps.as_mut(),
+        env,
+        mock_info(USER3, &coins(u128::MAX, UANDR)),
+        0,
+        Uint128::MAX,
+    );
+    assert_eq!(
+        result,
+        Err(StdError::overflow(OverflowError::new(
+            OverflowOperation::Add,
+            Uint128::MAX,
+            Uint128::one()
+        ))
+        .into())
+    );
+
+    Ok(())
+}
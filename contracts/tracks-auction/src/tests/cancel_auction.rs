@@ -182,11 +182,11 @@ fn cancel_auction_with_no_bids_sends_back_nft_to_creator() -> anyhow::Result<()>
 
     assert_eq!(query_auction(deps.as_ref(), 0)?.auction.status, Canceled);
 
-    assert!(query_auctions(deps.as_ref(), true, None, None)?
+    assert!(query_auctions(deps.as_ref(), true, None, None, None)?
         .auctions
         .is_empty());
     assert_eq!(
-        query_auctions(deps.as_ref(), false, None, None)?.auctions[0].status,
+        query_auctions(deps.as_ref(), false, None, None, None)?.auctions[0].status,
         Canceled
     );
 
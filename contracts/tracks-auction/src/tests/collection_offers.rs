@@ -0,0 +1,346 @@
+use crate::execute::{
+    make_collection_offer, refund_expired_collection_offer, retract_collection_offer,
+    CollectionOfferTerms,
+};
+use crate::query::{query_collection_offer, query_collection_offers};
+use crate::tests::helpers::{
+    after_seconds, instantiate_with_native_price_asset, send_test_nft, transfer_native_funds,
+    transfer_nft_msg, ADMIN, NFT_ADDR, TOKEN1, UANDR, USER1, USER2, USER3,
+};
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{
+    attr, coins, from_json, to_json_binary, Addr, Coin, ContractResult, DepsMut, Empty, Env,
+    MemoryStorage, OwnedDeps, Response, SystemResult, Uint128, WasmQuery,
+};
+use cw721::Cw721QueryMsg::NftInfo;
+use cw721::NftInfoResponse;
+use cw721_tracks_api::api::TrackMetadata;
+use cw_utils::Expiration;
+use tracks_auction_api::api::OfferTarget;
+use tracks_auction_api::error::AuctionError::{
+    CollectionOfferIdNotFound, InsufficientFundsForOffer, InvalidOfferQuantity, OfferExpired,
+    OfferNotExpired, TokenDoesNotMatchOffer, Unauthorized,
+};
+use tracks_auction_api::error::AuctionResult;
+use tracks_auction_api::msg::Cw721HookMsg::AcceptCollectionOffer;
+
+const ARTIST: &str = "artist";
+
+/// Mocks the NFT contract, where tokens "1" and "2" are by the verified artist,
+/// and any other token only claims the artist's name.
+fn mock_dependencies_with_tracks() -> OwnedDeps<MemoryStorage, MockApi, MockQuerier, Empty> {
+    let mut deps = mock_dependencies();
+
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == NFT_ADDR => {
+            let NftInfo { token_id } = from_json(msg).unwrap() else {
+                panic!("unexpected query")
+            };
+            let artist_address = match token_id.as_str() {
+                "1" | "2" => Some(Addr::unchecked(ARTIST)),
+                _ => None,
+            };
+            SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&NftInfoResponse {
+                    token_uri: None,
+                    extension: TrackMetadata {
+                        artist_name: "Boden".to_string(),
+                        artist_address,
+                        album: None,
+                        track_name: format!("Track {token_id}"),
+                        audio_track_url: "https://www.usdebtclock.org/".to_string(),
+                        edition: None,
+                    },
+                })
+                .unwrap(),
+            ))
+        }
+        _ => panic!("unexpected query"),
+    });
+
+    deps
+}
+
+fn test_make_collection_offer(
+    deps: DepsMut,
+    env: Env,
+    offerer: &str,
+    target: OfferTarget,
+    quantity: u32,
+    funds: &[Coin],
+) -> AuctionResult<Response> {
+    let expires = Expiration::AtTime(env.block.time.plus_seconds(600));
+    make_collection_offer(
+        deps,
+        env,
+        mock_info(offerer, funds),
+        CollectionOfferTerms {
+            nft_contract: NFT_ADDR.to_string(),
+            target,
+            price_per_token: Uint128::new(10),
+            quantity,
+            expires,
+        },
+    )
+}
+
+fn test_accept_collection_offer(
+    deps: DepsMut,
+    env: Env,
+    owner: &str,
+    token_id: &str,
+    offer_id: u64,
+) -> AuctionResult<Response> {
+    send_test_nft(
+        deps,
+        env,
+        NFT_ADDR,
+        token_id,
+        owner,
+        &AcceptCollectionOffer { offer_id },
+    )
+}
+
+#[test]
+fn collection_offer_is_filled_by_several_owners() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies_with_tracks();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    // overpayment is returned to the offerer
+    let response = test_make_collection_offer(
+        deps.as_mut(),
+        env.clone(),
+        USER3,
+        OfferTarget::AnyToken {},
+        2,
+        &coins(25, UANDR),
+    )?;
+    assert_eq!(
+        response.messages,
+        vec![transfer_native_funds(UANDR, 5, USER3)?]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "make_collection_offer"),
+            attr("offer_id", "0"),
+            attr("price_per_token", "10"),
+            attr("quantity", "2"),
+            attr("excess_refund", "5"),
+        ]
+    );
+
+    let response = test_accept_collection_offer(deps.as_mut(), env.clone(), USER1, TOKEN1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_native_funds(UANDR, 10, USER1)?,
+            transfer_nft_msg(NFT_ADDR, USER3, TOKEN1)?,
+        ]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "accept_collection_offer"),
+            attr("offer_id", "0"),
+            attr("token_id", TOKEN1),
+            attr("price", "10"),
+            attr("remaining_quantity", "1"),
+            attr("fee_bps", "0"),
+            attr("fee_amount", "0"),
+            attr("creator_amount", "10"),
+        ]
+    );
+
+    let offer = query_collection_offer(deps.as_ref(), 0)?.offer;
+    assert_eq!(offer.remaining_quantity, 1);
+    assert_eq!(offer.escrowed_amount(), Uint128::new(10));
+
+    test_accept_collection_offer(deps.as_mut(), env, USER2, "3", 0)?;
+
+    assert_eq!(
+        query_collection_offer(deps.as_ref(), 0),
+        Err(CollectionOfferIdNotFound)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn artist_offer_matches_artist_name() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies_with_tracks();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_make_collection_offer(
+        deps.as_mut(),
+        env.clone(),
+        USER3,
+        OfferTarget::ArtistName {
+            artist_name: "Someone else".to_string(),
+        },
+        1,
+        &coins(10, UANDR),
+    )?;
+    test_make_collection_offer(
+        deps.as_mut(),
+        env.clone(),
+        USER3,
+        OfferTarget::ArtistName {
+            artist_name: "Boden".to_string(),
+        },
+        1,
+        &coins(10, UANDR),
+    )?;
+
+    let result = test_accept_collection_offer(deps.as_mut(), env.clone(), USER1, "3", 0);
+    assert_eq!(result, Err(TokenDoesNotMatchOffer));
+
+    // the name alone is enough, even without a verified artist
+    test_accept_collection_offer(deps.as_mut(), env, USER1, "3", 1)?;
+
+    Ok(())
+}
+
+#[test]
+fn verified_artist_offer_matches_artist_address() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies_with_tracks();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_make_collection_offer(
+        deps.as_mut(),
+        env.clone(),
+        USER3,
+        OfferTarget::VerifiedArtist {
+            artist_address: ARTIST.to_string(),
+        },
+        2,
+        &coins(20, UANDR),
+    )?;
+
+    // claims the artist's name, but wasn't minted by them
+    let result = test_accept_collection_offer(deps.as_mut(), env.clone(), USER1, "3", 0);
+    assert_eq!(result, Err(TokenDoesNotMatchOffer));
+
+    test_accept_collection_offer(deps.as_mut(), env, USER1, "2", 0)?;
+
+    Ok(())
+}
+
+#[test]
+fn accept_invalid_collection_offer_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies_with_tracks();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_make_collection_offer(
+        deps.as_mut(),
+        env.clone(),
+        USER3,
+        OfferTarget::AnyToken {},
+        1,
+        &coins(10, UANDR),
+    )?;
+
+    let result = test_accept_collection_offer(deps.as_mut(), env.clone(), USER3, TOKEN1, 0);
+    assert_eq!(result, Err(Unauthorized));
+
+    let result =
+        test_accept_collection_offer(deps.as_mut(), after_seconds(&env, 600), USER1, TOKEN1, 0);
+    assert_eq!(result, Err(OfferExpired));
+
+    let result = test_accept_collection_offer(deps.as_mut(), env, USER1, TOKEN1, 1);
+    assert_eq!(result, Err(CollectionOfferIdNotFound));
+
+    Ok(())
+}
+
+#[test]
+fn make_invalid_collection_offer_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies_with_tracks();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let result = test_make_collection_offer(
+        deps.as_mut(),
+        env.clone(),
+        USER3,
+        OfferTarget::AnyToken {},
+        3,
+        &coins(25, UANDR),
+    );
+    assert_eq!(result, Err(InsufficientFundsForOffer));
+
+    let result = test_make_collection_offer(
+        deps.as_mut(),
+        env,
+        USER3,
+        OfferTarget::AnyToken {},
+        0,
+        &coins(10, UANDR),
+    );
+    assert_eq!(result, Err(InvalidOfferQuantity));
+
+    Ok(())
+}
+
+#[test]
+fn retract_and_refund_collection_offers() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies_with_tracks();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    for _ in 0..2 {
+        test_make_collection_offer(
+            deps.as_mut(),
+            env.clone(),
+            USER3,
+            OfferTarget::AnyToken {},
+            3,
+            &coins(30, UANDR),
+        )?;
+    }
+    test_accept_collection_offer(deps.as_mut(), env.clone(), USER1, TOKEN1, 0)?;
+
+    let result = retract_collection_offer(deps.as_mut(), mock_info(USER1, &[]), 0);
+    assert_eq!(result, Err(Unauthorized));
+
+    // only the tokens still wanted are refunded
+    let response = retract_collection_offer(deps.as_mut(), mock_info(USER3, &[]), 0)?;
+    assert_eq!(
+        response.messages,
+        vec![transfer_native_funds(UANDR, 20, USER3)?]
+    );
+
+    let result = refund_expired_collection_offer(deps.as_mut(), after_seconds(&env, 599), 1);
+    assert_eq!(result, Err(OfferNotExpired));
+
+    let response = refund_expired_collection_offer(deps.as_mut(), after_seconds(&env, 600), 1)?;
+    assert_eq!(
+        response.messages,
+        vec![transfer_native_funds(UANDR, 30, USER3)?]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "refund_expired_collection_offer"),
+            attr("offer_id", "1"),
+        ]
+    );
+
+    let offers = query_collection_offers(
+        deps.as_ref(),
+        Some(NFT_ADDR.to_string()),
+        Some(USER3.to_string()),
+        None,
+        None,
+    )?
+    .offers;
+    assert!(offers.is_empty());
+
+    Ok(())
+}
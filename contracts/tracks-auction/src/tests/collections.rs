@@ -0,0 +1,280 @@
+use crate::query::{query_auctions, query_collection, query_collections};
+use crate::tests::helpers::{
+    after_seconds, create_test_auction, default_duration, instantiate_with_native_price_asset,
+    test_add_collection, test_remove_collection, test_resolve_auction, ADMIN, NFT_ADDR, NFT_ADDR2,
+    TOKEN1, UANDR, USER1,
+};
+use cosmwasm_std::testing::{mock_dependencies, mock_env};
+use cosmwasm_std::{attr, Addr, Uint128};
+use tracks_auction_api::api::{AuctionId, CollectionSettings, TrackAuction, WhitelistedCollection};
+use tracks_auction_api::error::AuctionError::{
+    Cw721NotWhitelisted, MinimumBidBelowCollectionFloor, Unauthorized,
+};
+
+#[test]
+fn add_collection_by_non_admin_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let result = test_add_collection(deps.as_mut(), USER1, NFT_ADDR2, None);
+
+    assert_eq!(result, Err(Unauthorized));
+
+    Ok(())
+}
+
+#[test]
+fn add_collection_whitelists_it_for_auctions() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let settings = CollectionSettings {
+        label: Some("Label records".to_string()),
+        minimum_bid_floor: None,
+    };
+    let response = test_add_collection(deps.as_mut(), ADMIN, NFT_ADDR2, Some(settings.clone()))?;
+
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "add_collection"),
+            attr("nft_contract", NFT_ADDR2),
+        ]
+    );
+    assert_eq!(
+        query_collection(deps.as_ref(), NFT_ADDR2.to_string())?.collection,
+        WhitelistedCollection {
+            nft_contract: Addr::unchecked(NFT_ADDR2),
+            settings,
+        }
+    );
+
+    // both collections can now be auctioned
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        None,
+    )?;
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR2,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        None,
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn add_existing_collection_updates_its_settings() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let settings = CollectionSettings {
+        label: None,
+        minimum_bid_floor: Some(Uint128::new(10)),
+    };
+    test_add_collection(deps.as_mut(), ADMIN, NFT_ADDR, Some(settings.clone()))?;
+
+    assert_eq!(
+        query_collections(deps.as_ref(), None, None)?.collections,
+        vec![WhitelistedCollection {
+            nft_contract: Addr::unchecked(NFT_ADDR),
+            settings,
+        }]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn create_auction_below_collection_floor_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    test_add_collection(
+        deps.as_mut(),
+        ADMIN,
+        NFT_ADDR,
+        Some(CollectionSettings {
+            label: None,
+            minimum_bid_floor: Some(Uint128::new(10)),
+        }),
+    )?;
+
+    let result = create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        9,
+        None,
+    );
+
+    assert_eq!(
+        result,
+        Err(MinimumBidBelowCollectionFloor {
+            floor: Uint128::new(10)
+        })
+    );
+
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        10,
+        None,
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn remove_collection_by_non_admin_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let result = test_remove_collection(deps.as_mut(), USER1, NFT_ADDR);
+
+    assert_eq!(result, Err(Unauthorized));
+
+    Ok(())
+}
+
+#[test]
+fn remove_non_whitelisted_collection_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let result = test_remove_collection(deps.as_mut(), ADMIN, NFT_ADDR2);
+
+    assert_eq!(result, Err(Cw721NotWhitelisted));
+
+    Ok(())
+}
+
+#[test]
+fn remove_collection_blocks_only_new_auctions() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        None,
+    )?;
+
+    let response = test_remove_collection(deps.as_mut(), ADMIN, NFT_ADDR)?;
+
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "remove_collection"),
+            attr("nft_contract", NFT_ADDR),
+        ]
+    );
+    assert_eq!(
+        query_collection(deps.as_ref(), NFT_ADDR.to_string()),
+        Err(Cw721NotWhitelisted)
+    );
+
+    let result = create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        "2",
+        USER1,
+        default_duration(),
+        5,
+        None,
+    );
+    assert_eq!(result, Err(Cw721NotWhitelisted));
+
+    // existing auction can still be resolved
+    test_resolve_auction(deps.as_mut(), after_seconds(&env, 601), USER1, 0)?;
+
+    Ok(())
+}
+
+#[test]
+fn query_auctions_filters_by_nft_contract() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_add_collection(deps.as_mut(), ADMIN, NFT_ADDR2, None)?;
+
+    for nft_contract in [NFT_ADDR, NFT_ADDR2, NFT_ADDR, NFT_ADDR2] {
+        create_test_auction(
+            deps.as_mut(),
+            env.clone(),
+            nft_contract,
+            TOKEN1,
+            USER1,
+            default_duration(),
+            5,
+            None,
+        )?;
+    }
+
+    let response = query_auctions(deps.as_ref(), true, Some(NFT_ADDR2.to_string()), None, None)?;
+    assert_eq!(auction_ids(response.auctions), vec![1, 3]);
+
+    // pagination applies to the filtered auctions
+    let response = query_auctions(
+        deps.as_ref(),
+        true,
+        Some(NFT_ADDR.to_string()),
+        None,
+        Some(1),
+    )?;
+    assert_eq!(auction_ids(response.auctions), vec![0]);
+
+    let response = query_auctions(
+        deps.as_ref(),
+        true,
+        Some(NFT_ADDR.to_string()),
+        Some(0),
+        None,
+    )?;
+    assert_eq!(auction_ids(response.auctions), vec![2]);
+
+    Ok(())
+}
+
+fn auction_ids(auctions: Vec<TrackAuction>) -> Vec<AuctionId> {
+    auctions.into_iter().map(|auction| auction.id).collect()
+}
@@ -1,15 +1,21 @@
 use crate::query::{query_auction, query_auctions};
 use crate::tests::helpers::{
-    create_test_auction, default_duration, instantiate_with_native_price_asset, ADMIN, NFT_ADDR,
-    NFT_ADDR2, TOKEN1, UANDR, UATOM, USER1,
+    create_test_auction, create_test_auction_in_asset, default_duration,
+    instantiate_with_native_price_asset, test_update_config, ADMIN, CW20_ADDR, NFT_ADDR, NFT_ADDR2,
+    TOKEN1, UANDR, UATOM, USER1,
 };
 use cosmwasm_std::testing::{mock_dependencies, mock_env};
 use cosmwasm_std::{attr, Addr, BlockInfo, Env, Timestamp};
 use cw_utils::Duration;
 use cw_utils::Duration::Height;
+use cw_utils::{Expiration, Scheduled};
 use tracks_auction_api::api::AuctionStatus::Active;
-use tracks_auction_api::api::{PriceAsset, TrackAuction};
-use tracks_auction_api::error::AuctionError::{Cw721NotWhitelisted, InvalidAuctionDuration};
+use tracks_auction_api::api::{
+    AuctionKind, BidIncrement, PriceAsset, PriceAssetUnchecked, TrackAuction,
+};
+use tracks_auction_api::error::AuctionError::{
+    Cw721NotWhitelisted, InvalidAuctionDuration, PriceAssetNotAccepted,
+};
 use Duration::Time;
 
 #[test]
@@ -106,7 +112,7 @@ fn create_auction_saves_it_with_relevant_data() -> anyhow::Result<()> {
         NFT_ADDR,
         track_token_id,
         USER1,
-        duration.clone(),
+        duration,
         4,
         Some(213),
     )?;
@@ -128,6 +134,19 @@ fn create_auction_saves_it_with_relevant_data() -> anyhow::Result<()> {
         price_asset: PriceAsset::native("uatom"),
         active_bid: None,
         buyout_price: Some(213u8.into()),
+        fee_bps: 0,
+        creation_deposit: None,
+        bid_increment: BidIncrement::default(),
+        ends_at: Expiration::AtTime(Timestamp::from_nanos(5521400000).plus_seconds(24)),
+        anti_sniping: None,
+        starts_at: Scheduled::AtTime(Timestamp::from_nanos(5521400000)),
+        kind: AuctionKind::English,
+        unrevealed_bid_penalty: None,
+        second_price: false,
+        runner_up_amount: None,
+        lot_id: None,
+        relist_attempts: vec![],
+        relisted_from: None,
     };
 
     let response = query_auction(deps.as_ref(), 0)?;
@@ -135,11 +154,11 @@ fn create_auction_saves_it_with_relevant_data() -> anyhow::Result<()> {
     assert_eq!(response.auction, expected_auction.clone());
 
     assert_eq!(
-        query_auctions(deps.as_ref(), true, None, None)?.auctions,
+        query_auctions(deps.as_ref(), true, None, None, None)?.auctions,
         vec![expected_auction]
     );
 
-    assert!(query_auctions(deps.as_ref(), false, None, None)?
+    assert!(query_auctions(deps.as_ref(), false, None, None, None)?
         .auctions
         .is_empty());
 
@@ -188,24 +207,98 @@ fn create_auction_increments_auction_ids() -> anyhow::Result<()> {
 
     assert_eq!(query_auction(deps.as_ref(), 1)?.auction.id, 1);
 
-    let response = query_auctions(deps.as_ref(), true, None, None)?;
+    let response = query_auctions(deps.as_ref(), true, None, None, None)?;
 
     assert_eq!(response.auctions[0].id, 0);
     assert_eq!(response.auctions[1].id, 1);
 
     // ensure pagination works for query_auctions
 
-    let response = query_auctions(deps.as_ref(), true, None, Some(1))?;
+    let response = query_auctions(deps.as_ref(), true, None, None, Some(1))?;
     assert_eq!(response.auctions.len(), 1);
     assert_eq!(response.auctions[0].id, 0);
 
-    let response = query_auctions(deps.as_ref(), true, Some(0), None)?;
+    let response = query_auctions(deps.as_ref(), true, None, Some(0), None)?;
     assert_eq!(response.auctions.len(), 1);
     assert_eq!(response.auctions[0].id, 1);
 
-    assert!(query_auctions(deps.as_ref(), false, None, None)?
+    assert!(query_auctions(deps.as_ref(), false, None, None, None)?
         .auctions
         .is_empty());
 
     Ok(())
 }
+
+#[test]
+fn create_auction_with_non_accepted_price_asset_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UATOM)?;
+
+    let result = create_test_auction_in_asset(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        4,
+        None,
+        PriceAssetUnchecked::native(UANDR),
+    );
+
+    assert_eq!(result, Err(PriceAssetNotAccepted));
+
+    Ok(())
+}
+
+#[test]
+fn create_auctions_in_different_accepted_price_assets() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UATOM)?;
+    test_update_config(
+        deps.as_mut(),
+        ADMIN,
+        Some(vec![
+            PriceAssetUnchecked::native(UATOM),
+            PriceAssetUnchecked::cw20(CW20_ADDR),
+        ]),
+    )?;
+
+    create_test_auction_in_asset(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        "5",
+        USER1,
+        default_duration(),
+        4,
+        None,
+        PriceAssetUnchecked::native(UATOM),
+    )?;
+    create_test_auction_in_asset(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        "6",
+        USER1,
+        default_duration(),
+        4,
+        None,
+        PriceAssetUnchecked::cw20(CW20_ADDR),
+    )?;
+
+    assert_eq!(
+        query_auction(deps.as_ref(), 0)?.auction.price_asset,
+        PriceAsset::native(UATOM)
+    );
+    assert_eq!(
+        query_auction(deps.as_ref(), 1)?.auction.price_asset,
+        PriceAsset::cw20(Addr::unchecked(CW20_ADDR))
+    );
+
+    Ok(())
+}
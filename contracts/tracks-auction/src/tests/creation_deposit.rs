@@ -0,0 +1,414 @@
+use crate::execute::withdraw_creation_deposit;
+use crate::query::{query_config, query_creation_deposit_balance};
+use crate::tests::helpers::{
+    after_height, create_test_auction, instantiate_with_native_price_asset, test_bid,
+    test_cancel_auction, test_fund_creation_deposit, test_resolve_auction,
+    test_update_creation_deposit, test_update_fee, transfer_native_funds, transfer_nft_msg, ADMIN,
+    NFT_ADDR, TOKEN1, UANDR, UATOM, USER1, USER2, USER3,
+};
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{attr, coin, coins, BankMsg, DepsMut, SubMsg, Uint128};
+use cw_utils::Duration::Height;
+use tracks_auction_api::api::CreationDeposit;
+use tracks_auction_api::error::AuctionError::{
+    InsufficientCreationDeposit, InvalidCreationDepositFunds, WithdrawingMoreThanDeposited,
+};
+use tracks_auction_api::error::AuctionResult;
+
+fn require_deposit(deps: DepsMut, forfeit_unsold: bool) -> AuctionResult<()> {
+    test_update_creation_deposit(
+        deps,
+        ADMIN,
+        Some(CreationDeposit {
+            amount: coin(10, UATOM),
+            forfeit_unsold,
+        }),
+    )?;
+    Ok(())
+}
+
+fn create_funded_auction(mut deps: DepsMut, buyout_price: Option<u8>) -> AuctionResult<()> {
+    let env = mock_env();
+    test_fund_creation_deposit(deps.branch(), USER1, &coins(10, UATOM))?;
+    create_test_auction(
+        deps,
+        env,
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        Height(15),
+        5,
+        buyout_price,
+    )?;
+    Ok(())
+}
+
+#[test]
+fn update_creation_deposit_with_zero_amount_removes_requirement() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let response = test_update_creation_deposit(
+        deps.as_mut(),
+        ADMIN,
+        Some(CreationDeposit {
+            amount: coin(10, UATOM),
+            forfeit_unsold: true,
+        }),
+    )?;
+
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "update_config"),
+            attr("creation_deposit", "10uatom"),
+            attr("creation_deposit_forfeit_unsold", "true"),
+        ]
+    );
+
+    let response = test_update_creation_deposit(
+        deps.as_mut(),
+        ADMIN,
+        Some(CreationDeposit {
+            amount: coin(0, UATOM),
+            forfeit_unsold: true,
+        }),
+    )?;
+
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "update_config"),
+            attr("creation_deposit", "none"),
+        ]
+    );
+    assert_eq!(query_config(deps.as_ref())?.config.creation_deposit, None);
+
+    Ok(())
+}
+
+#[test]
+fn create_auction_without_deposit_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    require_deposit(deps.as_mut(), true)?;
+
+    let result = create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        Height(15),
+        5,
+        None,
+    );
+
+    assert_eq!(
+        result,
+        Err(InsufficientCreationDeposit {
+            required: coin(10, UATOM)
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn fund_creation_deposit_with_wrong_funds_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    // no deposit is required yet
+    let result = test_fund_creation_deposit(deps.as_mut(), USER1, &coins(10, UATOM));
+    assert_eq!(result, Err(InvalidCreationDepositFunds));
+
+    require_deposit(deps.as_mut(), true)?;
+
+    let result = test_fund_creation_deposit(deps.as_mut(), USER1, &coins(10, UANDR));
+    assert_eq!(result, Err(InvalidCreationDepositFunds));
+
+    let result = test_fund_creation_deposit(deps.as_mut(), USER1, &[]);
+    assert_eq!(result, Err(InvalidCreationDepositFunds));
+
+    let result =
+        test_fund_creation_deposit(deps.as_mut(), USER1, &[coin(10, UATOM), coin(10, UANDR)]);
+    assert_eq!(result, Err(InvalidCreationDepositFunds));
+
+    Ok(())
+}
+
+#[test]
+fn create_auction_takes_deposit_from_balance() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    require_deposit(deps.as_mut(), true)?;
+
+    let response = test_fund_creation_deposit(deps.as_mut(), USER1, &coins(25, UATOM))?;
+
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "fund_creation_deposit"),
+            attr("amount", "25uatom"),
+        ]
+    );
+
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        Height(15),
+        5,
+        None,
+    )?;
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        "2",
+        USER1,
+        Height(15),
+        5,
+        None,
+    )?;
+
+    assert_eq!(
+        query_creation_deposit_balance(deps.as_ref(), USER1.to_string())?.balances,
+        vec![coin(5, UATOM)]
+    );
+
+    let result = create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        "3",
+        USER1,
+        Height(15),
+        5,
+        None,
+    );
+
+    assert_eq!(
+        result,
+        Err(InsufficientCreationDeposit {
+            required: coin(10, UATOM)
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn withdraw_creation_deposit_returns_unused_balance() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    require_deposit(deps.as_mut(), true)?;
+
+    test_fund_creation_deposit(deps.as_mut(), USER1, &coins(25, UATOM))?;
+
+    let result = withdraw_creation_deposit(
+        deps.as_mut(),
+        mock_info(USER1, &[]),
+        UATOM.to_string(),
+        Some(Uint128::new(26)),
+    );
+    assert_eq!(result, Err(WithdrawingMoreThanDeposited));
+
+    let response = withdraw_creation_deposit(
+        deps.as_mut(),
+        mock_info(USER1, &[]),
+        UATOM.to_string(),
+        Some(Uint128::new(5)),
+    )?;
+    assert_eq!(
+        response.messages,
+        vec![SubMsg::new(BankMsg::Send {
+            to_address: USER1.to_string(),
+            amount: coins(5, UATOM),
+        })]
+    );
+
+    let response = withdraw_creation_deposit(
+        deps.as_mut(),
+        mock_info(USER1, &[]),
+        UATOM.to_string(),
+        None,
+    )?;
+    assert_eq!(
+        response.messages,
+        vec![SubMsg::new(BankMsg::Send {
+            to_address: USER1.to_string(),
+            amount: coins(20, UATOM),
+        })]
+    );
+
+    assert!(
+        query_creation_deposit_balance(deps.as_ref(), USER1.to_string())?
+            .balances
+            .is_empty()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn resolve_auction_with_sale_returns_deposit() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    require_deposit(deps.as_mut(), true)?;
+    create_funded_auction(deps.as_mut(), None)?;
+
+    test_bid(deps.as_mut(), env.clone(), USER2, 0, 6, &coins(6, UANDR))?;
+
+    let response = test_resolve_auction(deps.as_mut(), after_height(&env, 16), USER1, 0)?;
+
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_native_funds(UATOM, 10, USER1)?,
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+            transfer_native_funds(UANDR, 6, USER1)?,
+        ]
+    );
+    assert!(response
+        .attributes
+        .contains(&attr("creation_deposit_returned", "10uatom")));
+
+    Ok(())
+}
+
+#[test]
+fn buyout_returns_deposit() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    require_deposit(deps.as_mut(), true)?;
+    create_funded_auction(deps.as_mut(), Some(20))?;
+
+    let response = test_bid(deps.as_mut(), env.clone(), USER2, 0, 20, &coins(20, UANDR))?;
+
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_native_funds(UANDR, 20, USER1)?,
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+            transfer_native_funds(UATOM, 10, USER1)?,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn resolve_auction_without_bids_forfeits_deposit_to_fee_collector() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_fee(deps.as_mut(), ADMIN, None, Some(USER3))?;
+    require_deposit(deps.as_mut(), true)?;
+    create_funded_auction(deps.as_mut(), None)?;
+
+    let response = test_resolve_auction(deps.as_mut(), after_height(&env, 16), USER1, 0)?;
+
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_native_funds(UATOM, 10, USER3)?,
+            transfer_nft_msg(NFT_ADDR, USER1, TOKEN1)?,
+        ]
+    );
+    assert!(response
+        .attributes
+        .contains(&attr("creation_deposit_forfeited", "10uatom")));
+
+    Ok(())
+}
+
+#[test]
+fn resolve_auction_without_bids_returns_deposit_if_not_forfeited() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    require_deposit(deps.as_mut(), false)?;
+    create_funded_auction(deps.as_mut(), None)?;
+
+    let response = test_resolve_auction(deps.as_mut(), after_height(&env, 16), USER1, 0)?;
+
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_native_funds(UATOM, 10, USER1)?,
+            transfer_nft_msg(NFT_ADDR, USER1, TOKEN1)?,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cancel_auction_forfeits_deposit_to_fee_collector() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_fee(deps.as_mut(), ADMIN, None, Some(USER3))?;
+    require_deposit(deps.as_mut(), true)?;
+    create_funded_auction(deps.as_mut(), None)?;
+
+    test_bid(deps.as_mut(), env.clone(), USER2, 0, 6, &coins(6, UANDR))?;
+
+    let response = test_cancel_auction(deps.as_mut(), env.clone(), USER1, 0)?;
+
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER1, TOKEN1)?,
+            transfer_native_funds(UANDR, 6, USER2)?,
+            transfer_native_funds(UATOM, 10, USER3)?,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn deposit_policy_is_fixed_when_auction_is_created() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    require_deposit(deps.as_mut(), false)?;
+    create_funded_auction(deps.as_mut(), None)?;
+
+    require_deposit(deps.as_mut(), true)?;
+
+    let response = test_cancel_auction(deps.as_mut(), env.clone(), USER1, 0)?;
+
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER1, TOKEN1)?,
+            transfer_native_funds(UATOM, 10, USER1)?,
+        ]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,411 @@
+use crate::execute::{create_drop, mint_from_drop, receive_cw20, DropTerms};
+use crate::query::{query_drop, query_drops};
+use crate::tests::helpers::{
+    after_seconds, instantiate_with_cw20_price_asset, instantiate_with_native_price_asset,
+    test_add_collection, test_pause, test_update_fee, transfer_cw20_funds, transfer_native_funds,
+    ADMIN, CW20_ADDR, NFT_ADDR, NFT_ADDR2, UANDR, UATOM, USER1, USER2, USER3,
+};
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{
+    attr, coins, to_json_binary, wasm_execute, Addr, DepsMut, Env, SubMsg, Uint128,
+};
+use cw20::Cw20ReceiveMsg;
+use cw721_tracks_api::api::TrackMetadata;
+use cw721_tracks_api::msg::ExecuteMsg as Cw721TracksExecuteMsg;
+use tracks_auction_api::api::{CollectionSettings, PriceAssetUnchecked};
+use tracks_auction_api::error::AuctionError::{
+    ContractPaused, Cw721NotWhitelisted, DropEnded, DropIdNotFound, DropNotStarted,
+    DropPriceBelowCollectionFloor, DropSoldOut, InsufficientFundsForPurchase, InvalidDropMetadata,
+    InvalidDropPrice, InvalidDropSupplyCap, InvalidDropWindow, PaymentWrongAsset,
+    PriceAssetNotAccepted,
+};
+use tracks_auction_api::error::AuctionResult;
+use tracks_auction_api::msg::Cw20HookMsg;
+
+fn drop_metadata() -> TrackMetadata {
+    TrackMetadata {
+        artist_name: "Boden".to_string(),
+        artist_address: None,
+        album: None,
+        track_name: "Open edition".to_string(),
+        audio_track_url: "https://www.usdebtclock.org/".to_string(),
+        edition: None,
+    }
+}
+
+/// Drop terms priced at 20, opening now and running for 600 seconds.
+fn drop_terms(env: &Env, price_asset: PriceAssetUnchecked, supply_cap: Option<u32>) -> DropTerms {
+    DropTerms {
+        nft_contract: NFT_ADDR.to_string(),
+        metadata: drop_metadata(),
+        price: Uint128::new(20),
+        price_asset,
+        starts_at: env.block.time,
+        ends_at: env.block.time.plus_seconds(600),
+        supply_cap,
+    }
+}
+
+fn test_create_drop(deps: DepsMut, env: Env, terms: DropTerms) -> AuctionResult<()> {
+    create_drop(deps, env, mock_info(USER1, &[]), terms)?;
+    Ok(())
+}
+
+fn mint_edition_msg(drop_id: u64, edition: u32, owner: &str) -> anyhow::Result<SubMsg> {
+    Ok(SubMsg::new(wasm_execute(
+        NFT_ADDR,
+        &Cw721TracksExecuteMsg::Mint {
+            token_id: format!("{MOCK_CONTRACT_ADDR}/drop-{drop_id}-{edition}"),
+            owner: owner.to_string(),
+            token_uri: None,
+            extension: TrackMetadata {
+                edition: Some(edition),
+                ..drop_metadata()
+            },
+        },
+        vec![],
+    )?))
+}
+
+#[test]
+fn mint_numbered_editions_from_drop() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let response = create_drop(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(USER1, &[]),
+        drop_terms(&env, PriceAssetUnchecked::native(UANDR), None),
+    )?;
+    assert_eq!(
+        response.attributes,
+        vec![attr("action", "create_drop"), attr("drop_id", "0")]
+    );
+
+    let response = mint_from_drop(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(USER2, &coins(20, UANDR)),
+        0,
+    )?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_native_funds(UANDR, 20, USER1)?,
+            mint_edition_msg(0, 1, USER2)?,
+        ]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "mint_from_drop"),
+            attr("drop_id", "0"),
+            attr("edition", "1"),
+            attr("token_id", format!("{MOCK_CONTRACT_ADDR}/drop-0-1")),
+            attr("price", "20"),
+            attr("fee_bps", "0"),
+            attr("fee_amount", "0"),
+            attr("creator_amount", "20"),
+        ]
+    );
+
+    let response = mint_from_drop(deps.as_mut(), env, mock_info(USER3, &coins(20, UANDR)), 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_native_funds(UANDR, 20, USER1)?,
+            mint_edition_msg(0, 2, USER3)?,
+        ]
+    );
+
+    let drop = query_drop(deps.as_ref(), 0)?.drop;
+    assert_eq!(drop.minted, 2);
+    assert_eq!(drop.remaining_supply(), None);
+
+    Ok(())
+}
+
+#[test]
+fn mint_from_drop_pays_fee_and_refunds_excess() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_fee(deps.as_mut(), ADMIN, Some(500), Some(USER3))?;
+    test_create_drop(
+        deps.as_mut(),
+        env.clone(),
+        drop_terms(&env, PriceAssetUnchecked::native(UANDR), None),
+    )?;
+
+    // the fee is the one at the time the drop was created
+    test_update_fee(deps.as_mut(), ADMIN, Some(1000), None)?;
+
+    let response = mint_from_drop(deps.as_mut(), env, mock_info(USER2, &coins(25, UANDR)), 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_native_funds(UANDR, 19, USER1)?,
+            transfer_native_funds(UANDR, 1, USER3)?,
+            mint_edition_msg(0, 1, USER2)?,
+            transfer_native_funds(UANDR, 5, USER2)?,
+        ]
+    );
+    assert!(response.attributes.contains(&attr("excess_refund", "5")));
+
+    Ok(())
+}
+
+#[test]
+fn mint_from_drop_with_cw20() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_cw20_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, CW20_ADDR)?;
+    test_create_drop(
+        deps.as_mut(),
+        env.clone(),
+        drop_terms(&env, PriceAssetUnchecked::cw20(CW20_ADDR), None),
+    )?;
+
+    let response = receive_cw20(
+        deps.as_mut(),
+        env,
+        mock_info(CW20_ADDR, &[]),
+        Cw20ReceiveMsg {
+            sender: USER2.to_string(),
+            amount: Uint128::new(20),
+            msg: to_json_binary(&Cw20HookMsg::MintFromDrop { drop_id: 0 })?,
+        },
+    )?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_cw20_funds(CW20_ADDR, 20, USER1)?,
+            mint_edition_msg(0, 1, USER2)?,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn mint_from_drop_respects_window_and_supply_cap() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_create_drop(
+        deps.as_mut(),
+        env.clone(),
+        DropTerms {
+            starts_at: env.block.time.plus_seconds(100),
+            ..drop_terms(&env, PriceAssetUnchecked::native(UANDR), Some(1))
+        },
+    )?;
+
+    let result = mint_from_drop(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(USER2, &coins(20, UANDR)),
+        0,
+    );
+    assert_eq!(result, Err(DropNotStarted));
+
+    let started = after_seconds(&env, 100);
+
+    let result = mint_from_drop(
+        deps.as_mut(),
+        started.clone(),
+        mock_info(USER2, &coins(20, UATOM)),
+        0,
+    );
+    assert_eq!(result, Err(PaymentWrongAsset));
+
+    let result = mint_from_drop(
+        deps.as_mut(),
+        started.clone(),
+        mock_info(USER2, &coins(19, UANDR)),
+        0,
+    );
+    assert_eq!(result, Err(InsufficientFundsForPurchase));
+
+    mint_from_drop(
+        deps.as_mut(),
+        started.clone(),
+        mock_info(USER2, &coins(20, UANDR)),
+        0,
+    )?;
+
+    let result = mint_from_drop(
+        deps.as_mut(),
+        started,
+        mock_info(USER3, &coins(20, UANDR)),
+        0,
+    );
+    assert_eq!(result, Err(DropSoldOut));
+    assert_eq!(
+        query_drop(deps.as_ref(), 0)?.drop.remaining_supply(),
+        Some(0)
+    );
+
+    let result = mint_from_drop(
+        deps.as_mut(),
+        after_seconds(&env, 600),
+        mock_info(USER3, &coins(20, UANDR)),
+        0,
+    );
+    assert_eq!(result, Err(DropEnded));
+
+    let result = mint_from_drop(deps.as_mut(), env, mock_info(USER3, &coins(20, UANDR)), 1);
+    assert_eq!(result, Err(DropIdNotFound));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_drop_creation_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_add_collection(
+        deps.as_mut(),
+        ADMIN,
+        NFT_ADDR2,
+        Some(CollectionSettings {
+            label: None,
+            minimum_bid_floor: Some(Uint128::new(30)),
+        }),
+    )?;
+
+    let terms = || drop_terms(&env, PriceAssetUnchecked::native(UANDR), None);
+
+    for (terms, error) in [
+        (
+            DropTerms {
+                nft_contract: "unknown_nft_addr".to_string(),
+                ..terms()
+            },
+            Cw721NotWhitelisted,
+        ),
+        (
+            DropTerms {
+                price: Uint128::zero(),
+                ..terms()
+            },
+            InvalidDropPrice,
+        ),
+        (
+            DropTerms {
+                nft_contract: NFT_ADDR2.to_string(),
+                ..terms()
+            },
+            DropPriceBelowCollectionFloor {
+                floor: Uint128::new(30),
+            },
+        ),
+        (
+            DropTerms {
+                ends_at: env.block.time,
+                ..terms()
+            },
+            InvalidDropWindow,
+        ),
+        (
+            DropTerms {
+                starts_at: env.block.time.minus_seconds(600),
+                ends_at: env.block.time.minus_seconds(1),
+                ..terms()
+            },
+            InvalidDropWindow,
+        ),
+        (
+            DropTerms {
+                supply_cap: Some(0),
+                ..terms()
+            },
+            InvalidDropSupplyCap,
+        ),
+        (
+            DropTerms {
+                metadata: TrackMetadata {
+                    artist_address: Some(Addr::unchecked(USER1)),
+                    ..drop_metadata()
+                },
+                ..terms()
+            },
+            InvalidDropMetadata,
+        ),
+        (
+            DropTerms {
+                metadata: TrackMetadata {
+                    edition: Some(1),
+                    ..drop_metadata()
+                },
+                ..terms()
+            },
+            InvalidDropMetadata,
+        ),
+        (
+            DropTerms {
+                price_asset: PriceAssetUnchecked::native(UATOM),
+                ..terms()
+            },
+            PriceAssetNotAccepted,
+        ),
+    ] {
+        let result = create_drop(deps.as_mut(), env.clone(), mock_info(USER1, &[]), terms);
+        assert_eq!(result, Err(error));
+    }
+
+    test_pause(deps.as_mut(), ADMIN)?;
+
+    let result = create_drop(deps.as_mut(), env.clone(), mock_info(USER1, &[]), terms());
+    assert_eq!(result, Err(ContractPaused));
+
+    Ok(())
+}
+
+#[test]
+fn query_drops_by_artist() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_create_drop(
+        deps.as_mut(),
+        env.clone(),
+        drop_terms(&env, PriceAssetUnchecked::native(UANDR), Some(10)),
+    )?;
+    create_drop(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(USER2, &[]),
+        drop_terms(&env, PriceAssetUnchecked::native(UANDR), None),
+    )?;
+    mint_from_drop(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(USER3, &coins(20, UANDR)),
+        0,
+    )?;
+
+    let drops = query_drops(deps.as_ref(), None, None, None)?.drops;
+    assert_eq!(drops.len(), 2);
+
+    let drops = query_drops(deps.as_ref(), Some(USER1.to_string()), None, None)?.drops;
+    assert_eq!(drops.len(), 1);
+    assert_eq!(drops[0].artist, Addr::unchecked(USER1));
+    assert_eq!(drops[0].minted, 1);
+    assert_eq!(drops[0].remaining_supply(), Some(9));
+
+    let drops = query_drops(deps.as_ref(), None, Some(0), None)?.drops;
+    assert_eq!(drops.len(), 1);
+    assert_eq!(drops[0].artist, Addr::unchecked(USER2));
+
+    Ok(())
+}
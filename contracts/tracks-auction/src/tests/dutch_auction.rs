@@ -0,0 +1,274 @@
+use crate::query::{query_auction, query_current_price};
+use crate::tests::helpers::{
+    after_height, after_seconds, create_test_auction, default_duration,
+    instantiate_with_native_price_asset, send_test_nft, test_bid, transfer_native_funds,
+    transfer_nft_msg, ADMIN, NFT_ADDR, TOKEN1, UANDR, USER1, USER2,
+};
+use cosmwasm_std::testing::{mock_dependencies, mock_env};
+use cosmwasm_std::{attr, coins, Deps, DepsMut, Env, Response, Uint128};
+use cw_utils::Duration;
+use cw_utils::Duration::{Height, Time};
+use tracks_auction_api::api::AuctionStatus::Resolved;
+use tracks_auction_api::api::{
+    AuctionKind, DutchAuction, PriceAsset, PriceAssetUnchecked, PriceDecay,
+};
+use tracks_auction_api::error::AuctionError::{
+    BidLowerThanMinimum, InvalidDutchAuction, NotDutchAuction,
+};
+use tracks_auction_api::error::AuctionResult;
+use tracks_auction_api::msg::Cw721HookMsg::CreateAuction;
+
+fn create_dutch_auction(
+    deps: DepsMut,
+    env: Env,
+    duration: Duration,
+    floor_price: u8,
+    buyout_price: Option<u8>,
+    dutch_auction: DutchAuction,
+) -> AuctionResult<Response> {
+    send_test_nft(
+        deps,
+        env,
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        &CreateAuction {
+            duration,
+            start: None,
+            minimum_bid_amount: floor_price.into(),
+            buyout_price: buyout_price.map(Uint128::from),
+            price_asset: PriceAssetUnchecked::native(UANDR),
+            bid_increment: None,
+            kind: Some(AuctionKind::Dutch(dutch_auction)),
+            second_price: None,
+            reserve_price: None,
+            relist: None,
+        },
+    )
+}
+
+fn current_price(deps: Deps, env: Env) -> AuctionResult<u128> {
+    Ok(query_current_price(deps, env, 0)?.price.u128())
+}
+
+#[test]
+fn linear_dutch_auction_price_decays_to_floor() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let response = create_dutch_auction(
+        deps.as_mut(),
+        env.clone(),
+        Time(1000),
+        100,
+        None,
+        DutchAuction {
+            start_price: Uint128::new(200),
+            decay: PriceDecay::Linear,
+        },
+    )?;
+
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "create_auction"),
+            attr("auction_id", "0"),
+            attr("auction_kind", "dutch"),
+            attr("start_price", "200"),
+        ]
+    );
+
+    let response = query_current_price(deps.as_ref(), env.clone(), 0)?;
+    assert_eq!(response.price, Uint128::new(200));
+    assert_eq!(response.asset, PriceAsset::native(UANDR));
+
+    assert_eq!(current_price(deps.as_ref(), after_seconds(&env, 250))?, 175);
+    assert_eq!(current_price(deps.as_ref(), after_seconds(&env, 999))?, 101);
+    assert_eq!(
+        current_price(deps.as_ref(), after_seconds(&env, 1000))?,
+        100
+    );
+    assert_eq!(
+        current_price(deps.as_ref(), after_seconds(&env, 5000))?,
+        100
+    );
+
+    Ok(())
+}
+
+#[test]
+fn stepwise_dutch_auction_price_decays_in_steps() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    create_dutch_auction(
+        deps.as_mut(),
+        env.clone(),
+        Height(100),
+        100,
+        None,
+        DutchAuction {
+            start_price: Uint128::new(200),
+            decay: PriceDecay::Stepwise { interval: 25 },
+        },
+    )?;
+
+    assert_eq!(current_price(deps.as_ref(), after_height(&env, 24))?, 200);
+    assert_eq!(current_price(deps.as_ref(), after_height(&env, 25))?, 175);
+    assert_eq!(current_price(deps.as_ref(), after_height(&env, 74))?, 150);
+    assert_eq!(current_price(deps.as_ref(), after_height(&env, 100))?, 100);
+
+    Ok(())
+}
+
+#[test]
+fn first_bid_at_current_price_wins_dutch_auction() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    create_dutch_auction(
+        deps.as_mut(),
+        env.clone(),
+        Time(1000),
+        100,
+        None,
+        DutchAuction {
+            start_price: Uint128::new(200),
+            decay: PriceDecay::Linear,
+        },
+    )?;
+
+    let env = after_seconds(&env, 500);
+
+    let result = test_bid(
+        deps.as_mut(),
+        env.clone(),
+        USER2,
+        0,
+        149,
+        &coins(149, UANDR),
+    );
+    assert_eq!(
+        result,
+        Err(BidLowerThanMinimum {
+            minimum: Uint128::new(150)
+        })
+    );
+
+    // bidding above the current price still only pays the current price
+    let response = test_bid(
+        deps.as_mut(),
+        env.clone(),
+        USER2,
+        0,
+        160,
+        &coins(170, UANDR),
+    )?;
+
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_native_funds(UANDR, 150, USER1)?,
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+            transfer_native_funds(UANDR, 20, USER2)?,
+        ]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "dutch_auction_sale"),
+            attr("auction_id", "0"),
+            attr("bid_amount", "150"),
+            attr("fee_bps", "0"),
+            attr("fee_amount", "0"),
+            attr("creator_amount", "150"),
+            attr("excess_refund", "20"),
+        ]
+    );
+
+    assert_eq!(query_auction(deps.as_ref(), 0)?.auction.status, Resolved);
+
+    Ok(())
+}
+
+#[test]
+fn create_invalid_dutch_auction_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let linear = DutchAuction {
+        start_price: Uint128::new(200),
+        decay: PriceDecay::Linear,
+    };
+
+    // start price not above the floor
+    let result = create_dutch_auction(
+        deps.as_mut(),
+        env.clone(),
+        Height(100),
+        200,
+        None,
+        linear.clone(),
+    );
+    assert_eq!(result, Err(InvalidDutchAuction));
+
+    // buyout price set
+    let result = create_dutch_auction(
+        deps.as_mut(),
+        env.clone(),
+        Height(100),
+        100,
+        Some(150),
+        linear,
+    );
+    assert_eq!(result, Err(InvalidDutchAuction));
+
+    for interval in [0, 101] {
+        let result = create_dutch_auction(
+            deps.as_mut(),
+            env.clone(),
+            Height(100),
+            100,
+            None,
+            DutchAuction {
+                start_price: Uint128::new(200),
+                decay: PriceDecay::Stepwise { interval },
+            },
+        );
+        assert_eq!(result, Err(InvalidDutchAuction));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn query_current_price_of_english_auction_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        None,
+    )?;
+
+    let result = query_current_price(deps.as_ref(), env, 0);
+    assert_eq!(result, Err(NotDutchAuction));
+
+    Ok(())
+}
@@ -0,0 +1,310 @@
+use crate::query::{query_auction, query_fee_info};
+use crate::tests::helpers::{
+    after_height, create_test_auction, instantiate_with_cw20_price_asset,
+    instantiate_with_native_price_asset, test_bid, test_cw20_bid, test_resolve_auction,
+    test_update_fee, transfer_cw20_funds, transfer_native_funds, transfer_nft_msg, ADMIN,
+    CW20_ADDR, NFT_ADDR, TOKEN1, UANDR, USER1, USER2, USER3,
+};
+use cosmwasm_std::testing::{mock_dependencies, mock_env};
+use cosmwasm_std::{attr, coins, Addr};
+use cw_utils::Duration::Height;
+use tracks_auction_api::api::FeeInfoResponse;
+use tracks_auction_api::error::AuctionError::{FeeTooHigh, Unauthorized};
+
+#[test]
+fn fee_info_defaults_to_no_fee_collected_by_admin() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    assert_eq!(
+        query_fee_info(deps.as_ref())?,
+        FeeInfoResponse {
+            fee_bps: 0,
+            fee_collector: Addr::unchecked(ADMIN),
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn update_fee_by_admin_updates_fee_info() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let response = test_update_fee(deps.as_mut(), ADMIN, Some(250), Some(USER3))?;
+
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "update_config"),
+            attr("fee_bps", "250"),
+            attr("fee_collector", USER3),
+        ]
+    );
+    assert_eq!(
+        query_fee_info(deps.as_ref())?,
+        FeeInfoResponse {
+            fee_bps: 250,
+            fee_collector: Addr::unchecked(USER3),
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn update_fee_by_non_admin_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let result = test_update_fee(deps.as_mut(), USER1, Some(250), None);
+
+    assert_eq!(result, Err(Unauthorized));
+
+    Ok(())
+}
+
+#[test]
+fn update_fee_above_maximum_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    for fee_bps in [2_501, 10_000] {
+        let result = test_update_fee(deps.as_mut(), ADMIN, Some(fee_bps), None);
+
+        assert_eq!(result, Err(FeeTooHigh { max_fee_bps: 2_500 }));
+    }
+
+    test_update_fee(deps.as_mut(), ADMIN, Some(2_500), None)?;
+    assert_eq!(query_fee_info(deps.as_ref())?.fee_bps, 2_500);
+
+    Ok(())
+}
+
+#[test]
+fn resolve_auction_splits_native_bid_between_creator_and_fee_collector() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_fee(deps.as_mut(), ADMIN, Some(250), Some(USER3))?;
+
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        Height(15),
+        5,
+        None,
+    )?;
+
+    test_bid(
+        deps.as_mut(),
+        env.clone(),
+        USER2,
+        0,
+        200,
+        &coins(200, UANDR),
+    )?;
+
+    let response = test_resolve_auction(deps.as_mut(), after_height(&env, 16), USER1, 0)?;
+
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+            transfer_native_funds(UANDR, 195, USER1)?,
+            transfer_native_funds(UANDR, 5, USER3)?,
+        ]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "resolve_auction"),
+            attr("auction_id", "0"),
+            attr("fee_bps", "250"),
+            attr("fee_amount", "5"),
+            attr("creator_amount", "195"),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn resolve_auction_rounds_fee_down_in_favor_of_creator() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_cw20_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, CW20_ADDR)?;
+    test_update_fee(deps.as_mut(), ADMIN, Some(250), Some(USER3))?;
+
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        Height(15),
+        5,
+        None,
+    )?;
+
+    test_cw20_bid(deps.as_mut(), env.clone(), USER2, 0, 99, 99, CW20_ADDR)?;
+
+    let response = test_resolve_auction(deps.as_mut(), after_height(&env, 16), USER1, 0)?;
+
+    // 2.5% of 99 is 2.475, the fee is rounded down to 2
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+            transfer_cw20_funds(CW20_ADDR, 97, USER1)?,
+            transfer_cw20_funds(CW20_ADDR, 2, USER3)?,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn resolve_auction_skips_fee_transfer_when_fee_rounds_to_zero() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_fee(deps.as_mut(), ADMIN, Some(100), Some(USER3))?;
+
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        Height(15),
+        5,
+        None,
+    )?;
+
+    test_bid(deps.as_mut(), env.clone(), USER2, 0, 50, &coins(50, UANDR))?;
+
+    let response = test_resolve_auction(deps.as_mut(), after_height(&env, 16), USER1, 0)?;
+
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+            transfer_native_funds(UANDR, 50, USER1)?,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn buyout_splits_payment_between_creator_and_fee_collector() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_fee(deps.as_mut(), ADMIN, Some(1_000), Some(USER3))?;
+
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        Height(15),
+        5,
+        Some(100),
+    )?;
+
+    let response = test_bid(
+        deps.as_mut(),
+        env.clone(),
+        USER2,
+        0,
+        100,
+        &coins(100, UANDR),
+    )?;
+
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_native_funds(UANDR, 90, USER1)?,
+            transfer_native_funds(UANDR, 10, USER3)?,
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+        ]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "instant_buyout"),
+            attr("auction_id", "0"),
+            attr("bid_amount", "100"),
+            attr("fee_bps", "1000"),
+            attr("fee_amount", "10"),
+            attr("creator_amount", "90"),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn fee_is_fixed_when_auction_is_created() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_fee(deps.as_mut(), ADMIN, Some(250), Some(USER3))?;
+
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        Height(15),
+        5,
+        None,
+    )?;
+
+    test_update_fee(deps.as_mut(), ADMIN, Some(2_000), None)?;
+
+    assert_eq!(query_auction(deps.as_ref(), 0)?.auction.fee_bps, 250);
+
+    test_bid(
+        deps.as_mut(),
+        env.clone(),
+        USER2,
+        0,
+        200,
+        &coins(200, UANDR),
+    )?;
+
+    let response = test_resolve_auction(deps.as_mut(), after_height(&env, 16), USER1, 0)?;
+
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+            transfer_native_funds(UANDR, 195, USER1)?,
+            transfer_native_funds(UANDR, 5, USER3)?,
+        ]
+    );
+
+    Ok(())
+}
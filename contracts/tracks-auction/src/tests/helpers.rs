@@ -1,5 +1,12 @@
+use crate::admin::{
+    accept_admin_role, add_collection, pause, propose_new_admin, remove_collection, unpause,
+    update_config, ConfigUpdate,
+};
+use crate::config::load_config;
 use crate::contract::instantiate;
-use crate::execute::{bid, cancel_auction, receive_cw20, receive_nft, resolve_auction};
+use crate::execute::{
+    bid, buy, cancel_auction, fund_creation_deposit, receive_cw20, receive_nft, resolve_auction,
+};
 use cosmwasm_std::testing::mock_info;
 use cosmwasm_std::{
     to_json_binary, wasm_execute, Addr, BlockInfo, Coin, DepsMut, Env, Response, SubMsg,
@@ -9,10 +16,13 @@ use cw721::Cw721ExecuteMsg::TransferNft;
 use cw721::Cw721ReceiveMsg;
 use cw_asset::Asset;
 use cw_utils::Duration;
-use tracks_auction_api::api::{AuctionId, PriceAssetUnchecked};
+use tracks_auction_api::api::{
+    AntiSniping, AuctionId, BidIncrement, CollectionSettings, CreationDeposit, DurationLimits,
+    ListingId, PriceAssetUnchecked,
+};
 use tracks_auction_api::error::AuctionResult;
 use tracks_auction_api::msg::Cw721HookMsg::CreateAuction;
-use tracks_auction_api::msg::{Cw20HookMsg, InstantiateMsg};
+use tracks_auction_api::msg::{Cw20HookMsg, Cw721HookMsg, InstantiateMsg};
 
 pub const ADMIN: &str = "admin";
 
@@ -41,10 +51,20 @@ pub fn test_instantiate(
     instantiate(
         deps,
         env,
-        mock_info(instantiator, &vec![]),
+        mock_info(instantiator, &[]),
         InstantiateMsg {
-            whitelisted_nft: whitelisted_nft.to_string(),
-            price_asset,
+            admin: None,
+            whitelisted_nfts: vec![whitelisted_nft.to_string()],
+            price_assets: vec![price_asset],
+            fee_bps: 0,
+            fee_collector: None,
+            creation_deposit: None,
+            duration_limits: None,
+            max_active_auctions_per_creator: None,
+            bid_increment: None,
+            anti_sniping: None,
+            unrevealed_bid_penalty: None,
+            randomness_beacon: None,
         },
     )
 }
@@ -81,6 +101,7 @@ pub fn instantiate_with_cw20_price_asset(
     )
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn create_test_auction(
     deps: DepsMut,
     env: Env,
@@ -90,30 +111,116 @@ pub fn create_test_auction(
     duration: Duration,
     minimum_bid_amount: u8,
     buyout_price: Option<u8>,
+) -> AuctionResult<Response> {
+    // price the auction in the first accepted asset
+    let price_asset = load_config(deps.storage)?.price_assets[0].clone();
+
+    create_test_auction_in_asset(
+        deps,
+        env,
+        nft_contract,
+        token_id,
+        creator,
+        duration,
+        minimum_bid_amount,
+        buyout_price,
+        price_asset.into(),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_test_auction_in_asset(
+    deps: DepsMut,
+    env: Env,
+    nft_contract: &str,
+    token_id: &str,
+    creator: &str,
+    duration: Duration,
+    minimum_bid_amount: u8,
+    buyout_price: Option<u8>,
+    price_asset: PriceAssetUnchecked,
+) -> AuctionResult<Response> {
+    send_test_nft(
+        deps,
+        env,
+        nft_contract,
+        token_id,
+        creator,
+        &CreateAuction {
+            duration,
+            start: None,
+            minimum_bid_amount: minimum_bid_amount.into(),
+            buyout_price: buyout_price.map(|it| it.into()),
+            price_asset,
+            bid_increment: None,
+            kind: None,
+            second_price: None,
+            reserve_price: None,
+            relist: None,
+        },
+    )
+}
+
+pub fn send_test_nft(
+    deps: DepsMut,
+    env: Env,
+    nft_contract: &str,
+    token_id: &str,
+    sender: &str,
+    msg: &Cw721HookMsg,
 ) -> AuctionResult<Response> {
     receive_nft(
         deps,
         env,
-        mock_info(nft_contract, &vec![]),
+        mock_info(nft_contract, &[]),
         Cw721ReceiveMsg {
-            sender: creator.to_string(),
+            sender: sender.to_string(),
             token_id: token_id.to_string(),
-            msg: to_json_binary(&CreateAuction {
-                duration,
-                minimum_bid_amount: minimum_bid_amount.into(),
-                buyout_price: buyout_price.map(|it| it.into()),
-            })?,
+            msg: to_json_binary(msg)?,
+        },
+    )
+}
+
+pub fn test_create_listing(
+    deps: DepsMut,
+    env: Env,
+    token_id: &str,
+    seller: &str,
+    price: u8,
+) -> AuctionResult<Response> {
+    // price the listing in the first accepted asset
+    let price_asset = load_config(deps.storage)?.price_assets[0].clone();
+
+    send_test_nft(
+        deps,
+        env,
+        NFT_ADDR,
+        token_id,
+        seller,
+        &Cw721HookMsg::CreateListing {
+            price: price.into(),
+            price_asset: price_asset.into(),
         },
     )
 }
 
+pub fn test_buy(
+    deps: DepsMut,
+    buyer: &str,
+    listing_id: ListingId,
+    price: u8,
+    funds: &[Coin],
+) -> AuctionResult<Response> {
+    buy(deps, mock_info(buyer, funds), listing_id, price.into())
+}
+
 pub fn test_bid(
     deps: DepsMut,
     env: Env,
     bidder: &str,
     auction_id: AuctionId,
     bid_amount: u8,
-    bid_funds: &Vec<Coin>,
+    bid_funds: &[Coin],
 ) -> AuctionResult<Response> {
     bid(
         deps,
@@ -136,7 +243,7 @@ pub fn test_cw20_bid(
     receive_cw20(
         deps,
         env,
-        mock_info(cw20, &vec![]),
+        mock_info(cw20, &[]),
         Cw20ReceiveMsg {
             sender: bidder.to_string(),
             amount: amount_sent.into(),
@@ -154,7 +261,7 @@ pub fn test_resolve_auction(
     sender: &str,
     auction_id: AuctionId,
 ) -> AuctionResult<Response> {
-    resolve_auction(deps, env, mock_info(sender, &vec![]), auction_id)
+    resolve_auction(deps, env, mock_info(sender, &[]), auction_id)
 }
 
 pub fn test_cancel_auction(
@@ -163,13 +270,157 @@ pub fn test_cancel_auction(
     sender: &str,
     auction_id: AuctionId,
 ) -> AuctionResult<Response> {
-    cancel_auction(deps, env, mock_info(sender, &vec![]), auction_id)
+    cancel_auction(deps, env, mock_info(sender, &[]), auction_id)
+}
+
+pub fn test_update_config(
+    deps: DepsMut,
+    sender: &str,
+    price_assets: Option<Vec<PriceAssetUnchecked>>,
+) -> AuctionResult<Response> {
+    update_config(
+        deps,
+        mock_info(sender, &[]),
+        ConfigUpdate {
+            price_assets,
+            ..ConfigUpdate::default()
+        },
+    )
+}
+
+pub fn test_update_fee(
+    deps: DepsMut,
+    sender: &str,
+    fee_bps: Option<u16>,
+    fee_collector: Option<&str>,
+) -> AuctionResult<Response> {
+    update_config(
+        deps,
+        mock_info(sender, &[]),
+        ConfigUpdate {
+            fee_bps,
+            fee_collector: fee_collector.map(|it| it.to_string()),
+            ..ConfigUpdate::default()
+        },
+    )
+}
+
+pub fn test_update_creation_deposit(
+    deps: DepsMut,
+    sender: &str,
+    creation_deposit: Option<CreationDeposit>,
+) -> AuctionResult<Response> {
+    update_config(
+        deps,
+        mock_info(sender, &[]),
+        ConfigUpdate {
+            creation_deposit,
+            ..ConfigUpdate::default()
+        },
+    )
+}
+
+pub fn test_update_auction_limits(
+    deps: DepsMut,
+    sender: &str,
+    duration_limits: Option<DurationLimits>,
+    max_active_auctions_per_creator: Option<u32>,
+) -> AuctionResult<Response> {
+    update_config(
+        deps,
+        mock_info(sender, &[]),
+        ConfigUpdate {
+            duration_limits,
+            max_active_auctions_per_creator,
+            ..ConfigUpdate::default()
+        },
+    )
+}
+
+pub fn test_update_bid_increment(
+    deps: DepsMut,
+    sender: &str,
+    bid_increment: BidIncrement,
+) -> AuctionResult<Response> {
+    update_config(
+        deps,
+        mock_info(sender, &[]),
+        ConfigUpdate {
+            bid_increment: Some(bid_increment),
+            ..ConfigUpdate::default()
+        },
+    )
+}
+
+pub fn test_fund_creation_deposit(
+    deps: DepsMut,
+    sender: &str,
+    funds: &[Coin],
+) -> AuctionResult<Response> {
+    fund_creation_deposit(deps, mock_info(sender, funds))
+}
+
+pub fn test_add_collection(
+    deps: DepsMut,
+    sender: &str,
+    nft_contract: &str,
+    settings: Option<CollectionSettings>,
+) -> AuctionResult<Response> {
+    add_collection(
+        deps,
+        mock_info(sender, &[]),
+        nft_contract.to_string(),
+        settings,
+    )
+}
+
+pub fn test_remove_collection(
+    deps: DepsMut,
+    sender: &str,
+    nft_contract: &str,
+) -> AuctionResult<Response> {
+    remove_collection(deps, mock_info(sender, &[]), nft_contract.to_string())
+}
+
+pub fn test_propose_new_admin(
+    deps: DepsMut,
+    sender: &str,
+    new_admin: &str,
+) -> AuctionResult<Response> {
+    propose_new_admin(deps, mock_info(sender, &[]), new_admin.to_string())
+}
+
+pub fn test_accept_admin_role(deps: DepsMut, sender: &str) -> AuctionResult<Response> {
+    accept_admin_role(deps, mock_info(sender, &[]))
+}
+
+pub fn test_pause(deps: DepsMut, sender: &str) -> AuctionResult<Response> {
+    pause(deps, mock_info(sender, &[]))
+}
+
+pub fn test_unpause(deps: DepsMut, sender: &str) -> AuctionResult<Response> {
+    unpause(deps, mock_info(sender, &[]))
 }
 
 pub fn no_funds() -> Vec<Coin> {
     vec![]
 }
 
+pub fn test_update_anti_sniping(
+    deps: DepsMut,
+    sender: &str,
+    anti_sniping: AntiSniping,
+) -> AuctionResult<Response> {
+    update_config(
+        deps,
+        mock_info(sender, &[]),
+        ConfigUpdate {
+            anti_sniping: Some(anti_sniping),
+            ..ConfigUpdate::default()
+        },
+    )
+}
+
 pub fn default_duration() -> Duration {
     Duration::Time(600)
 }
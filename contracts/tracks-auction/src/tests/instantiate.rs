@@ -1,10 +1,17 @@
-use crate::query::query_config;
+use crate::contract::instantiate;
+use crate::query::{query_collections, query_config};
 use crate::tests::helpers::{
-    instantiate_with_cw20_price_asset, instantiate_with_native_price_asset, ADMIN, CW20_ADDR, UATOM,
+    instantiate_with_cw20_price_asset, instantiate_with_native_price_asset, ADMIN, CW20_ADDR,
+    NFT_ADDR, NFT_ADDR2, UATOM, USER1,
 };
-use cosmwasm_std::testing::{mock_dependencies, mock_env};
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
 use cosmwasm_std::{attr, Addr};
-use tracks_auction_api::api::{Config, PriceAsset};
+use tracks_auction_api::api::{
+    AntiSniping, BidIncrement, CollectionSettings, Config, DurationLimits, PriceAsset,
+    PriceAssetUnchecked, UnrevealedBidPenalty, WhitelistedCollection,
+};
+use tracks_auction_api::error::AuctionError::FeeTooHigh;
+use tracks_auction_api::msg::InstantiateMsg;
 
 #[test]
 fn instantiate_with_native_prices_stores_config() -> anyhow::Result<()> {
@@ -25,6 +32,9 @@ fn instantiate_with_native_prices_stores_config() -> anyhow::Result<()> {
         response.attributes,
         vec![
             attr("action", "instantiate"),
+            attr("admin", ADMIN),
+            attr("fee_bps", "0"),
+            attr("fee_collector", ADMIN),
             attr("whitelisted_nft", whitelisted_nft),
             attr("price_asset", "native"),
             attr("price_asset_denom", UATOM),
@@ -34,10 +44,26 @@ fn instantiate_with_native_prices_stores_config() -> anyhow::Result<()> {
     assert_eq!(
         query_config(deps.as_ref())?.config,
         Config {
-            whitelisted_nft: Addr::unchecked(whitelisted_nft),
-            price_asset: PriceAsset::native(UATOM),
+            admin: Addr::unchecked(ADMIN),
+            price_assets: vec![PriceAsset::native(UATOM)],
+            fee_bps: 0,
+            fee_collector: Addr::unchecked(ADMIN),
+            creation_deposit: None,
+            duration_limits: DurationLimits::default(),
+            max_active_auctions_per_creator: None,
+            bid_increment: BidIncrement::default(),
+            anti_sniping: AntiSniping::default(),
+            unrevealed_bid_penalty: UnrevealedBidPenalty::default(),
+            randomness_beacon: None,
         }
     );
+    assert_eq!(
+        query_collections(deps.as_ref(), None, None)?.collections,
+        vec![WhitelistedCollection {
+            nft_contract: Addr::unchecked(whitelisted_nft),
+            settings: CollectionSettings::default(),
+        }]
+    );
 
     Ok(())
 }
@@ -61,6 +87,9 @@ fn instantiate_with_cw20_prices_stores_config() -> anyhow::Result<()> {
         response.attributes,
         vec![
             attr("action", "instantiate"),
+            attr("admin", ADMIN),
+            attr("fee_bps", "0"),
+            attr("fee_collector", ADMIN),
             attr("whitelisted_nft", whitelisted_nft),
             attr("price_asset", "cw20"),
             attr("price_asset_cw20_contract", CW20_ADDR),
@@ -70,10 +99,137 @@ fn instantiate_with_cw20_prices_stores_config() -> anyhow::Result<()> {
     assert_eq!(
         query_config(deps.as_ref())?.config,
         Config {
-            whitelisted_nft: Addr::unchecked(whitelisted_nft),
-            price_asset: PriceAsset::cw20(Addr::unchecked(CW20_ADDR)),
+            admin: Addr::unchecked(ADMIN),
+            price_assets: vec![PriceAsset::cw20(Addr::unchecked(CW20_ADDR))],
+            fee_bps: 0,
+            fee_collector: Addr::unchecked(ADMIN),
+            creation_deposit: None,
+            duration_limits: DurationLimits::default(),
+            max_active_auctions_per_creator: None,
+            bid_increment: BidIncrement::default(),
+            anti_sniping: AntiSniping::default(),
+            unrevealed_bid_penalty: UnrevealedBidPenalty::default(),
+            randomness_beacon: None,
         }
     );
 
     Ok(())
 }
+
+#[test]
+fn instantiate_with_explicit_admin_stores_it() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    let response = instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(USER1, &[]),
+        InstantiateMsg {
+            admin: Some(ADMIN.to_string()),
+            whitelisted_nfts: vec![NFT_ADDR.to_string()],
+            price_assets: vec![PriceAssetUnchecked::native(UATOM)],
+            fee_bps: 0,
+            fee_collector: None,
+            creation_deposit: None,
+            duration_limits: None,
+            max_active_auctions_per_creator: None,
+            bid_increment: None,
+            anti_sniping: None,
+            unrevealed_bid_penalty: None,
+            randomness_beacon: None,
+        },
+    )?;
+
+    assert_eq!(response.attributes[1], attr("admin", ADMIN));
+    assert_eq!(
+        query_config(deps.as_ref())?.config.admin,
+        Addr::unchecked(ADMIN)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn instantiate_with_multiple_nfts_whitelists_all() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    let response = instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(ADMIN, &[]),
+        InstantiateMsg {
+            admin: None,
+            whitelisted_nfts: vec![NFT_ADDR.to_string(), NFT_ADDR2.to_string()],
+            price_assets: vec![PriceAssetUnchecked::native(UATOM)],
+            fee_bps: 0,
+            fee_collector: None,
+            creation_deposit: None,
+            duration_limits: None,
+            max_active_auctions_per_creator: None,
+            bid_increment: None,
+            anti_sniping: None,
+            unrevealed_bid_penalty: None,
+            randomness_beacon: None,
+        },
+    )?;
+
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "instantiate"),
+            attr("admin", ADMIN),
+            attr("fee_bps", "0"),
+            attr("fee_collector", ADMIN),
+            attr("whitelisted_nft", NFT_ADDR),
+            attr("whitelisted_nft", NFT_ADDR2),
+            attr("price_asset", "native"),
+            attr("price_asset_denom", UATOM),
+        ],
+    );
+
+    let collections = query_collections(deps.as_ref(), None, None)?.collections;
+    assert_eq!(collections.len(), 2);
+
+    // pagination works for collections
+    let collections = query_collections(
+        deps.as_ref(),
+        Some(collections[0].nft_contract.to_string()),
+        None,
+    )?
+    .collections;
+    assert_eq!(collections.len(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn instantiate_with_fee_above_maximum_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    let result = instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(ADMIN, &[]),
+        InstantiateMsg {
+            admin: None,
+            whitelisted_nfts: vec![NFT_ADDR.to_string()],
+            price_assets: vec![PriceAssetUnchecked::native(UATOM)],
+            fee_bps: 2_501,
+            fee_collector: Some(USER1.to_string()),
+            creation_deposit: None,
+            duration_limits: None,
+            max_active_auctions_per_creator: None,
+            bid_increment: None,
+            anti_sniping: None,
+            unrevealed_bid_penalty: None,
+            randomness_beacon: None,
+        },
+    );
+
+    assert_eq!(result, Err(FeeTooHigh { max_fee_bps: 2_500 }));
+
+    Ok(())
+}
@@ -0,0 +1,276 @@
+use crate::execute::{delist, receive_cw20, update_price};
+use crate::query::{query_listing, query_listings};
+use crate::tests::helpers::{
+    instantiate_with_cw20_price_asset, instantiate_with_native_price_asset, test_buy,
+    test_create_listing, test_pause, transfer_cw20_funds, transfer_native_funds, transfer_nft_msg,
+    ADMIN, CW20_ADDR, NFT_ADDR, TOKEN1, UANDR, UATOM, USER1, USER2, USER3,
+};
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{attr, coins, to_json_binary, Addr, Uint128};
+use cw20::Cw20ReceiveMsg;
+use tracks_auction_api::api::Listing;
+use tracks_auction_api::error::AuctionError::{
+    ContractPaused, InsufficientFundsForPurchase, InvalidListingPrice, ListingIdNotFound,
+    ListingPriceMismatch, NoPaymentFundsSupplied, PaymentWrongAsset, Unauthorized,
+};
+use tracks_auction_api::msg::Cw20HookMsg;
+
+#[test]
+fn buy_listing_with_native_funds() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let response = test_create_listing(deps.as_mut(), env, TOKEN1, USER1, 20)?;
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "create_listing"),
+            attr("listing_id", "0"),
+            attr("price", "20"),
+        ]
+    );
+
+    let listing = query_listing(deps.as_ref(), 0)?.listing;
+    assert_eq!(listing.seller, Addr::unchecked(USER1));
+    assert_eq!(listing.price, Uint128::new(20));
+
+    // overpayment is returned to the buyer
+    let response = test_buy(deps.as_mut(), USER2, 0, 20, &coins(25, UANDR))?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_native_funds(UANDR, 20, USER1)?,
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+            transfer_native_funds(UANDR, 5, USER2)?,
+        ]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "buy"),
+            attr("listing_id", "0"),
+            attr("price", "20"),
+            attr("fee_bps", "0"),
+            attr("fee_amount", "0"),
+            attr("creator_amount", "20"),
+            attr("excess_refund", "5"),
+        ]
+    );
+
+    assert_eq!(query_listing(deps.as_ref(), 0), Err(ListingIdNotFound));
+
+    Ok(())
+}
+
+#[test]
+fn buy_listing_with_cw20() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_cw20_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, CW20_ADDR)?;
+    test_create_listing(deps.as_mut(), env.clone(), TOKEN1, USER1, 20)?;
+
+    let response = receive_cw20(
+        deps.as_mut(),
+        env,
+        mock_info(CW20_ADDR, &[]),
+        Cw20ReceiveMsg {
+            sender: USER2.to_string(),
+            amount: Uint128::new(20),
+            msg: to_json_binary(&Cw20HookMsg::Buy {
+                listing_id: 0,
+                price: Uint128::new(20),
+            })?,
+        },
+    )?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_cw20_funds(CW20_ADDR, 20, USER1)?,
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn buy_listing_with_invalid_payment_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_create_listing(deps.as_mut(), env, TOKEN1, USER1, 20)?;
+
+    let result = test_buy(deps.as_mut(), USER2, 0, 15, &coins(20, UANDR));
+    assert_eq!(
+        result,
+        Err(ListingPriceMismatch {
+            price: Uint128::new(20)
+        })
+    );
+
+    let result = test_buy(deps.as_mut(), USER2, 0, 20, &coins(19, UANDR));
+    assert_eq!(result, Err(InsufficientFundsForPurchase));
+
+    let result = test_buy(deps.as_mut(), USER2, 0, 20, &coins(20, UATOM));
+    assert_eq!(result, Err(PaymentWrongAsset));
+
+    let result = test_buy(deps.as_mut(), USER2, 0, 20, &[]);
+    assert_eq!(result, Err(NoPaymentFundsSupplied));
+
+    let result = test_buy(deps.as_mut(), USER1, 0, 20, &coins(20, UANDR));
+    assert_eq!(result, Err(Unauthorized));
+
+    let result = test_buy(deps.as_mut(), USER2, 1, 20, &coins(20, UANDR));
+    assert_eq!(result, Err(ListingIdNotFound));
+
+    Ok(())
+}
+
+#[test]
+fn buy_listing_while_paused_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_create_listing(deps.as_mut(), env.clone(), TOKEN1, USER1, 20)?;
+
+    test_pause(deps.as_mut(), ADMIN)?;
+
+    let result = test_buy(deps.as_mut(), USER2, 0, 20, &coins(20, UANDR));
+    assert_eq!(result, Err(ContractPaused));
+
+    let result = test_create_listing(deps.as_mut(), env, "2", USER1, 20);
+    assert_eq!(result, Err(ContractPaused));
+
+    Ok(())
+}
+
+#[test]
+fn create_listing_with_zero_price_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let result = test_create_listing(deps.as_mut(), env, TOKEN1, USER1, 0);
+    assert_eq!(result, Err(InvalidListingPrice));
+
+    Ok(())
+}
+
+#[test]
+fn update_listing_price() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_create_listing(deps.as_mut(), env, TOKEN1, USER1, 20)?;
+
+    let result = update_price(deps.as_mut(), mock_info(USER2, &[]), 0, Uint128::new(10));
+    assert_eq!(result, Err(Unauthorized));
+
+    let result = update_price(deps.as_mut(), mock_info(USER1, &[]), 0, Uint128::zero());
+    assert_eq!(result, Err(InvalidListingPrice));
+
+    let response = update_price(deps.as_mut(), mock_info(USER1, &[]), 0, Uint128::new(10))?;
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "update_price"),
+            attr("listing_id", "0"),
+            attr("price", "10"),
+        ]
+    );
+
+    // buyers pinned to the old price are rejected
+    let result = test_buy(deps.as_mut(), USER2, 0, 20, &coins(20, UANDR));
+    assert_eq!(
+        result,
+        Err(ListingPriceMismatch {
+            price: Uint128::new(10)
+        })
+    );
+
+    test_buy(deps.as_mut(), USER2, 0, 10, &coins(10, UANDR))?;
+
+    Ok(())
+}
+
+#[test]
+fn delist_returns_nft() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_create_listing(deps.as_mut(), env, TOKEN1, USER1, 20)?;
+
+    let result = delist(deps.as_mut(), mock_info(USER2, &[]), 0);
+    assert_eq!(result, Err(Unauthorized));
+
+    let response = delist(deps.as_mut(), mock_info(USER1, &[]), 0)?;
+    assert_eq!(
+        response.messages,
+        vec![transfer_nft_msg(NFT_ADDR, USER1, TOKEN1)?]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![attr("action", "delist"), attr("listing_id", "0")]
+    );
+
+    let result = test_buy(deps.as_mut(), USER2, 0, 20, &coins(20, UANDR));
+    assert_eq!(result, Err(ListingIdNotFound));
+
+    Ok(())
+}
+
+#[test]
+fn query_listings_by_seller() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_create_listing(deps.as_mut(), env.clone(), "1", USER1, 20)?;
+    test_create_listing(deps.as_mut(), env.clone(), "2", USER2, 20)?;
+    test_create_listing(deps.as_mut(), env.clone(), "3", USER1, 20)?;
+    test_create_listing(deps.as_mut(), env, "4", USER1, 20)?;
+
+    let ids = |listings: Vec<Listing>| {
+        listings
+            .iter()
+            .map(|listing| listing.id)
+            .collect::<Vec<_>>()
+    };
+
+    let listings = query_listings(deps.as_ref(), None, None, None, None)?.listings;
+    assert_eq!(ids(listings), vec![0, 1, 2, 3]);
+
+    let listings =
+        query_listings(deps.as_ref(), Some(USER1.to_string()), None, None, None)?.listings;
+    assert_eq!(ids(listings), vec![0, 2, 3]);
+
+    let listings = query_listings(
+        deps.as_ref(),
+        Some(USER1.to_string()),
+        None,
+        Some(0),
+        Some(1),
+    )?
+    .listings;
+    assert_eq!(ids(listings), vec![2]);
+
+    let listings = query_listings(
+        deps.as_ref(),
+        Some(USER3.to_string()),
+        Some(NFT_ADDR.to_string()),
+        None,
+        None,
+    )?
+    .listings;
+    assert!(listings.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,255 @@
+use crate::execute::{discard_lot, open_lot, start_lot_auction, AuctionTerms};
+use crate::query::{query_auction, query_auctions, query_lot};
+use crate::tests::helpers::{
+    after_seconds, default_duration, instantiate_with_native_price_asset, send_test_nft,
+    test_add_collection, test_bid, test_cancel_auction, test_pause, test_resolve_auction,
+    transfer_native_funds, transfer_nft_msg, ADMIN, NFT_ADDR, NFT_ADDR2, TOKEN1, UANDR, USER1,
+    USER2,
+};
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{attr, coins, Addr, DepsMut, Env, Response, Uint128};
+use tracks_auction_api::api::{LotToken, PriceAssetUnchecked};
+use tracks_auction_api::error::AuctionError::{
+    ContractPaused, EmptyLot, LotAlreadyStarted, LotIdNotFound, Unauthorized,
+};
+use tracks_auction_api::error::AuctionResult;
+use tracks_auction_api::msg::Cw721HookMsg::AddToLot;
+
+fn test_add_to_lot(
+    deps: DepsMut,
+    env: Env,
+    token_id: &str,
+    sender: &str,
+    lot_id: u64,
+) -> AuctionResult<Response> {
+    send_test_nft(deps, env, NFT_ADDR, token_id, sender, &AddToLot { lot_id })
+}
+
+fn test_start_lot_auction(
+    deps: DepsMut,
+    env: Env,
+    sender: &str,
+    lot_id: u64,
+) -> AuctionResult<Response> {
+    start_lot_auction(
+        deps,
+        env,
+        mock_info(sender, &[]),
+        lot_id,
+        AuctionTerms {
+            duration: default_duration(),
+            start: None,
+            minimum_bid_amount: Uint128::new(5),
+            buyout_price: None,
+            price_asset: PriceAssetUnchecked::native(UANDR),
+            bid_increment: None,
+            kind: None,
+            second_price: None,
+            reserve_price: None,
+            relist: None,
+        },
+    )
+}
+
+/// Opens a lot for `USER1`, holding tokens "1" and "2".
+fn open_test_lot(deps: &mut DepsMut, env: &Env) -> AuctionResult<()> {
+    open_lot(deps.branch(), mock_info(USER1, &[]))?;
+    test_add_to_lot(deps.branch(), env.clone(), TOKEN1, USER1, 0)?;
+    test_add_to_lot(deps.branch(), env.clone(), "2", USER1, 0)?;
+    Ok(())
+}
+
+#[test]
+fn lot_auction_transfers_all_tokens_to_winner() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    open_test_lot(&mut deps.as_mut(), &env)?;
+
+    let response = test_start_lot_auction(deps.as_mut(), env.clone(), USER1, 0)?;
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "create_auction"),
+            attr("auction_id", "0"),
+            attr("lot_id", "0"),
+            attr("lot_size", "2"),
+        ]
+    );
+
+    let auction = query_auction(deps.as_ref(), 0)?.auction;
+    assert_eq!(auction.lot_id, Some(0));
+    assert_eq!(auction.track_token_id, TOKEN1);
+
+    test_bid(deps.as_mut(), env.clone(), USER2, 0, 5, &coins(5, UANDR))?;
+
+    let response = test_resolve_auction(deps.as_mut(), after_seconds(&env, 601), USER1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+            transfer_nft_msg(NFT_ADDR, USER2, "2")?,
+            transfer_native_funds(UANDR, 5, USER1)?,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cancel_lot_auction_returns_all_tokens() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    open_test_lot(&mut deps.as_mut(), &env)?;
+    test_start_lot_auction(deps.as_mut(), env.clone(), USER1, 0)?;
+
+    let response = test_cancel_auction(deps.as_mut(), env, USER1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER1, TOKEN1)?,
+            transfer_nft_msg(NFT_ADDR, USER1, "2")?,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn discard_draft_lot_returns_tokens() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    open_test_lot(&mut deps.as_mut(), &env)?;
+
+    let result = discard_lot(deps.as_mut(), mock_info(USER2, &[]), 0);
+    assert_eq!(result, Err(Unauthorized));
+
+    let response = discard_lot(deps.as_mut(), mock_info(USER1, &[]), 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER1, TOKEN1)?,
+            transfer_nft_msg(NFT_ADDR, USER1, "2")?,
+        ]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![attr("action", "discard_lot"), attr("lot_id", "0")]
+    );
+
+    assert_eq!(query_lot(deps.as_ref(), 0), Err(LotIdNotFound));
+
+    Ok(())
+}
+
+#[test]
+fn query_lot_contents() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    open_test_lot(&mut deps.as_mut(), &env)?;
+
+    let lot = query_lot(deps.as_ref(), 0)?.lot;
+    assert_eq!(lot.creator, Addr::unchecked(USER1));
+    assert_eq!(
+        lot.tokens,
+        vec![
+            LotToken {
+                nft_contract: Addr::unchecked(NFT_ADDR),
+                token_id: TOKEN1.to_string(),
+            },
+            LotToken {
+                nft_contract: Addr::unchecked(NFT_ADDR),
+                token_id: "2".to_string(),
+            },
+        ]
+    );
+    assert_eq!(lot.auction_id, None);
+
+    test_start_lot_auction(deps.as_mut(), env, USER1, 0)?;
+
+    assert_eq!(query_lot(deps.as_ref(), 0)?.lot.auction_id, Some(0));
+
+    Ok(())
+}
+
+#[test]
+fn query_auctions_filters_by_any_token_of_lot() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_add_collection(deps.as_mut(), ADMIN, NFT_ADDR2, None)?;
+
+    open_lot(deps.as_mut(), mock_info(USER1, &[]))?;
+    test_add_to_lot(deps.as_mut(), env.clone(), TOKEN1, USER1, 0)?;
+    send_test_nft(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR2,
+        TOKEN1,
+        USER1,
+        &AddToLot { lot_id: 0 },
+    )?;
+    test_start_lot_auction(deps.as_mut(), env, USER1, 0)?;
+
+    for nft_contract in [NFT_ADDR, NFT_ADDR2] {
+        let auctions = query_auctions(
+            deps.as_ref(),
+            true,
+            Some(nft_contract.to_string()),
+            None,
+            None,
+        )?
+        .auctions;
+        assert_eq!(auctions.len(), 1);
+        assert_eq!(auctions[0].lot_id, Some(0));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn invalid_lot_operations_fail() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    open_lot(deps.as_mut(), mock_info(USER1, &[]))?;
+
+    let result = test_start_lot_auction(deps.as_mut(), env.clone(), USER1, 0);
+    assert_eq!(result, Err(EmptyLot));
+
+    // only the lot's creator can add tokens to it
+    let result = test_add_to_lot(deps.as_mut(), env.clone(), TOKEN1, USER2, 0);
+    assert_eq!(result, Err(Unauthorized));
+
+    let result = test_add_to_lot(deps.as_mut(), env.clone(), TOKEN1, USER1, 1);
+    assert_eq!(result, Err(LotIdNotFound));
+
+    test_add_to_lot(deps.as_mut(), env.clone(), TOKEN1, USER1, 0)?;
+
+    let result = test_start_lot_auction(deps.as_mut(), env.clone(), USER2, 0);
+    assert_eq!(result, Err(Unauthorized));
+
+    test_start_lot_auction(deps.as_mut(), env.clone(), USER1, 0)?;
+
+    let result = test_add_to_lot(deps.as_mut(), env.clone(), "2", USER1, 0);
+    assert_eq!(result, Err(LotAlreadyStarted));
+
+    let result = discard_lot(deps.as_mut(), mock_info(USER1, &[]), 0);
+    assert_eq!(result, Err(LotAlreadyStarted));
+
+    test_pause(deps.as_mut(), ADMIN)?;
+
+    let result = open_lot(deps.as_mut(), mock_info(USER1, &[]));
+    assert_eq!(result, Err(ContractPaused));
+
+    Ok(())
+}
@@ -0,0 +1,209 @@
+use crate::contract::migrate;
+use crate::query::{query_auction, query_collections, query_config, query_unaccounted_balances};
+use crate::tests::helpers::{
+    create_test_auction, default_duration, instantiate_with_native_price_asset, test_bid,
+    test_update_auction_limits, ADMIN, NFT_ADDR, TOKEN1, UATOM, USER1, USER2,
+};
+use cosmwasm_std::testing::{mock_dependencies, mock_env, MockQuerier};
+use cosmwasm_std::{
+    attr, coins, to_json_binary, to_json_vec, Addr, ContractInfoResponse, ContractResult, StdError,
+    Storage, SystemResult, Uint128, WasmQuery,
+};
+use cw_storage_plus::Map;
+use tracks_auction_api::api::{
+    AntiSniping, BidIncrement, CollectionSettings, Config, DurationLimits, PriceAsset,
+    TrackAuction, UnrevealedBidPenalty, WhitelistedCollection,
+};
+use tracks_auction_api::error::AuctionError;
+use tracks_auction_api::msg::MigrateMsg;
+
+const CONTRACT_NAME: &str = "tracks-auction";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[test]
+fn migrate_from_different_contract_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UATOM)?;
+    cw2::set_contract_version(deps.as_mut().storage, "cw721-tracks", "0.1.0")?;
+
+    let result = migrate(deps.as_mut(), env.clone(), MigrateMsg {});
+
+    assert!(matches!(
+        result,
+        Err(AuctionError::Std(StdError::GenericErr { .. }))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn migrate_from_newer_version_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UATOM)?;
+    cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "99.0.0")?;
+
+    let result = migrate(deps.as_mut(), env.clone(), MigrateMsg {});
+
+    assert!(matches!(
+        result,
+        Err(AuctionError::Std(StdError::GenericErr { .. }))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn migrate_from_same_version_keeps_storage() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UATOM)?;
+    let config = query_config(deps.as_ref())?.config;
+
+    let response = migrate(deps.as_mut(), env.clone(), MigrateMsg {})?;
+
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "migrate"),
+            attr("from_version", CONTRACT_VERSION),
+            attr("to_version", CONTRACT_VERSION),
+        ]
+    );
+    assert_eq!(query_config(deps.as_ref())?.config, config);
+
+    Ok(())
+}
+
+#[test]
+fn migrate_from_v0_1_sets_contract_admin_as_config_admin() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    // v0.1 config had no admin
+    set_v0_1_config(&mut deps.storage)?;
+
+    mock_contract_admin(&mut deps.querier, ADMIN);
+
+    let response = migrate(deps.as_mut(), env.clone(), MigrateMsg {})?;
+
+    assert_eq!(response.attributes[1], attr("from_version", "0.1.0"));
+    assert_eq!(
+        query_config(deps.as_ref())?.config,
+        Config {
+            admin: Addr::unchecked(ADMIN),
+            price_assets: vec![PriceAsset::native(UATOM)],
+            fee_bps: 0,
+            fee_collector: Addr::unchecked(ADMIN),
+            creation_deposit: None,
+            duration_limits: DurationLimits::default(),
+            max_active_auctions_per_creator: None,
+            bid_increment: BidIncrement::default(),
+            anti_sniping: AntiSniping::default(),
+            unrevealed_bid_penalty: UnrevealedBidPenalty::default(),
+            randomness_beacon: None,
+        }
+    );
+    assert_eq!(
+        query_collections(deps.as_ref(), None, None)?.collections,
+        vec![WhitelistedCollection {
+            nft_contract: Addr::unchecked(NFT_ADDR),
+            settings: CollectionSettings::default(),
+        }]
+    );
+    assert_eq!(
+        cw2::get_contract_version(deps.as_ref().storage)?.version,
+        CONTRACT_VERSION
+    );
+
+    Ok(())
+}
+
+#[test]
+fn migrate_from_v0_1_keeps_active_auctions() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UATOM)?;
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        None,
+    )?;
+    test_bid(deps.as_mut(), env.clone(), USER2, 0, 10, &coins(10, UATOM))?;
+    let auction = query_auction(deps.as_ref(), 0)?.auction;
+
+    // v0.1 auctions ended with the buyout price, fields added since are serialized after it
+    let auction_key = Map::<u64, TrackAuction>::new("active_auctions").key(0);
+    let auction_json = String::from_utf8(to_json_vec(&auction)?)?;
+    let legacy_fields_end = auction_json.find(r#","fee_bps""#).unwrap();
+    let legacy_auction = format!("{}}}", &auction_json[..legacy_fields_end]);
+    deps.storage.set(&auction_key, legacy_auction.as_bytes());
+    // v0.1 did not count active auctions per creator, nor keep escrow totals
+    let count_key = Map::<&Addr, u32>::new("active_auction_counts").key(&Addr::unchecked(USER1));
+    deps.storage.remove(&count_key);
+    let escrow_key = Map::<&str, (PriceAsset, Uint128)>::new("escrow_totals")
+        .key(&*PriceAsset::native(UATOM).to_asset_info().to_string());
+    deps.storage.remove(&escrow_key);
+    set_v0_1_config(&mut deps.storage)?;
+    mock_contract_admin(&mut deps.querier, ADMIN);
+
+    migrate(deps.as_mut(), env.clone(), MigrateMsg {})?;
+
+    assert_eq!(query_auction(deps.as_ref(), 0)?.auction, auction);
+
+    deps.querier
+        .update_balance(env.contract.address.clone(), coins(10, UATOM));
+    let balances = query_unaccounted_balances(deps.as_ref(), env.clone())?.balances;
+    assert_eq!(balances[0].accounted, Uint128::new(10));
+    assert_eq!(balances[0].unaccounted, Uint128::zero());
+
+    test_update_auction_limits(deps.as_mut(), ADMIN, None, Some(1))?;
+    let result = create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        "2",
+        USER1,
+        default_duration(),
+        5,
+        None,
+    );
+    assert_eq!(result, Err(AuctionError::TooManyActiveAuctions { max: 1 }));
+
+    Ok(())
+}
+
+fn set_v0_1_config(storage: &mut dyn Storage) -> anyhow::Result<()> {
+    cw2::set_contract_version(storage, CONTRACT_NAME, "0.1.0")?;
+    storage.set(
+        b"config",
+        format!(
+            r#"{{"whitelisted_nft":"{}","price_asset":{{"native":{{"denom":"{}"}}}}}}"#,
+            NFT_ADDR, UATOM
+        )
+        .as_bytes(),
+    );
+    Ok(())
+}
+
+fn mock_contract_admin(querier: &mut MockQuerier, admin: &'static str) {
+    querier.update_wasm(move |query| match query {
+        WasmQuery::ContractInfo { .. } => {
+            let mut contract_info = ContractInfoResponse::default();
+            contract_info.creator = USER1.to_string();
+            contract_info.admin = Some(admin.to_string());
+            SystemResult::Ok(ContractResult::Ok(to_json_binary(&contract_info).unwrap()))
+        }
+        _ => panic!("unexpected wasm query"),
+    });
+}
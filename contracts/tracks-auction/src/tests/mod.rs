@@ -1,7 +1,31 @@
+mod admin;
+mod anti_sniping;
+mod auction_limits;
 mod bid;
 mod bid_cw20;
+mod bid_increment;
 mod cancel_auction;
+mod collection_offers;
+mod collections;
 mod create_auction;
+mod creation_deposit;
+mod drops;
+mod dutch_auction;
+mod fees;
 pub mod helpers;
 mod instantiate;
+mod listings;
+mod lots;
+mod migrate;
+mod offers;
+mod overpayment;
+mod pause;
+mod proxy_bids;
+mod raffles;
+mod relist;
+mod reserve_price;
 mod resolve_auction;
+mod scheduled_start;
+mod sealed_bid;
+mod second_price;
+mod update_auction;
@@ -0,0 +1,295 @@
+use crate::execute::{make_offer, receive_cw20, refund_expired_offer, retract_offer};
+use crate::query::{query_offer, query_offers_by_offerer, query_offers_by_token};
+use crate::tests::helpers::{
+    after_seconds, instantiate_with_cw20_price_asset, instantiate_with_native_price_asset,
+    send_test_nft, test_pause, transfer_cw20_funds, transfer_native_funds, transfer_nft_msg, ADMIN,
+    CW20_ADDR, NFT_ADDR, NFT_ADDR2, TOKEN1, UANDR, UATOM, USER1, USER2, USER3,
+};
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{attr, coins, to_json_binary, Coin, DepsMut, Env, Response, Uint128};
+use cw20::Cw20ReceiveMsg;
+use cw_utils::Expiration;
+use tracks_auction_api::api::Offer;
+use tracks_auction_api::error::AuctionError::{
+    ContractPaused, Cw721NotWhitelisted, InvalidOfferExpiration, NoOfferFundsSupplied,
+    OfferExpired, OfferIdNotFound, OfferNotExpired, OfferTokenMismatch, PriceAssetNotAccepted,
+    Unauthorized,
+};
+use tracks_auction_api::error::AuctionResult;
+use tracks_auction_api::msg::Cw20HookMsg;
+use tracks_auction_api::msg::Cw721HookMsg::AcceptOffer;
+
+fn expires_in(env: &Env, seconds: u64) -> Expiration {
+    Expiration::AtTime(env.block.time.plus_seconds(seconds))
+}
+
+fn test_make_offer(
+    deps: DepsMut,
+    env: Env,
+    offerer: &str,
+    token_id: &str,
+    funds: &[Coin],
+) -> AuctionResult<Response> {
+    let expires = expires_in(&env, 600);
+    make_offer(
+        deps,
+        env,
+        mock_info(offerer, funds),
+        NFT_ADDR.to_string(),
+        token_id.to_string(),
+        expires,
+    )
+}
+
+fn test_accept_offer(
+    deps: DepsMut,
+    env: Env,
+    owner: &str,
+    token_id: &str,
+    offer_id: u64,
+) -> AuctionResult<Response> {
+    send_test_nft(
+        deps,
+        env,
+        NFT_ADDR,
+        token_id,
+        owner,
+        &AcceptOffer { offer_id },
+    )
+}
+
+#[test]
+fn accept_offer() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let response = test_make_offer(deps.as_mut(), env.clone(), USER2, TOKEN1, &coins(20, UANDR))?;
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "make_offer"),
+            attr("offer_id", "0"),
+            attr("amount", "20"),
+        ]
+    );
+
+    let response = test_accept_offer(deps.as_mut(), env, USER1, TOKEN1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_native_funds(UANDR, 20, USER1)?,
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+        ]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "accept_offer"),
+            attr("offer_id", "0"),
+            attr("amount", "20"),
+            attr("fee_bps", "0"),
+            attr("fee_amount", "0"),
+            attr("creator_amount", "20"),
+        ]
+    );
+
+    assert_eq!(query_offer(deps.as_ref(), 0), Err(OfferIdNotFound));
+
+    Ok(())
+}
+
+#[test]
+fn accept_cw20_offer() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_cw20_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, CW20_ADDR)?;
+
+    receive_cw20(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(CW20_ADDR, &[]),
+        Cw20ReceiveMsg {
+            sender: USER2.to_string(),
+            amount: Uint128::new(20),
+            msg: to_json_binary(&Cw20HookMsg::MakeOffer {
+                nft_contract: NFT_ADDR.to_string(),
+                token_id: TOKEN1.to_string(),
+                expires: expires_in(&env, 600),
+            })?,
+        },
+    )?;
+
+    let response = test_accept_offer(deps.as_mut(), env, USER1, TOKEN1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_cw20_funds(CW20_ADDR, 20, USER1)?,
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn accept_offer_for_another_token_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_make_offer(deps.as_mut(), env.clone(), USER2, TOKEN1, &coins(20, UANDR))?;
+
+    let result = test_accept_offer(deps.as_mut(), env.clone(), USER1, "2", 0);
+    assert_eq!(result, Err(OfferTokenMismatch));
+
+    let result = test_accept_offer(deps.as_mut(), env.clone(), USER2, TOKEN1, 0);
+    assert_eq!(result, Err(Unauthorized));
+
+    let result = test_accept_offer(deps.as_mut(), env, USER1, TOKEN1, 1);
+    assert_eq!(result, Err(OfferIdNotFound));
+
+    Ok(())
+}
+
+#[test]
+fn expired_offer_is_refundable_by_anyone() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_make_offer(deps.as_mut(), env.clone(), USER2, TOKEN1, &coins(20, UANDR))?;
+
+    let result = refund_expired_offer(deps.as_mut(), after_seconds(&env, 599), 0);
+    assert_eq!(result, Err(OfferNotExpired));
+
+    let result = test_accept_offer(deps.as_mut(), after_seconds(&env, 600), USER1, TOKEN1, 0);
+    assert_eq!(result, Err(OfferExpired));
+
+    let response = refund_expired_offer(deps.as_mut(), after_seconds(&env, 600), 0)?;
+    assert_eq!(
+        response.messages,
+        vec![transfer_native_funds(UANDR, 20, USER2)?]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "refund_expired_offer"),
+            attr("offer_id", "0"),
+        ]
+    );
+
+    let result = refund_expired_offer(deps.as_mut(), after_seconds(&env, 600), 0);
+    assert_eq!(result, Err(OfferIdNotFound));
+
+    Ok(())
+}
+
+#[test]
+fn retract_offer_refunds_offerer() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_make_offer(deps.as_mut(), env.clone(), USER2, TOKEN1, &coins(20, UANDR))?;
+
+    let result = retract_offer(deps.as_mut(), mock_info(USER1, &[]), 0);
+    assert_eq!(result, Err(Unauthorized));
+
+    // retracting keeps working while paused
+    test_pause(deps.as_mut(), ADMIN)?;
+
+    let response = retract_offer(deps.as_mut(), mock_info(USER2, &[]), 0)?;
+    assert_eq!(
+        response.messages,
+        vec![transfer_native_funds(UANDR, 20, USER2)?]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![attr("action", "retract_offer"), attr("offer_id", "0")]
+    );
+
+    let result = test_accept_offer(deps.as_mut(), env, USER1, TOKEN1, 0);
+    assert_eq!(result, Err(ContractPaused));
+
+    Ok(())
+}
+
+#[test]
+fn make_invalid_offer_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let result = test_make_offer(deps.as_mut(), env.clone(), USER2, TOKEN1, &[]);
+    assert_eq!(result, Err(NoOfferFundsSupplied));
+
+    let result = test_make_offer(deps.as_mut(), env.clone(), USER2, TOKEN1, &coins(20, UATOM));
+    assert_eq!(result, Err(PriceAssetNotAccepted));
+
+    let result = make_offer(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(USER2, &coins(20, UANDR)),
+        NFT_ADDR2.to_string(),
+        TOKEN1.to_string(),
+        expires_in(&env, 600),
+    );
+    assert_eq!(result, Err(Cw721NotWhitelisted));
+
+    let result = make_offer(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(USER2, &coins(20, UANDR)),
+        NFT_ADDR.to_string(),
+        TOKEN1.to_string(),
+        expires_in(&env, 0),
+    );
+    assert_eq!(result, Err(InvalidOfferExpiration));
+
+    test_pause(deps.as_mut(), ADMIN)?;
+
+    let result = test_make_offer(deps.as_mut(), env, USER2, TOKEN1, &coins(20, UANDR));
+    assert_eq!(result, Err(ContractPaused));
+
+    Ok(())
+}
+
+#[test]
+fn query_offers_by_token_and_offerer() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_make_offer(deps.as_mut(), env.clone(), USER2, TOKEN1, &coins(20, UANDR))?;
+    test_make_offer(deps.as_mut(), env.clone(), USER3, TOKEN1, &coins(25, UANDR))?;
+    test_make_offer(deps.as_mut(), env.clone(), USER2, "2", &coins(10, UANDR))?;
+
+    let ids = |offers: Vec<Offer>| offers.iter().map(|offer| offer.id).collect::<Vec<_>>();
+
+    let offers = query_offers_by_token(
+        deps.as_ref(),
+        NFT_ADDR.to_string(),
+        TOKEN1.to_string(),
+        None,
+        None,
+    )?
+    .offers;
+    assert_eq!(ids(offers), vec![0, 1]);
+
+    let offers = query_offers_by_offerer(deps.as_ref(), USER2.to_string(), None, None)?.offers;
+    assert_eq!(ids(offers), vec![0, 2]);
+
+    let offers =
+        query_offers_by_offerer(deps.as_ref(), USER2.to_string(), Some(0), Some(1))?.offers;
+    assert_eq!(ids(offers), vec![2]);
+
+    let offer = query_offer(deps.as_ref(), 1)?.offer;
+    assert_eq!(offer.amount, Uint128::new(25));
+    assert_eq!(offer.expires, expires_in(&env, 600));
+
+    Ok(())
+}
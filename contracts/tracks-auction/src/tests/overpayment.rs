@@ -0,0 +1,224 @@
+use crate::query::{query_auction, query_unaccounted_balances};
+use crate::tests::helpers::{
+    create_test_auction, default_duration, instantiate_with_cw20_price_asset,
+    instantiate_with_native_price_asset, test_bid, test_cw20_bid, transfer_cw20_funds,
+    transfer_native_funds, transfer_nft_msg, ADMIN, CW20_ADDR, NFT_ADDR, TOKEN1, UANDR, USER1,
+    USER2, USER3,
+};
+use cosmwasm_std::testing::{mock_dependencies, mock_dependencies_with_balance, mock_env};
+use cosmwasm_std::{
+    attr, coins, to_json_binary, Addr, ContractResult, SystemResult, Uint128, WasmQuery,
+};
+use cw20::BalanceResponse;
+use tracks_auction_api::api::{AssetBalance, PriceAsset};
+
+#[test]
+fn bid_with_excess_funds_refunds_the_difference() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        None,
+    )?;
+
+    test_bid(deps.as_mut(), env.clone(), USER2, 0, 5, &coins(5, UANDR))?;
+
+    let response = test_bid(deps.as_mut(), env.clone(), USER3, 0, 6, &coins(10, UANDR))?;
+
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_native_funds(UANDR, 5, USER2)?,
+            transfer_native_funds(UANDR, 4, USER3)?,
+        ]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "bid"),
+            attr("auction_id", "0"),
+            attr("bid_amount", "6"),
+            attr("excess_refund", "4"),
+        ]
+    );
+
+    let active_bid = query_auction(deps.as_ref(), 0)?.auction.active_bid.unwrap();
+    assert_eq!(active_bid.amount, Uint128::new(6));
+
+    Ok(())
+}
+
+#[test]
+fn bid_cw20_with_excess_funds_refunds_the_difference() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_cw20_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, CW20_ADDR)?;
+
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        None,
+    )?;
+
+    let response = test_cw20_bid(deps.as_mut(), env.clone(), USER2, 0, 5, 7, CW20_ADDR)?;
+
+    assert_eq!(
+        response.messages,
+        vec![transfer_cw20_funds(CW20_ADDR, 2, USER2)?]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "bid"),
+            attr("auction_id", "0"),
+            attr("bid_amount", "5"),
+            attr("excess_refund", "2"),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn buyout_with_excess_funds_refunds_the_difference() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        Some(10),
+    )?;
+
+    let response = test_bid(deps.as_mut(), env.clone(), USER2, 0, 10, &coins(15, UANDR))?;
+
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_native_funds(UANDR, 10, USER1)?,
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+            transfer_native_funds(UANDR, 5, USER2)?,
+        ]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "instant_buyout"),
+            attr("auction_id", "0"),
+            attr("bid_amount", "10"),
+            attr("fee_bps", "0"),
+            attr("fee_amount", "0"),
+            attr("creator_amount", "10"),
+            attr("excess_refund", "5"),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn query_unaccounted_native_balance() -> anyhow::Result<()> {
+    // 5 held for the active bid, 2 sent to the contract directly
+    let mut deps = mock_dependencies_with_balance(&coins(7, UANDR));
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        None,
+    )?;
+    test_bid(deps.as_mut(), env.clone(), USER2, 0, 5, &coins(5, UANDR))?;
+
+    let response = query_unaccounted_balances(deps.as_ref(), env)?;
+
+    assert_eq!(
+        response.balances,
+        vec![AssetBalance {
+            asset: PriceAsset::native(UANDR),
+            held: Uint128::new(7),
+            accounted: Uint128::new(5),
+            unaccounted: Uint128::new(2),
+        }]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn query_unaccounted_cw20_balance() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::Smart { contract_addr, .. } if contract_addr == CW20_ADDR => {
+            SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&BalanceResponse {
+                    balance: Uint128::new(20),
+                })
+                .unwrap(),
+            ))
+        }
+        _ => panic!("unexpected query"),
+    });
+
+    instantiate_with_cw20_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, CW20_ADDR)?;
+
+    // no bids yet, the whole balance is unaccounted for
+    let response = query_unaccounted_balances(deps.as_ref(), env.clone())?;
+    assert_eq!(
+        response.balances,
+        vec![AssetBalance {
+            asset: PriceAsset::cw20(Addr::unchecked(CW20_ADDR)),
+            held: Uint128::new(20),
+            accounted: Uint128::zero(),
+            unaccounted: Uint128::new(20),
+        }]
+    );
+
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        None,
+    )?;
+    test_cw20_bid(deps.as_mut(), env.clone(), USER2, 0, 8, 8, CW20_ADDR)?;
+
+    let response = query_unaccounted_balances(deps.as_ref(), env)?;
+    assert_eq!(response.balances[0].accounted, Uint128::new(8));
+    assert_eq!(response.balances[0].unaccounted, Uint128::new(12));
+
+    Ok(())
+}
@@ -0,0 +1,204 @@
+use crate::query::query_pause_status;
+use crate::tests::helpers::{
+    after_height, create_test_auction, instantiate_with_cw20_price_asset,
+    instantiate_with_native_price_asset, test_bid, test_cancel_auction, test_cw20_bid, test_pause,
+    test_resolve_auction, test_unpause, ADMIN, CW20_ADDR, NFT_ADDR, TOKEN1, UANDR, USER1, USER2,
+};
+use cosmwasm_std::testing::{mock_dependencies, mock_env};
+use cosmwasm_std::{attr, coins};
+use cw_utils::Duration::Height;
+use tracks_auction_api::api::PauseStatusResponse;
+use tracks_auction_api::error::AuctionError::{ContractNotPaused, ContractPaused, Unauthorized};
+
+#[test]
+fn pause_by_admin_pauses_contract() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    assert_eq!(
+        query_pause_status(deps.as_ref())?,
+        PauseStatusResponse { paused: false }
+    );
+
+    let response = test_pause(deps.as_mut(), ADMIN)?;
+
+    assert_eq!(response.attributes, vec![attr("action", "pause")]);
+    assert_eq!(
+        query_pause_status(deps.as_ref())?,
+        PauseStatusResponse { paused: true }
+    );
+
+    let response = test_unpause(deps.as_mut(), ADMIN)?;
+
+    assert_eq!(response.attributes, vec![attr("action", "unpause")]);
+    assert_eq!(
+        query_pause_status(deps.as_ref())?,
+        PauseStatusResponse { paused: false }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn pause_by_non_admin_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    assert_eq!(test_pause(deps.as_mut(), USER1), Err(Unauthorized));
+
+    test_pause(deps.as_mut(), ADMIN)?;
+
+    assert_eq!(test_unpause(deps.as_mut(), USER1), Err(Unauthorized));
+
+    Ok(())
+}
+
+#[test]
+fn pause_when_paused_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    assert_eq!(test_unpause(deps.as_mut(), ADMIN), Err(ContractNotPaused));
+
+    test_pause(deps.as_mut(), ADMIN)?;
+
+    assert_eq!(test_pause(deps.as_mut(), ADMIN), Err(ContractPaused));
+
+    Ok(())
+}
+
+#[test]
+fn create_auction_when_paused_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_pause(deps.as_mut(), ADMIN)?;
+
+    let result = create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        Height(15),
+        5,
+        None,
+    );
+
+    assert_eq!(result, Err(ContractPaused));
+
+    test_unpause(deps.as_mut(), ADMIN)?;
+
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        Height(15),
+        5,
+        None,
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn bid_when_paused_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        Height(15),
+        5,
+        None,
+    )?;
+
+    test_pause(deps.as_mut(), ADMIN)?;
+
+    let result = test_bid(deps.as_mut(), env.clone(), USER2, 0, 6, &coins(6, UANDR));
+
+    assert_eq!(result, Err(ContractPaused));
+
+    Ok(())
+}
+
+#[test]
+fn bid_cw20_when_paused_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_cw20_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, CW20_ADDR)?;
+
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        Height(15),
+        5,
+        None,
+    )?;
+
+    test_pause(deps.as_mut(), ADMIN)?;
+
+    let result = test_cw20_bid(deps.as_mut(), env.clone(), USER2, 0, 6, 6, CW20_ADDR);
+
+    assert_eq!(result, Err(ContractPaused));
+
+    Ok(())
+}
+
+#[test]
+fn resolve_and_cancel_auction_when_paused_succeed() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        Height(15),
+        5,
+        None,
+    )?;
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        "2",
+        USER1,
+        Height(15),
+        5,
+        None,
+    )?;
+
+    test_bid(deps.as_mut(), env.clone(), USER2, 0, 6, &coins(6, UANDR))?;
+    test_bid(deps.as_mut(), env.clone(), USER2, 1, 6, &coins(6, UANDR))?;
+
+    test_pause(deps.as_mut(), ADMIN)?;
+
+    test_cancel_auction(deps.as_mut(), env.clone(), USER1, 1)?;
+    test_resolve_auction(deps.as_mut(), after_height(&env, 16), USER2, 0)?;
+
+    Ok(())
+}
@@ -0,0 +1,555 @@
+use crate::auctions::{load_proxy_bid_maximum, load_reserve_price};
+use crate::execute::{proxy_bid, receive_cw20};
+use crate::query::{query_auction, query_reserve_price, query_unaccounted_balances};
+use crate::tests::helpers::{
+    after_seconds, create_test_auction, default_duration, instantiate_with_cw20_price_asset,
+    instantiate_with_native_price_asset, send_test_nft, test_bid, test_cancel_auction,
+    test_resolve_auction, transfer_cw20_funds, transfer_native_funds, transfer_nft_msg, ADMIN,
+    CW20_ADDR, NFT_ADDR, TOKEN1, UANDR, USER1, USER2, USER3,
+};
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_dependencies_with_balance, mock_env, mock_info,
+};
+use cosmwasm_std::{attr, coins, to_json_binary, Addr, Deps, DepsMut, Env, Response, Uint128};
+use cw20::Cw20ReceiveMsg;
+use cw_utils::Duration::Time;
+use tracks_auction_api::api::{
+    AuctionKind, Bid, DutchAuction, PriceAsset, PriceAssetUnchecked, PriceDecay,
+};
+use tracks_auction_api::error::AuctionError::{BidLowerThanMinimum, ProxyBidsNotSupported};
+use tracks_auction_api::error::AuctionResult;
+use tracks_auction_api::msg::Cw20HookMsg;
+use tracks_auction_api::msg::Cw721HookMsg::CreateAuction;
+
+fn test_proxy_bid(
+    deps: DepsMut,
+    env: Env,
+    bidder: &str,
+    max_amount: u8,
+    amount_sent: u8,
+) -> AuctionResult<Response> {
+    proxy_bid(
+        deps,
+        env,
+        mock_info(bidder, &coins(amount_sent.into(), UANDR)),
+        0,
+        max_amount.into(),
+    )
+}
+
+fn test_active_bid(deps: Deps) -> AuctionResult<Option<(Addr, Uint128)>> {
+    let auction = query_auction(deps, 0)?.auction;
+    Ok(auction.active_bid.map(|bid| (bid.bidder, bid.amount)))
+}
+
+fn create_auction_with_reserve_price(
+    deps: DepsMut,
+    env: Env,
+    reserve_price: u8,
+) -> AuctionResult<Response> {
+    send_test_nft(
+        deps,
+        env,
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        &CreateAuction {
+            duration: default_duration(),
+            start: None,
+            minimum_bid_amount: Uint128::new(5),
+            buyout_price: None,
+            price_asset: PriceAssetUnchecked::native(UANDR),
+            bid_increment: None,
+            kind: None,
+            second_price: None,
+            reserve_price: Some(reserve_price.into()),
+            relist: None,
+        },
+    )
+}
+
+#[test]
+fn proxy_bid_is_placed_at_minimum_and_hides_maximum() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies_with_balance(&coins(20, UANDR));
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        None,
+    )?;
+
+    let response = test_proxy_bid(deps.as_mut(), env.clone(), USER2, 20, 20)?;
+    assert!(response.messages.is_empty());
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "proxy_bid"),
+            attr("auction_id", "0"),
+            attr("bid_amount", "5"),
+        ]
+    );
+
+    let auction = query_auction(deps.as_ref(), 0)?.auction;
+    assert_eq!(
+        auction.active_bid,
+        Some(Bid {
+            amount: Uint128::new(5),
+            asset: PriceAsset::native(UANDR),
+            bidder: Addr::unchecked(USER2),
+            posted_at: env.block.clone(),
+        })
+    );
+    assert_eq!(auction.minimum_next_bid_amount()?, Uint128::new(6));
+
+    // the whole maximum stays escrowed
+    let response = query_unaccounted_balances(deps.as_ref(), env)?;
+    assert_eq!(response.balances[0].accounted, Uint128::new(20));
+    assert_eq!(response.balances[0].unaccounted, Uint128::zero());
+
+    Ok(())
+}
+
+#[test]
+fn proxy_bid_is_raised_when_outbid_up_to_maximum() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        None,
+    )?;
+    test_proxy_bid(deps.as_mut(), env.clone(), USER2, 20, 20)?;
+
+    // a bid below the maximum is outbid right away and refunded
+    let response = test_bid(deps.as_mut(), env.clone(), USER3, 0, 10, &coins(10, UANDR))?;
+    assert_eq!(
+        response.messages,
+        vec![transfer_native_funds(UANDR, 10, USER3)?]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "bid"),
+            attr("auction_id", "0"),
+            attr("bid_amount", "10"),
+            attr("outbid_by", "proxy_bid"),
+            attr("active_bid_amount", "11"),
+        ]
+    );
+    assert_eq!(
+        test_active_bid(deps.as_ref())?,
+        Some((Addr::unchecked(USER2), Uint128::new(11)))
+    );
+
+    // bids below the raised amount are refused as usual
+    let result = test_bid(deps.as_mut(), env.clone(), USER3, 0, 11, &coins(11, UANDR));
+    assert_eq!(
+        result,
+        Err(BidLowerThanMinimum {
+            minimum: Uint128::new(12)
+        })
+    );
+
+    // the proxy bid is never raised above its maximum, and holds against a bid equal to it
+    test_bid(deps.as_mut(), env.clone(), USER3, 0, 20, &coins(20, UANDR))?;
+    assert_eq!(
+        test_active_bid(deps.as_ref())?,
+        Some((Addr::unchecked(USER2), Uint128::new(20)))
+    );
+
+    // a bid above the maximum wins, and the whole maximum is refunded
+    let response = test_bid(deps.as_mut(), env, USER3, 0, 21, &coins(21, UANDR))?;
+    assert_eq!(
+        response.messages,
+        vec![transfer_native_funds(UANDR, 20, USER2)?]
+    );
+    assert_eq!(
+        test_active_bid(deps.as_ref())?,
+        Some((Addr::unchecked(USER3), Uint128::new(21)))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn higher_proxy_bid_wins_at_one_increment_over_lower_maximum() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        None,
+    )?;
+    test_proxy_bid(deps.as_mut(), env.clone(), USER2, 20, 20)?;
+
+    let response = test_proxy_bid(deps.as_mut(), env.clone(), USER3, 30, 30)?;
+    assert_eq!(
+        response.messages,
+        vec![transfer_native_funds(UANDR, 20, USER2)?]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "proxy_bid"),
+            attr("auction_id", "0"),
+            attr("bid_amount", "21"),
+        ]
+    );
+    assert_eq!(
+        test_active_bid(deps.as_ref())?,
+        Some((Addr::unchecked(USER3), Uint128::new(21)))
+    );
+
+    // raising one's own maximum leaves the bid where it is, and refunds the previous maximum
+    let response = test_proxy_bid(deps.as_mut(), env, USER3, 40, 40)?;
+    assert_eq!(
+        response.messages,
+        vec![transfer_native_funds(UANDR, 30, USER3)?]
+    );
+    assert_eq!(
+        test_active_bid(deps.as_ref())?,
+        Some((Addr::unchecked(USER3), Uint128::new(21)))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn earlier_proxy_bid_wins_tie() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        None,
+    )?;
+    test_proxy_bid(deps.as_mut(), env.clone(), USER2, 20, 20)?;
+
+    let response = test_proxy_bid(deps.as_mut(), env, USER3, 20, 20)?;
+    assert_eq!(
+        response.messages,
+        vec![transfer_native_funds(UANDR, 20, USER3)?]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "proxy_bid"),
+            attr("auction_id", "0"),
+            attr("bid_amount", "20"),
+            attr("outbid_by", "proxy_bid"),
+            attr("active_bid_amount", "20"),
+        ]
+    );
+    assert_eq!(
+        test_active_bid(deps.as_ref())?,
+        Some((Addr::unchecked(USER2), Uint128::new(20)))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn resolve_auction_refunds_unused_maximum() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        None,
+    )?;
+    test_proxy_bid(deps.as_mut(), env.clone(), USER2, 20, 20)?;
+    test_bid(deps.as_mut(), env.clone(), USER3, 0, 10, &coins(10, UANDR))?;
+
+    let response = test_resolve_auction(deps.as_mut(), after_seconds(&env, 601), USER1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+            transfer_native_funds(UANDR, 11, USER1)?,
+            transfer_native_funds(UANDR, 9, USER2)?,
+        ]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "resolve_auction"),
+            attr("auction_id", "0"),
+            attr("sale_price", "11"),
+            attr("winner_refund", "9"),
+            attr("fee_bps", "0"),
+            attr("fee_amount", "0"),
+            attr("creator_amount", "11"),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cancel_auction_refunds_whole_maximum() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        None,
+    )?;
+    test_proxy_bid(deps.as_mut(), env.clone(), USER2, 20, 20)?;
+
+    let response = test_cancel_auction(deps.as_mut(), env, USER1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER1, TOKEN1)?,
+            transfer_native_funds(UANDR, 20, USER2)?,
+        ]
+    );
+    // the maximum isn't kept once the auction is over
+    assert_eq!(load_proxy_bid_maximum(deps.as_ref().storage, 0)?, None);
+
+    Ok(())
+}
+
+#[test]
+fn proxy_bid_reaching_buyout_price_buys_at_buyout_price() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        Some(15),
+    )?;
+
+    let response = test_proxy_bid(deps.as_mut(), env, USER2, 20, 22)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_native_funds(UANDR, 15, USER1)?,
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+            transfer_native_funds(UANDR, 7, USER2)?,
+        ]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "instant_buyout"),
+            attr("auction_id", "0"),
+            attr("bid_amount", "15"),
+            attr("fee_bps", "0"),
+            attr("fee_amount", "0"),
+            attr("creator_amount", "15"),
+            attr("excess_refund", "7"),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn proxy_bid_reaching_reserve_price_buys_at_it_without_disclosing_it() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_auction_with_reserve_price(deps.as_mut(), env.clone(), 12)?;
+
+    test_proxy_bid(deps.as_mut(), env.clone(), USER2, 10, 10)?;
+    assert_eq!(
+        test_active_bid(deps.as_ref())?,
+        Some((Addr::unchecked(USER2), Uint128::new(5)))
+    );
+
+    // the bid stays at the lowest winning amount, below the reserve price
+    test_proxy_bid(deps.as_mut(), env.clone(), USER3, 20, 20)?;
+    assert_eq!(
+        test_active_bid(deps.as_ref())?,
+        Some((Addr::unchecked(USER3), Uint128::new(11)))
+    );
+    assert!(query_reserve_price(deps.as_ref(), 0)?.reserve_met);
+
+    // the escrowed maximum reaches the reserve price, which becomes the sale price
+    let response = test_resolve_auction(deps.as_mut(), after_seconds(&env, 601), USER1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER3, TOKEN1)?,
+            transfer_native_funds(UANDR, 12, USER1)?,
+            transfer_native_funds(UANDR, 8, USER3)?,
+        ]
+    );
+    assert!(response.attributes.contains(&attr("sale_price", "12")));
+
+    // neither the maximum nor the reserve price are kept once the auction is over
+    assert_eq!(load_proxy_bid_maximum(deps.as_ref().storage, 0)?, None);
+    assert_eq!(load_reserve_price(deps.as_ref().storage, 0)?, None);
+
+    Ok(())
+}
+
+#[test]
+fn proxy_bid_below_reserve_price_is_refunded() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_auction_with_reserve_price(deps.as_mut(), env.clone(), 12)?;
+
+    test_proxy_bid(deps.as_mut(), env.clone(), USER2, 11, 11)?;
+    assert!(!query_reserve_price(deps.as_ref(), 0)?.reserve_met);
+
+    let response = test_resolve_auction(deps.as_mut(), after_seconds(&env, 601), USER1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_native_funds(UANDR, 11, USER2)?,
+            transfer_nft_msg(NFT_ADDR, USER1, TOKEN1)?,
+        ]
+    );
+    assert!(response
+        .attributes
+        .contains(&attr("no_sale_reason", "reserve_price_not_met")));
+
+    Ok(())
+}
+
+#[test]
+fn proxy_bid_with_cw20() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_cw20_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, CW20_ADDR)?;
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        None,
+    )?;
+
+    for (bidder, max_amount) in [(USER2, 20u8), (USER3, 30u8)] {
+        receive_cw20(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(CW20_ADDR, &[]),
+            Cw20ReceiveMsg {
+                sender: bidder.to_string(),
+                amount: max_amount.into(),
+                msg: to_json_binary(&Cw20HookMsg::ProxyBid {
+                    auction_id: 0,
+                    max_amount: max_amount.into(),
+                })?,
+            },
+        )?;
+    }
+    assert_eq!(
+        test_active_bid(deps.as_ref())?,
+        Some((Addr::unchecked(USER3), Uint128::new(21)))
+    );
+
+    let response = test_resolve_auction(deps.as_mut(), after_seconds(&env, 601), USER1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER3, TOKEN1)?,
+            transfer_cw20_funds(CW20_ADDR, 21, USER1)?,
+            transfer_cw20_funds(CW20_ADDR, 9, USER3)?,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn proxy_bid_on_dutch_or_second_price_auction_fails() -> anyhow::Result<()> {
+    for (kind, second_price) in [
+        (
+            Some(AuctionKind::Dutch(DutchAuction {
+                start_price: Uint128::new(50),
+                decay: PriceDecay::Linear,
+            })),
+            None,
+        ),
+        (None, Some(true)),
+    ] {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+        send_test_nft(
+            deps.as_mut(),
+            env.clone(),
+            NFT_ADDR,
+            TOKEN1,
+            USER1,
+            &CreateAuction {
+                duration: Time(600),
+                start: None,
+                minimum_bid_amount: Uint128::new(5),
+                buyout_price: None,
+                price_asset: PriceAssetUnchecked::native(UANDR),
+                bid_increment: None,
+                kind,
+                second_price,
+                reserve_price: None,
+                relist: None,
+            },
+        )?;
+
+        let result = test_proxy_bid(deps.as_mut(), env, USER2, 60, 60);
+        assert_eq!(result, Err(ProxyBidsNotSupported));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,792 @@
+use crate::admin::{update_config, ConfigUpdate};
+use crate::execute::{
+    buy_tickets, cancel_raffle, commit_raffle_draw, draw_raffle, receive_cw20, void_raffle,
+};
+use crate::query::{query_raffle, query_raffle_tickets, query_unaccounted_balances};
+use crate::raffles::draw_commitment;
+use crate::raffles::{MAX_TICKET_PURCHASES_PER_RAFFLE, RAFFLE_DRAW_TIMEOUT};
+use crate::tests::helpers::{
+    after_seconds, create_test_auction, instantiate_with_cw20_price_asset,
+    instantiate_with_native_price_asset, send_test_nft, test_fund_creation_deposit,
+    test_update_auction_limits, test_update_creation_deposit, test_update_fee, transfer_cw20_funds,
+    transfer_native_funds, transfer_nft_msg, ADMIN, CW20_ADDR, NFT_ADDR, TOKEN1, UANDR, UATOM,
+    USER1, USER2, USER3,
+};
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{
+    attr, coin, coins, to_json_binary, Addr, DepsMut, Env, HexBinary, Response, SubMsg, Timestamp,
+    Uint128,
+};
+use cw20::Cw20ReceiveMsg;
+use cw_asset::Asset;
+use cw_utils::Duration::Height;
+use tracks_auction_api::api::{
+    CreationDeposit, PriceAsset, PriceAssetUnchecked, RaffleId, RaffleStatus, RandomnessBeacon,
+    TicketPurchase,
+};
+use tracks_auction_api::error::AuctionError::{
+    DrawCommitmentMismatch, InsufficientFundsForPurchase, InvalidBeaconSignature,
+    InvalidDrawCommitment, InvalidMaxTickets, InvalidRaffleEnd, InvalidRandomnessBeacon,
+    InvalidTicketCount, InvalidTicketPrice, NoTicketsSold, NotEnoughTicketsLeft, PaymentWrongAsset,
+    PriceAssetNotAccepted, RaffleDrawAlreadyCommitted, RaffleDrawDeadlineNotReached,
+    RaffleDrawNotCommitted, RaffleEnded, RaffleHasTickets, RaffleNotOpen, RaffleStillInProgress,
+    RandomnessBeaconNotConfigured, TooManyActiveAuctions, TooManyTicketPurchases, Unauthorized,
+};
+use tracks_auction_api::error::AuctionResult;
+use tracks_auction_api::msg::Cw20HookMsg;
+use tracks_auction_api::msg::Cw721HookMsg::CreateRaffle;
+
+/// Public key of the test beacon, with signatures of rounds 35 and 36 below.
+const BEACON_PUBLIC_KEY: &str =
+    "02018145a3628c457cccd26f0d4031f241c2633ad13c00bc321d38f06fada6ca7d";
+
+/// Round 35 is the first one published after raffles ending 600 seconds after `mock_env`.
+const ROUND_35_SIGNATURE: &str = "2a8e6e01198abed698a1cdea6f4fc947b8218aa3f9b70f644fed1ebc61df3e65\
+623ea58bf6d962e88ad1f0ecd9f45520351cd7de810bb0245008e5252c987ea2";
+
+/// Beacon's signatures of the commitments to `ROUND_35_SIGNATURE` for raffle 0,
+/// and to `ROUND_36_SIGNATURE` for raffle 1.
+const RAFFLE_0_COMMITMENT_SIGNATURE: &str =
+    "10e8ea55ae828fea992a9210b60b7ecc32ae6cf47d0005cbda835caa7d978fcb\
+7456218390ae7f7851b2f783316022df0f7ef060c07a5b73666ce09352d74af3";
+const RAFFLE_1_COMMITMENT_SIGNATURE: &str =
+    "9652cd1e06f816cb8dec8ccff2004b5756d2575935ff5f1d10841670b6a5ac7f\
+1f6a876dc2fcecfbd2819dcfc84cbec13a0ae4c337f71800634dd997e66bc6ca";
+
+/// Same as `ROUND_35_SIGNATURE`, with its `s` value negated.
+const ROUND_35_MALLEATED_SIGNATURE: &str =
+    "2a8e6e01198abed698a1cdea6f4fc947b8218aa3f9b70f644fed1ebc61df3e65\
+9dc15a7409269d17752e0f13260baade859205082e3cf0176fc97967a39dc29f";
+
+const ROUND_36_SIGNATURE: &str = "d05b0ec8c3224c3f5597837da48a69c14c39ffb1bb7886e228053c6dd07d9bcd\
+0598918b353303b9a9efd50b97193fcf3c92561edf391603ab70617de4234649";
+
+fn test_beacon() -> RandomnessBeacon {
+    RandomnessBeacon {
+        public_key: HexBinary::from_hex(BEACON_PUBLIC_KEY).unwrap(),
+        genesis_time: Timestamp::from_seconds(1_571_797_000),
+        period: 30,
+    }
+}
+
+fn test_update_randomness_beacon(
+    deps: DepsMut,
+    beacon: RandomnessBeacon,
+) -> AuctionResult<Response> {
+    update_config(
+        deps,
+        mock_info(ADMIN, &[]),
+        ConfigUpdate {
+            randomness_beacon: Some(beacon),
+            ..ConfigUpdate::default()
+        },
+    )
+}
+
+/// Raffles `TOKEN1` of `USER1` with tickets priced at 10 UANDR, ending in 600 seconds.
+fn create_test_raffle(
+    deps: DepsMut,
+    env: Env,
+    max_tickets: Option<u32>,
+) -> AuctionResult<Response> {
+    send_test_nft(
+        deps,
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        &CreateRaffle {
+            ticket_price: Uint128::new(10),
+            price_asset: PriceAssetUnchecked::native(UANDR),
+            ends_at: env.block.time.plus_seconds(600),
+            max_tickets,
+        },
+    )
+}
+
+/// Commits the test beacon to drawing raffle 0 with `ROUND_35_SIGNATURE`,
+/// or raffle 1 with `ROUND_36_SIGNATURE`.
+fn test_commit_raffle_draw(
+    deps: DepsMut,
+    env: Env,
+    raffle_id: RaffleId,
+) -> AuctionResult<Response> {
+    let (signature, commitment_signature) = match raffle_id {
+        0 => (ROUND_35_SIGNATURE, RAFFLE_0_COMMITMENT_SIGNATURE),
+        _ => (ROUND_36_SIGNATURE, RAFFLE_1_COMMITMENT_SIGNATURE),
+    };
+    commit_raffle_draw(
+        deps,
+        env,
+        raffle_id,
+        draw_commitment(&HexBinary::from_hex(signature)?),
+        HexBinary::from_hex(commitment_signature)?,
+    )
+}
+
+fn test_buy_tickets(
+    deps: DepsMut,
+    env: Env,
+    buyer: &str,
+    count: u32,
+    amount: u128,
+) -> AuctionResult<Response> {
+    buy_tickets(deps, env, mock_info(buyer, &coins(amount, UANDR)), 0, count)
+}
+
+fn test_draw_raffle(deps: DepsMut, env: Env, signature: &str) -> AuctionResult<Response> {
+    draw_raffle(deps, env, 0, HexBinary::from_hex(signature)?)
+}
+
+#[test]
+fn raffle_winner_is_drawn_with_beacon_round() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_randomness_beacon(deps.as_mut(), test_beacon())?;
+
+    let response = create_test_raffle(deps.as_mut(), env.clone(), None)?;
+    test_commit_raffle_draw(deps.as_mut(), env.clone(), 0)?;
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "create_raffle"),
+            attr("raffle_id", "0"),
+            attr("draw_round", "35"),
+        ]
+    );
+
+    test_buy_tickets(deps.as_mut(), env.clone(), USER2, 2, 20)?;
+    let response = test_buy_tickets(deps.as_mut(), env.clone(), USER3, 3, 35)?;
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "buy_tickets"),
+            attr("raffle_id", "0"),
+            attr("first_ticket", "2"),
+            attr("count", "3"),
+            attr("price", "30"),
+            attr("excess_refund", "5"),
+        ]
+    );
+    assert_eq!(
+        response.messages,
+        vec![transfer_native_funds(UANDR, 5, USER3)?]
+    );
+    test_buy_tickets(deps.as_mut(), env.clone(), USER2, 2, 20)?;
+
+    let result = test_draw_raffle(deps.as_mut(), env.clone(), ROUND_35_SIGNATURE);
+    assert_eq!(result, Err(RaffleStillInProgress));
+
+    let env = after_seconds(&env, 600);
+
+    let result = test_draw_raffle(deps.as_mut(), env.clone(), ROUND_36_SIGNATURE);
+    assert_eq!(result, Err(InvalidBeaconSignature { round: 35 }));
+
+    // the signature, mixed with the purchases, draws ticket 3 out of 7, bought by USER3
+    let response = test_draw_raffle(deps.as_mut(), env.clone(), ROUND_35_SIGNATURE)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER3, TOKEN1)?,
+            transfer_native_funds(UANDR, 70, USER1)?,
+        ]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "draw_raffle"),
+            attr("raffle_id", "0"),
+            attr("draw_round", "35"),
+            attr("winning_ticket", "3"),
+            attr("winner", USER3),
+            attr("ticket_sales", "70"),
+            attr("fee_bps", "0"),
+            attr("fee_amount", "0"),
+            attr("creator_amount", "70"),
+        ]
+    );
+
+    let raffle = query_raffle(deps.as_ref(), 0)?.raffle;
+    assert_eq!(raffle.status, RaffleStatus::Drawn);
+    assert_eq!(raffle.winning_ticket, Some(3));
+    assert_eq!(raffle.winner, Some(Addr::unchecked(USER3)));
+
+    let result = test_draw_raffle(deps.as_mut(), env, ROUND_35_SIGNATURE);
+    assert_eq!(result, Err(RaffleNotOpen));
+
+    Ok(())
+}
+
+#[test]
+fn raffle_draw_is_committed_to_before_ticket_sales() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_randomness_beacon(deps.as_mut(), test_beacon())?;
+    create_test_raffle(deps.as_mut(), env.clone(), None)?;
+
+    let commitment = draw_commitment(&HexBinary::from_hex(ROUND_35_SIGNATURE)?);
+
+    // the commitment has to be signed by the beacon, for this raffle
+    let result = commit_raffle_draw(
+        deps.as_mut(),
+        env.clone(),
+        0,
+        commitment.clone(),
+        HexBinary::from_hex(RAFFLE_1_COMMITMENT_SIGNATURE)?,
+    );
+    assert_eq!(result, Err(InvalidDrawCommitment));
+
+    let result = commit_raffle_draw(
+        deps.as_mut(),
+        after_seconds(&env, 600),
+        0,
+        commitment.clone(),
+        HexBinary::from_hex(RAFFLE_0_COMMITMENT_SIGNATURE)?,
+    );
+    assert_eq!(result, Err(RaffleEnded));
+
+    let response = test_commit_raffle_draw(deps.as_mut(), env.clone(), 0)?;
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "commit_raffle_draw"),
+            attr("raffle_id", "0"),
+            attr("draw_commitment", commitment.to_hex()),
+        ]
+    );
+    assert_eq!(
+        query_raffle(deps.as_ref(), 0)?.raffle.draw_commitment,
+        Some(commitment)
+    );
+
+    let result = test_commit_raffle_draw(deps.as_mut(), env.clone(), 0);
+    assert_eq!(result, Err(RaffleDrawAlreadyCommitted));
+
+    test_buy_tickets(deps.as_mut(), env.clone(), USER2, 2, 20)?;
+
+    // a valid beacon signature of the round still has to be the committed one
+    let result = test_draw_raffle(
+        deps.as_mut(),
+        after_seconds(&env, 600),
+        ROUND_35_MALLEATED_SIGNATURE,
+    );
+    assert_eq!(result, Err(DrawCommitmentMismatch));
+
+    test_draw_raffle(deps.as_mut(), after_seconds(&env, 600), ROUND_35_SIGNATURE)?;
+
+    Ok(())
+}
+
+#[test]
+fn draw_raffle_pays_fee_fixed_at_creation() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_randomness_beacon(deps.as_mut(), test_beacon())?;
+    test_update_fee(deps.as_mut(), ADMIN, Some(1000), Some(USER3))?;
+    create_test_raffle(deps.as_mut(), env.clone(), None)?;
+    test_commit_raffle_draw(deps.as_mut(), env.clone(), 0)?;
+
+    test_update_fee(deps.as_mut(), ADMIN, Some(0), None)?;
+
+    test_buy_tickets(deps.as_mut(), env.clone(), USER2, 7, 70)?;
+
+    let response = test_draw_raffle(deps.as_mut(), after_seconds(&env, 600), ROUND_35_SIGNATURE)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+            transfer_native_funds(UANDR, 63, USER1)?,
+            transfer_native_funds(UANDR, 7, USER3)?,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn buy_tickets_with_cw20() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_cw20_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, CW20_ADDR)?;
+    test_update_randomness_beacon(deps.as_mut(), test_beacon())?;
+    send_test_nft(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        &CreateRaffle {
+            ticket_price: Uint128::new(10),
+            price_asset: PriceAssetUnchecked::cw20(CW20_ADDR),
+            ends_at: env.block.time.plus_seconds(600),
+            max_tickets: None,
+        },
+    )?;
+    test_commit_raffle_draw(deps.as_mut(), env.clone(), 0)?;
+
+    receive_cw20(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(CW20_ADDR, &[]),
+        Cw20ReceiveMsg {
+            sender: USER2.to_string(),
+            amount: Uint128::new(30),
+            msg: to_json_binary(&Cw20HookMsg::BuyTickets {
+                raffle_id: 0,
+                count: 3,
+            })?,
+        },
+    )?;
+
+    let response = test_draw_raffle(deps.as_mut(), after_seconds(&env, 600), ROUND_35_SIGNATURE)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+            transfer_cw20_funds(CW20_ADDR, 30, USER1)?,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn invalid_ticket_purchases_fail() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_randomness_beacon(deps.as_mut(), test_beacon())?;
+    create_test_raffle(deps.as_mut(), env.clone(), Some(3))?;
+
+    // tickets only go on sale once the draw is committed to
+    let result = test_buy_tickets(deps.as_mut(), env.clone(), USER2, 1, 10);
+    assert_eq!(result, Err(RaffleDrawNotCommitted));
+
+    test_commit_raffle_draw(deps.as_mut(), env.clone(), 0)?;
+
+    let result = test_buy_tickets(deps.as_mut(), env.clone(), USER1, 1, 10);
+    assert_eq!(result, Err(Unauthorized));
+
+    let result = test_buy_tickets(deps.as_mut(), env.clone(), USER2, 0, 10);
+    assert_eq!(result, Err(InvalidTicketCount));
+
+    let result = test_buy_tickets(deps.as_mut(), env.clone(), USER2, 2, 19);
+    assert_eq!(result, Err(InsufficientFundsForPurchase));
+
+    let result = buy_tickets(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(USER2, &coins(10, UATOM)),
+        0,
+        1,
+    );
+    assert_eq!(result, Err(PaymentWrongAsset));
+
+    test_buy_tickets(deps.as_mut(), env.clone(), USER2, 2, 20)?;
+
+    let result = test_buy_tickets(deps.as_mut(), env.clone(), USER3, 2, 20);
+    assert_eq!(result, Err(NotEnoughTicketsLeft { remaining: 1 }));
+
+    let result = test_buy_tickets(deps.as_mut(), after_seconds(&env, 600), USER3, 1, 10);
+    assert_eq!(result, Err(RaffleEnded));
+
+    // ticket sales are held until the raffle is drawn
+    deps.querier
+        .update_balance(env.contract.address.clone(), coins(20, UANDR));
+    let balances = query_unaccounted_balances(deps.as_ref(), env)?.balances;
+    assert_eq!(balances[0].accounted, Uint128::new(20));
+    assert_eq!(balances[0].unaccounted, Uint128::zero());
+
+    assert_eq!(
+        query_raffle_tickets(deps.as_ref(), 0, None, None)?.purchases,
+        vec![TicketPurchase {
+            buyer: Addr::unchecked(USER2),
+            first_ticket: 0,
+            count: 2,
+        }]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cancel_raffle_without_tickets() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_randomness_beacon(deps.as_mut(), test_beacon())?;
+    create_test_raffle(deps.as_mut(), env.clone(), None)?;
+    test_commit_raffle_draw(deps.as_mut(), env.clone(), 0)?;
+
+    let env = after_seconds(&env, 600);
+
+    let result = test_draw_raffle(deps.as_mut(), env.clone(), ROUND_35_SIGNATURE);
+    assert_eq!(result, Err(NoTicketsSold));
+
+    let result = cancel_raffle(deps.as_mut(), mock_info(USER2, &[]), 0);
+    assert_eq!(result, Err(Unauthorized));
+
+    let response = cancel_raffle(deps.as_mut(), mock_info(USER1, &[]), 0)?;
+    assert_eq!(
+        response.messages,
+        vec![transfer_nft_msg(NFT_ADDR, USER1, TOKEN1)?]
+    );
+    assert_eq!(
+        query_raffle(deps.as_ref(), 0)?.raffle.status,
+        RaffleStatus::Canceled
+    );
+
+    // raffles with tickets sold can only be drawn, or voided after their draw deadline
+    send_test_nft(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        "2",
+        USER1,
+        &CreateRaffle {
+            ticket_price: Uint128::new(10),
+            price_asset: PriceAssetUnchecked::native(UANDR),
+            ends_at: env.block.time.plus_seconds(600),
+            max_tickets: None,
+        },
+    )?;
+    test_commit_raffle_draw(deps.as_mut(), env.clone(), 1)?;
+    buy_tickets(
+        deps.as_mut(),
+        env,
+        mock_info(USER2, &coins(10, UANDR)),
+        1,
+        1,
+    )?;
+
+    let result = cancel_raffle(deps.as_mut(), mock_info(USER1, &[]), 1);
+    assert_eq!(result, Err(RaffleHasTickets));
+
+    Ok(())
+}
+
+#[test]
+fn undrawn_raffle_is_voided_after_draw_deadline() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_randomness_beacon(deps.as_mut(), test_beacon())?;
+    create_test_raffle(deps.as_mut(), env.clone(), None)?;
+    test_commit_raffle_draw(deps.as_mut(), env.clone(), 0)?;
+
+    test_buy_tickets(deps.as_mut(), env.clone(), USER2, 2, 20)?;
+    test_buy_tickets(deps.as_mut(), env.clone(), USER3, 3, 30)?;
+    test_buy_tickets(deps.as_mut(), env.clone(), USER2, 1, 10)?;
+
+    let raffle = query_raffle(deps.as_ref(), 0)?.raffle;
+    assert_eq!(
+        raffle.draw_deadline,
+        raffle.ends_at.plus_seconds(RAFFLE_DRAW_TIMEOUT)
+    );
+
+    let env = after_seconds(&env, 600 + RAFFLE_DRAW_TIMEOUT);
+    let result = void_raffle(deps.as_mut(), env.clone(), 0);
+    assert_eq!(result, Err(RaffleDrawDeadlineNotReached));
+
+    let env = after_seconds(&env, 1);
+    let response = void_raffle(deps.as_mut(), env.clone(), 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER1, TOKEN1)?,
+            transfer_native_funds(UANDR, 30, USER2)?,
+            transfer_native_funds(UANDR, 30, USER3)?,
+        ]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "void_raffle"),
+            attr("raffle_id", "0"),
+            attr("ticket_sales", "60"),
+        ]
+    );
+    assert_eq!(
+        query_raffle(deps.as_ref(), 0)?.raffle.status,
+        RaffleStatus::Voided
+    );
+
+    // the refunded ticket sales are no longer held
+    let balances = query_unaccounted_balances(deps.as_ref(), env.clone())?.balances;
+    assert!(balances.is_empty());
+
+    let result = test_draw_raffle(deps.as_mut(), env.clone(), ROUND_35_SIGNATURE);
+    assert_eq!(result, Err(RaffleNotOpen));
+    let result = void_raffle(deps.as_mut(), env, 0);
+    assert_eq!(result, Err(RaffleNotOpen));
+
+    Ok(())
+}
+
+#[test]
+fn ticket_purchases_per_raffle_are_capped() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_randomness_beacon(deps.as_mut(), test_beacon())?;
+    create_test_raffle(deps.as_mut(), env.clone(), None)?;
+    test_commit_raffle_draw(deps.as_mut(), env.clone(), 0)?;
+
+    for _ in 0..MAX_TICKET_PURCHASES_PER_RAFFLE {
+        test_buy_tickets(deps.as_mut(), env.clone(), USER2, 1, 10)?;
+    }
+
+    let result = test_buy_tickets(deps.as_mut(), env.clone(), USER3, 1, 10);
+    assert_eq!(
+        result,
+        Err(TooManyTicketPurchases {
+            max: MAX_TICKET_PURCHASES_PER_RAFFLE
+        })
+    );
+
+    // all purchases of a buyer are refunded at once
+    let env = after_seconds(&env, 601 + RAFFLE_DRAW_TIMEOUT);
+    let response = void_raffle(deps.as_mut(), env, 0)?;
+    assert_eq!(response.messages.len(), 2);
+    assert_eq!(
+        response.messages[1],
+        SubMsg::new(Asset::native(UANDR, 1000u128).transfer_msg(USER2)?)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn invalid_raffle_creation_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let result = create_test_raffle(deps.as_mut(), env.clone(), None);
+    assert_eq!(result, Err(RandomnessBeaconNotConfigured));
+
+    for beacon in [
+        RandomnessBeacon {
+            period: 0,
+            ..test_beacon()
+        },
+        RandomnessBeacon {
+            public_key: HexBinary::from_hex("02018145a3628c457c")?,
+            ..test_beacon()
+        },
+    ] {
+        let result = test_update_randomness_beacon(deps.as_mut(), beacon);
+        assert_eq!(result, Err(InvalidRandomnessBeacon));
+    }
+
+    let response = test_update_randomness_beacon(deps.as_mut(), test_beacon())?;
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "update_config"),
+            attr("randomness_beacon_public_key", BEACON_PUBLIC_KEY),
+            attr("randomness_beacon_genesis_time", "1571797000"),
+            attr("randomness_beacon_period", "30"),
+        ]
+    );
+
+    let result = create_test_raffle(deps.as_mut(), env.clone(), Some(0));
+    assert_eq!(result, Err(InvalidMaxTickets));
+
+    for (ticket_price, price_asset, ends_at, error) in [
+        (
+            0,
+            UANDR,
+            env.block.time.plus_seconds(600),
+            InvalidTicketPrice,
+        ),
+        (
+            10,
+            UATOM,
+            env.block.time.plus_seconds(600),
+            PriceAssetNotAccepted,
+        ),
+        (10, UANDR, env.block.time, InvalidRaffleEnd),
+    ] {
+        let result = send_test_nft(
+            deps.as_mut(),
+            env.clone(),
+            NFT_ADDR,
+            TOKEN1,
+            USER1,
+            &CreateRaffle {
+                ticket_price: Uint128::new(ticket_price),
+                price_asset: PriceAssetUnchecked::native(price_asset),
+                ends_at,
+                max_tickets: None,
+            },
+        );
+        assert_eq!(result, Err(error));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn raffle_takes_creation_deposit_and_returns_it_when_drawn() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_randomness_beacon(deps.as_mut(), test_beacon())?;
+    test_update_creation_deposit(
+        deps.as_mut(),
+        ADMIN,
+        Some(CreationDeposit {
+            amount: coin(10, UATOM),
+            forfeit_unsold: true,
+        }),
+    )?;
+
+    let result = create_test_raffle(deps.as_mut(), env.clone(), None);
+    assert!(result.is_err());
+
+    test_fund_creation_deposit(deps.as_mut(), USER1, &coins(10, UATOM))?;
+    create_test_raffle(deps.as_mut(), env.clone(), None)?;
+    test_commit_raffle_draw(deps.as_mut(), env.clone(), 0)?;
+    test_buy_tickets(deps.as_mut(), env.clone(), USER2, 1, 10)?;
+
+    // the deposit is held while the raffle is open
+    let balances = query_unaccounted_balances(deps.as_ref(), env.clone())?.balances;
+    let accounted: Vec<_> = balances
+        .iter()
+        .map(|balance| (balance.asset.clone(), balance.accounted.u128()))
+        .collect();
+    assert_eq!(
+        accounted,
+        vec![
+            (PriceAsset::native(UANDR), 10),
+            (PriceAsset::native(UATOM), 10),
+        ]
+    );
+
+    let response = test_draw_raffle(deps.as_mut(), after_seconds(&env, 600), ROUND_35_SIGNATURE)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+            transfer_native_funds(UANDR, 10, USER1)?,
+            transfer_native_funds(UATOM, 10, USER1)?,
+        ]
+    );
+    assert!(response
+        .attributes
+        .contains(&attr("creation_deposit_returned", "10uatom")));
+
+    Ok(())
+}
+
+#[test]
+fn cancel_raffle_forfeits_creation_deposit() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_randomness_beacon(deps.as_mut(), test_beacon())?;
+    test_update_fee(deps.as_mut(), ADMIN, None, Some(USER3))?;
+    test_update_creation_deposit(
+        deps.as_mut(),
+        ADMIN,
+        Some(CreationDeposit {
+            amount: coin(10, UATOM),
+            forfeit_unsold: true,
+        }),
+    )?;
+    test_fund_creation_deposit(deps.as_mut(), USER1, &coins(10, UATOM))?;
+    create_test_raffle(deps.as_mut(), env.clone(), None)?;
+
+    let response = cancel_raffle(deps.as_mut(), mock_info(USER1, &[]), 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER1, TOKEN1)?,
+            transfer_native_funds(UATOM, 10, USER3)?,
+        ]
+    );
+    assert!(response
+        .attributes
+        .contains(&attr("creation_deposit_forfeited", "10uatom")));
+
+    Ok(())
+}
+
+#[test]
+fn open_raffles_count_towards_active_auction_limit() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_randomness_beacon(deps.as_mut(), test_beacon())?;
+    test_update_auction_limits(deps.as_mut(), ADMIN, None, Some(2))?;
+
+    create_test_raffle(deps.as_mut(), env.clone(), None)?;
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        "2",
+        USER1,
+        Height(15),
+        5,
+        None,
+    )?;
+
+    let result = create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        "3",
+        USER1,
+        Height(15),
+        5,
+        None,
+    );
+    assert_eq!(result, Err(TooManyActiveAuctions { max: 2 }));
+
+    let result = send_test_nft(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        "3",
+        USER1,
+        &CreateRaffle {
+            ticket_price: Uint128::new(10),
+            price_asset: PriceAssetUnchecked::native(UANDR),
+            ends_at: env.block.time.plus_seconds(600),
+            max_tickets: None,
+        },
+    );
+    assert_eq!(result, Err(TooManyActiveAuctions { max: 2 }));
+
+    // canceled raffles no longer count
+    cancel_raffle(deps.as_mut(), mock_info(USER1, &[]), 0)?;
+    create_test_auction(
+        deps.as_mut(),
+        env,
+        NFT_ADDR,
+        "3",
+        USER1,
+        Height(15),
+        5,
+        None,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,190 @@
+use crate::query::query_auction;
+use crate::tests::helpers::{
+    after_seconds, instantiate_with_native_price_asset, send_test_nft, test_bid,
+    test_fund_creation_deposit, test_resolve_auction, test_update_creation_deposit,
+    transfer_native_funds, transfer_nft_msg, ADMIN, NFT_ADDR, TOKEN1, UANDR, UATOM, USER1, USER2,
+};
+use cosmwasm_std::testing::{mock_dependencies, mock_env};
+use cosmwasm_std::{attr, coin, coins, DepsMut, Env, Response, Uint128};
+use cw_utils::Duration::{Height, Time};
+use tracks_auction_api::api::{CreationDeposit, PriceAssetUnchecked, RelistAttempt};
+use tracks_auction_api::error::AuctionError::{InvalidRelistAttempt, TooManyRelistAttempts};
+use tracks_auction_api::error::AuctionResult;
+use tracks_auction_api::msg::Cw721HookMsg::CreateAuction;
+
+fn create_relisting_auction(
+    deps: DepsMut,
+    env: Env,
+    relist: Vec<RelistAttempt>,
+) -> AuctionResult<Response> {
+    send_test_nft(
+        deps,
+        env,
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        &CreateAuction {
+            duration: Time(600),
+            start: None,
+            minimum_bid_amount: Uint128::new(5),
+            buyout_price: None,
+            price_asset: PriceAssetUnchecked::native(UANDR),
+            bid_increment: None,
+            kind: None,
+            second_price: None,
+            reserve_price: None,
+            relist: Some(relist),
+        },
+    )
+}
+
+fn relist_attempt(seconds: u64, minimum_bid_amount: Option<u128>) -> RelistAttempt {
+    RelistAttempt {
+        duration: Time(seconds),
+        minimum_bid_amount: minimum_bid_amount.map(Uint128::new),
+    }
+}
+
+#[test]
+fn unsold_auction_is_relisted() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let response = create_relisting_auction(
+        deps.as_mut(),
+        env.clone(),
+        vec![relist_attempt(300, Some(3)), relist_attempt(300, None)],
+    )?;
+    assert!(response.attributes.contains(&attr("relist_attempts", "2")));
+
+    // the NFT stays escrowed for the new auction
+    let env = after_seconds(&env, 601);
+    let response = test_resolve_auction(deps.as_mut(), env.clone(), USER2, 0)?;
+    assert_eq!(response.messages, vec![]);
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "resolve_auction"),
+            attr("auction_id", "0"),
+            attr("relisted_as", "1"),
+            attr("relist_attempts_left", "1"),
+        ]
+    );
+
+    let auction = query_auction(deps.as_ref(), 1)?.auction;
+    assert_eq!(auction.relisted_from, Some(0));
+    assert_eq!(auction.duration, Time(300));
+    assert_eq!(auction.minimum_bid_amount, Uint128::new(3));
+    assert_eq!(auction.relist_attempts, vec![relist_attempt(300, None)]);
+    assert_eq!(auction.creator.as_str(), USER1);
+    assert_eq!(auction.track_token_id, TOKEN1);
+
+    // the last round keeps the lowered minimum bid
+    let env = after_seconds(&env, 301);
+    test_resolve_auction(deps.as_mut(), env.clone(), USER2, 1)?;
+
+    let auction = query_auction(deps.as_ref(), 2)?.auction;
+    assert_eq!(auction.relisted_from, Some(1));
+    assert_eq!(auction.minimum_bid_amount, Uint128::new(3));
+    assert_eq!(auction.relist_attempts, vec![]);
+
+    // once all attempts are used, the NFT goes back to the creator
+    let response = test_resolve_auction(deps.as_mut(), after_seconds(&env, 301), USER2, 2)?;
+    assert_eq!(
+        response.messages,
+        vec![transfer_nft_msg(NFT_ADDR, USER1, TOKEN1)?]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn sold_auction_is_not_relisted() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_relisting_auction(deps.as_mut(), env.clone(), vec![relist_attempt(300, None)])?;
+    test_bid(deps.as_mut(), env.clone(), USER2, 0, 5, &coins(5, UANDR))?;
+
+    let response = test_resolve_auction(deps.as_mut(), after_seconds(&env, 601), USER1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+            transfer_native_funds(UANDR, 5, USER1)?,
+        ]
+    );
+    assert!(query_auction(deps.as_ref(), 1).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn creation_deposit_carries_over_to_relisted_auction() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_creation_deposit(
+        deps.as_mut(),
+        ADMIN,
+        Some(CreationDeposit {
+            amount: coin(10, UATOM),
+            forfeit_unsold: false,
+        }),
+    )?;
+    test_fund_creation_deposit(deps.as_mut(), USER1, &coins(10, UATOM))?;
+
+    create_relisting_auction(deps.as_mut(), env.clone(), vec![relist_attempt(300, None)])?;
+
+    // no new deposit is needed for the relisted auction
+    let env = after_seconds(&env, 601);
+    let response = test_resolve_auction(deps.as_mut(), env.clone(), USER2, 0)?;
+    assert_eq!(response.messages, vec![]);
+
+    let auction = query_auction(deps.as_ref(), 1)?.auction;
+    assert_eq!(
+        auction.creation_deposit.map(|it| it.amount),
+        Some(coin(10, UATOM))
+    );
+
+    let response = test_resolve_auction(deps.as_mut(), after_seconds(&env, 301), USER2, 1)?;
+    assert!(response
+        .attributes
+        .contains(&attr("creation_deposit_returned", "10uatom")));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_relist_attempts_fail() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let result = create_relisting_auction(
+        deps.as_mut(),
+        env.clone(),
+        vec![relist_attempt(300, Some(4)), relist_attempt(300, Some(5))],
+    );
+    assert_eq!(result, Err(InvalidRelistAttempt));
+
+    let result = create_relisting_auction(
+        deps.as_mut(),
+        env.clone(),
+        vec![RelistAttempt {
+            duration: Height(100),
+            minimum_bid_amount: None,
+        }],
+    );
+    assert_eq!(result, Err(InvalidRelistAttempt));
+
+    let result = create_relisting_auction(deps.as_mut(), env, vec![relist_attempt(300, None); 11]);
+    assert_eq!(result, Err(TooManyRelistAttempts { max: 10 }));
+
+    Ok(())
+}
@@ -0,0 +1,248 @@
+use crate::execute::{commit_bid, reveal_bid};
+use crate::query::query_reserve_price;
+use crate::sealed_bids::sealed_bid_commitment;
+use crate::tests::helpers::{
+    after_seconds, create_test_auction, default_duration, instantiate_with_native_price_asset,
+    send_test_nft, test_bid, test_resolve_auction, transfer_native_funds, transfer_nft_msg, ADMIN,
+    NFT_ADDR, TOKEN1, UANDR, USER1, USER2, USER3,
+};
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{attr, coins, Addr, DepsMut, Env, Response, Uint128};
+use tracks_auction_api::api::{
+    AuctionKind, DutchAuction, PriceAssetUnchecked, PriceDecay, ReservePriceResponse,
+    SealedBidAuction,
+};
+use tracks_auction_api::error::AuctionError::InvalidReservePrice;
+use tracks_auction_api::error::AuctionResult;
+use tracks_auction_api::msg::Cw721HookMsg::CreateAuction;
+
+fn create_reserve_price_auction(
+    deps: DepsMut,
+    env: Env,
+    reserve_price: u8,
+    buyout_price: Option<u8>,
+    kind: Option<AuctionKind>,
+    second_price: bool,
+) -> AuctionResult<Response> {
+    send_test_nft(
+        deps,
+        env,
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        &CreateAuction {
+            duration: default_duration(),
+            start: None,
+            minimum_bid_amount: Uint128::new(5),
+            buyout_price: buyout_price.map(Uint128::from),
+            price_asset: PriceAssetUnchecked::native(UANDR),
+            bid_increment: None,
+            kind,
+            second_price: Some(second_price),
+            reserve_price: Some(reserve_price.into()),
+            relist: None,
+        },
+    )
+}
+
+#[test]
+fn bid_below_reserve_price_is_refunded() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let response = create_reserve_price_auction(deps.as_mut(), env.clone(), 20, None, None, false)?;
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "create_auction"),
+            attr("auction_id", "0"),
+            attr("has_reserve_price", "true"),
+        ]
+    );
+
+    // bids below the reserve are still accepted
+    test_bid(deps.as_mut(), env.clone(), USER2, 0, 10, &coins(10, UANDR))?;
+
+    assert_eq!(
+        query_reserve_price(deps.as_ref(), 0)?,
+        ReservePriceResponse {
+            has_reserve_price: true,
+            reserve_met: false,
+        }
+    );
+
+    let response = test_resolve_auction(deps.as_mut(), after_seconds(&env, 601), USER1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_native_funds(UANDR, 10, USER2)?,
+            transfer_nft_msg(NFT_ADDR, USER1, TOKEN1)?,
+        ]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "resolve_auction"),
+            attr("auction_id", "0"),
+            attr("no_sale_reason", "reserve_price_not_met"),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn bid_reaching_reserve_price_wins() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_reserve_price_auction(deps.as_mut(), env.clone(), 20, None, None, false)?;
+
+    test_bid(deps.as_mut(), env.clone(), USER2, 0, 10, &coins(10, UANDR))?;
+    test_bid(deps.as_mut(), env.clone(), USER3, 0, 20, &coins(20, UANDR))?;
+
+    assert!(query_reserve_price(deps.as_ref(), 0)?.reserve_met);
+
+    let response = test_resolve_auction(deps.as_mut(), after_seconds(&env, 601), USER1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER3, TOKEN1)?,
+            transfer_native_funds(UANDR, 20, USER1)?,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn second_price_sale_never_goes_below_reserve_price() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_reserve_price_auction(deps.as_mut(), env.clone(), 20, None, None, true)?;
+
+    test_bid(deps.as_mut(), env.clone(), USER2, 0, 10, &coins(10, UANDR))?;
+    test_bid(deps.as_mut(), env.clone(), USER3, 0, 25, &coins(25, UANDR))?;
+
+    let response = test_resolve_auction(deps.as_mut(), after_seconds(&env, 601), USER1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER3, TOKEN1)?,
+            transfer_native_funds(UANDR, 20, USER1)?,
+            transfer_native_funds(UANDR, 5, USER3)?,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn sealed_bid_below_reserve_price_is_refunded() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_reserve_price_auction(
+        deps.as_mut(),
+        env.clone(),
+        20,
+        None,
+        Some(AuctionKind::SealedBid(SealedBidAuction {
+            reveal_duration: 300,
+        })),
+        false,
+    )?;
+
+    commit_bid(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(USER2, &coins(12, UANDR)),
+        0,
+        sealed_bid_commitment(10u8.into(), "salt", &Addr::unchecked(USER2)),
+    )?;
+    reveal_bid(
+        deps.as_mut(),
+        after_seconds(&env, 601),
+        mock_info(USER2, &[]),
+        0,
+        10u8.into(),
+        "salt".to_string(),
+    )?;
+
+    let response = test_resolve_auction(deps.as_mut(), after_seconds(&env, 901), USER1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_native_funds(UANDR, 12, USER2)?,
+            transfer_nft_msg(NFT_ADDR, USER1, TOKEN1)?,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn auction_without_reserve_price_always_meets_it() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_test_auction(
+        deps.as_mut(),
+        env,
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        None,
+    )?;
+
+    assert_eq!(
+        query_reserve_price(deps.as_ref(), 0)?,
+        ReservePriceResponse {
+            has_reserve_price: false,
+            reserve_met: true,
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn create_auction_with_invalid_reserve_price_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    // not above the minimum bid
+    let result = create_reserve_price_auction(deps.as_mut(), env.clone(), 5, None, None, false);
+    assert_eq!(result, Err(InvalidReservePrice));
+
+    // above the buyout price
+    let result =
+        create_reserve_price_auction(deps.as_mut(), env.clone(), 20, Some(15), None, false);
+    assert_eq!(result, Err(InvalidReservePrice));
+
+    let result = create_reserve_price_auction(
+        deps.as_mut(),
+        env,
+        20,
+        None,
+        Some(AuctionKind::Dutch(DutchAuction {
+            start_price: Uint128::new(50),
+            decay: PriceDecay::Linear,
+        })),
+        false,
+    );
+    assert_eq!(result, Err(InvalidReservePrice));
+
+    Ok(())
+}
@@ -23,7 +23,7 @@ fn resolve_auction_that_does_not_exist_fails() -> anyhow::Result<()> {
 
     instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
 
-    let result = resolve_auction(deps.as_mut(), env.clone(), mock_info(USER1, &vec![]), 0);
+    let result = resolve_auction(deps.as_mut(), env.clone(), mock_info(USER1, &[]), 0);
 
     assert_eq!(result, Err(AuctionIdNotFound));
 
@@ -174,11 +174,11 @@ fn resolve_auction_with_active_bid_sends_nft_and_bid_to_new_owners() -> anyhow::
     let auction = query_auction(deps.as_ref(), 0)?.auction;
     assert_eq!(auction.status, Resolved);
 
-    assert!(query_auctions(deps.as_ref(), true, None, None)?
+    assert!(query_auctions(deps.as_ref(), true, None, None, None)?
         .auctions
         .is_empty());
     assert_eq!(
-        query_auctions(deps.as_ref(), false, None, None)?.auctions[0].status,
+        query_auctions(deps.as_ref(), false, None, None, None)?.auctions[0].status,
         Resolved
     );
 
@@ -0,0 +1,182 @@
+use crate::query::query_auction;
+use crate::tests::helpers::{
+    after_height, after_seconds, instantiate_with_native_price_asset, send_test_nft, test_bid,
+    test_cancel_auction, test_fund_creation_deposit, test_resolve_auction,
+    test_update_creation_deposit, transfer_nft_msg, ADMIN, NFT_ADDR, TOKEN1, UANDR, UATOM, USER1,
+    USER2,
+};
+use cosmwasm_std::testing::{mock_dependencies, mock_env};
+use cosmwasm_std::{attr, coin, coins, BankMsg, DepsMut, Env, Response, SubMsg, Uint128};
+use cw_utils::Duration::{Height, Time};
+use cw_utils::{Duration, Expiration, Scheduled};
+use tracks_auction_api::api::{CreationDeposit, PriceAssetUnchecked};
+use tracks_auction_api::error::AuctionError::{
+    AuctionNotStarted, AuctionStillInProgress, InvalidAuctionStart,
+};
+use tracks_auction_api::error::AuctionResult;
+use tracks_auction_api::msg::Cw721HookMsg::CreateAuction;
+
+fn create_scheduled_auction(
+    deps: DepsMut,
+    env: Env,
+    duration: Duration,
+    start: Option<Scheduled>,
+) -> AuctionResult<Response> {
+    send_test_nft(
+        deps,
+        env,
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        &CreateAuction {
+            duration,
+            start,
+            minimum_bid_amount: Uint128::new(5),
+            buyout_price: None,
+            price_asset: PriceAssetUnchecked::native(UANDR),
+            bid_increment: None,
+            kind: None,
+            second_price: None,
+            reserve_price: None,
+            relist: None,
+        },
+    )
+}
+
+#[test]
+fn bid_before_scheduled_start_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let starts_at = Scheduled::AtTime(env.block.time.plus_seconds(3600));
+    let response =
+        create_scheduled_auction(deps.as_mut(), env.clone(), Time(600), Some(starts_at))?;
+
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "create_auction"),
+            attr("auction_id", "0"),
+            attr("starts_at", starts_at.to_string()),
+        ]
+    );
+
+    let result = test_bid(
+        deps.as_mut(),
+        after_seconds(&env, 3599),
+        USER2,
+        0,
+        5,
+        &coins(5, UANDR),
+    );
+    assert_eq!(result, Err(AuctionNotStarted { starts_at }));
+
+    test_bid(
+        deps.as_mut(),
+        after_seconds(&env, 3600),
+        USER2,
+        0,
+        5,
+        &coins(5, UANDR),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn duration_is_counted_from_scheduled_start() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let starts_at = Scheduled::AtHeight(env.block.height + 100);
+    create_scheduled_auction(deps.as_mut(), env.clone(), Height(50), Some(starts_at))?;
+
+    let auction = query_auction(deps.as_ref(), 0)?.auction;
+    assert_eq!(auction.starts_at, starts_at);
+    assert_eq!(
+        auction.ends_at,
+        Expiration::AtHeight(env.block.height + 150)
+    );
+
+    let result = test_resolve_auction(deps.as_mut(), after_height(&env, 150), USER1, 0);
+    assert_eq!(result, Err(AuctionStillInProgress));
+
+    test_resolve_auction(deps.as_mut(), after_height(&env, 151), USER1, 0)?;
+
+    Ok(())
+}
+
+#[test]
+fn create_auction_with_invalid_start_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    // start in the past
+    let result = create_scheduled_auction(
+        deps.as_mut(),
+        env.clone(),
+        Height(50),
+        Some(Scheduled::AtHeight(env.block.height - 1)),
+    );
+    assert_eq!(result, Err(InvalidAuctionStart));
+
+    // start of a different kind than the duration
+    let result = create_scheduled_auction(
+        deps.as_mut(),
+        env.clone(),
+        Height(50),
+        Some(Scheduled::AtTime(env.block.time.plus_seconds(60))),
+    );
+    assert_eq!(result, Err(InvalidAuctionStart));
+
+    Ok(())
+}
+
+#[test]
+fn cancel_before_start_returns_forfeitable_deposit() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_update_creation_deposit(
+        deps.as_mut(),
+        ADMIN,
+        Some(CreationDeposit {
+            amount: coin(10, UATOM),
+            forfeit_unsold: true,
+        }),
+    )?;
+    test_fund_creation_deposit(deps.as_mut(), USER1, &coins(10, UATOM))?;
+
+    let starts_at = Scheduled::AtHeight(env.block.height + 100);
+    create_scheduled_auction(deps.as_mut(), env.clone(), Height(50), Some(starts_at))?;
+
+    let response = test_cancel_auction(deps.as_mut(), after_height(&env, 99), USER1, 0)?;
+
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER1, TOKEN1)?,
+            SubMsg::new(BankMsg::Send {
+                to_address: USER1.to_string(),
+                amount: coins(10, UATOM),
+            }),
+        ]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "cancel_auction"),
+            attr("auction_id", "0"),
+            attr("creation_deposit_returned", "10uatom"),
+        ]
+    );
+
+    Ok(())
+}
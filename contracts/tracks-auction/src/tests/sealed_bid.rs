@@ -0,0 +1,581 @@
+use crate::admin::{update_config, ConfigUpdate};
+use crate::execute::{commit_bid, receive_cw20, reveal_bid};
+use crate::query::{query_sealed_bid, query_sealed_bid_commitment, query_unaccounted_balances};
+use crate::sealed_bids::sealed_bid_commitment;
+use crate::sealed_bids::MAX_SEALED_BIDS_PER_AUCTION;
+use crate::tests::helpers::{
+    after_seconds, instantiate_with_cw20_price_asset, instantiate_with_native_price_asset,
+    send_test_nft, test_bid, test_cancel_auction, test_resolve_auction, transfer_cw20_funds,
+    transfer_native_funds, transfer_nft_msg, ADMIN, CW20_ADDR, NFT_ADDR, TOKEN1, UANDR, USER1,
+    USER2, USER3,
+};
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{
+    attr, coin, coins, to_json_binary, Addr, Coin, DepsMut, Env, HexBinary, Response, Uint128,
+};
+use cw20::Cw20ReceiveMsg;
+use cw_utils::Duration::Time;
+use tracks_auction_api::api::{
+    AuctionKind, PriceAssetUnchecked, SealedBidAuction, UnrevealedBidPenalty,
+};
+use tracks_auction_api::error::AuctionError::{
+    AuctionStillInProgress, BidLowerThanMinimum, BidWrongAsset, CommitmentMismatch,
+    InvalidSealedBidAuction, InvalidUnrevealedBidPenalty, NotInRevealPhase,
+    RevealedBidExceedsCollateral, SealedBidAlreadyCommitted, SealedBidAlreadyRevealed,
+    SealedBidsMustBeCommitted, TooManySealedBids,
+};
+use tracks_auction_api::error::AuctionResult;
+use tracks_auction_api::msg::Cw20HookMsg;
+use tracks_auction_api::msg::Cw721HookMsg::CreateAuction;
+
+const SALT: &str = "salt";
+
+fn create_sealed_bid_auction(
+    deps: DepsMut,
+    env: Env,
+    price_asset: PriceAssetUnchecked,
+    buyout_price: Option<u8>,
+    reveal_duration: u64,
+) -> AuctionResult<Response> {
+    send_test_nft(
+        deps,
+        env,
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        &CreateAuction {
+            duration: Time(600),
+            start: None,
+            minimum_bid_amount: Uint128::new(5),
+            buyout_price: buyout_price.map(Uint128::from),
+            price_asset,
+            bid_increment: None,
+            kind: Some(AuctionKind::SealedBid(SealedBidAuction { reveal_duration })),
+            second_price: None,
+            reserve_price: None,
+            relist: None,
+        },
+    )
+}
+
+fn commitment(amount: u8, bidder: &str) -> HexBinary {
+    sealed_bid_commitment(amount.into(), SALT, &Addr::unchecked(bidder))
+}
+
+fn test_commit_bid(
+    deps: DepsMut,
+    env: Env,
+    bidder: &str,
+    amount: u8,
+    collateral: &[Coin],
+) -> AuctionResult<Response> {
+    commit_bid(
+        deps,
+        env,
+        mock_info(bidder, collateral),
+        0,
+        commitment(amount, bidder),
+    )
+}
+
+fn test_reveal_bid(deps: DepsMut, env: Env, bidder: &str, amount: u8) -> AuctionResult<Response> {
+    reveal_bid(
+        deps,
+        env,
+        mock_info(bidder, &[]),
+        0,
+        amount.into(),
+        SALT.to_string(),
+    )
+}
+
+fn test_update_unrevealed_bid_penalty(
+    deps: DepsMut,
+    penalty: UnrevealedBidPenalty,
+) -> AuctionResult<Response> {
+    update_config(
+        deps,
+        mock_info(ADMIN, &[]),
+        ConfigUpdate {
+            unrevealed_bid_penalty: Some(penalty),
+            ..ConfigUpdate::default()
+        },
+    )
+}
+
+#[test]
+fn highest_revealed_bid_wins_sealed_bid_auction() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let response = create_sealed_bid_auction(
+        deps.as_mut(),
+        env.clone(),
+        PriceAssetUnchecked::native(UANDR),
+        None,
+        300,
+    )?;
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "create_auction"),
+            attr("auction_id", "0"),
+            attr("auction_kind", "sealed_bid"),
+            attr("reveal_duration", "300"),
+        ]
+    );
+
+    let result = test_bid(deps.as_mut(), env.clone(), USER2, 0, 10, &coins(10, UANDR));
+    assert_eq!(result, Err(SealedBidsMustBeCommitted));
+
+    // the collateral hides the actual bid amount
+    let response = test_commit_bid(deps.as_mut(), env.clone(), USER2, 10, &coins(15, UANDR))?;
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "commit_bid"),
+            attr("auction_id", "0"),
+            attr("collateral", "15"),
+        ]
+    );
+    test_commit_bid(deps.as_mut(), env.clone(), USER3, 8, &coins(8, UANDR))?;
+
+    let result = test_reveal_bid(deps.as_mut(), after_seconds(&env, 600), USER2, 10);
+    assert_eq!(result, Err(NotInRevealPhase));
+
+    let reveal_env = after_seconds(&env, 601);
+
+    let response = test_reveal_bid(deps.as_mut(), reveal_env.clone(), USER2, 10)?;
+    assert_eq!(response.messages, vec![]);
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "reveal_bid"),
+            attr("auction_id", "0"),
+            attr("bid_amount", "10"),
+            attr("highest_bid", "true"),
+        ]
+    );
+
+    // lower bids get their collateral back as soon as they are revealed
+    let response = test_reveal_bid(deps.as_mut(), reveal_env.clone(), USER3, 8)?;
+    assert_eq!(
+        response.messages,
+        vec![transfer_native_funds(UANDR, 8, USER3)?]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "reveal_bid"),
+            attr("auction_id", "0"),
+            attr("bid_amount", "8"),
+            attr("highest_bid", "false"),
+        ]
+    );
+
+    let result = test_reveal_bid(deps.as_mut(), reveal_env, USER2, 10);
+    assert_eq!(result, Err(SealedBidAlreadyRevealed));
+
+    let result = test_resolve_auction(deps.as_mut(), after_seconds(&env, 900), USER1, 0);
+    assert_eq!(result, Err(AuctionStillInProgress));
+
+    let response = test_resolve_auction(deps.as_mut(), after_seconds(&env, 901), USER1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_native_funds(UANDR, 5, USER2)?,
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+            transfer_native_funds(UANDR, 10, USER1)?,
+        ]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "resolve_auction"),
+            attr("auction_id", "0"),
+            attr("unrevealed_bids", "0"),
+            attr("forfeited_collateral", "0"),
+            attr("winner_refund", "5"),
+            attr("fee_bps", "0"),
+            attr("fee_amount", "0"),
+            attr("creator_amount", "10"),
+        ]
+    );
+
+    assert_eq!(
+        query_sealed_bid(deps.as_ref(), 0, USER2.to_string())?.sealed_bid,
+        None
+    );
+
+    Ok(())
+}
+
+#[test]
+fn revealing_higher_bid_refunds_previous_highest_bid() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_sealed_bid_auction(
+        deps.as_mut(),
+        env.clone(),
+        PriceAssetUnchecked::native(UANDR),
+        None,
+        300,
+    )?;
+
+    test_commit_bid(deps.as_mut(), env.clone(), USER2, 10, &coins(10, UANDR))?;
+    test_commit_bid(deps.as_mut(), env.clone(), USER3, 6, &coins(7, UANDR))?;
+
+    let reveal_env = after_seconds(&env, 601);
+    test_reveal_bid(deps.as_mut(), reveal_env.clone(), USER3, 6)?;
+
+    let response = test_reveal_bid(deps.as_mut(), reveal_env, USER2, 10)?;
+    assert_eq!(
+        response.messages,
+        vec![transfer_native_funds(UANDR, 7, USER3)?]
+    );
+
+    let response = test_resolve_auction(deps.as_mut(), after_seconds(&env, 901), USER1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+            transfer_native_funds(UANDR, 10, USER1)?,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn unrevealed_bid_collateral_is_penalized() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let response = test_update_unrevealed_bid_penalty(
+        deps.as_mut(),
+        UnrevealedBidPenalty::Forfeit { bps: 5000 },
+    )?;
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "update_config"),
+            attr("unrevealed_bid_penalty", "forfeit"),
+            attr("unrevealed_bid_penalty_bps", "5000"),
+        ]
+    );
+
+    create_sealed_bid_auction(
+        deps.as_mut(),
+        env.clone(),
+        PriceAssetUnchecked::native(UANDR),
+        None,
+        300,
+    )?;
+
+    test_commit_bid(deps.as_mut(), env.clone(), USER2, 10, &coins(10, UANDR))?;
+    test_commit_bid(deps.as_mut(), env.clone(), USER3, 6, &coins(6, UANDR))?;
+
+    test_reveal_bid(deps.as_mut(), after_seconds(&env, 601), USER3, 6)?;
+
+    let response = test_resolve_auction(deps.as_mut(), after_seconds(&env, 901), USER1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_native_funds(UANDR, 5, USER2)?,
+            transfer_native_funds(UANDR, 5, ADMIN)?,
+            transfer_nft_msg(NFT_ADDR, USER3, TOKEN1)?,
+            transfer_native_funds(UANDR, 6, USER1)?,
+        ]
+    );
+    assert_eq!(
+        response.attributes[2..4],
+        vec![
+            attr("unrevealed_bids", "1"),
+            attr("forfeited_collateral", "5"),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn penalty_is_fixed_at_auction_creation() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_sealed_bid_auction(
+        deps.as_mut(),
+        env.clone(),
+        PriceAssetUnchecked::native(UANDR),
+        None,
+        300,
+    )?;
+
+    test_update_unrevealed_bid_penalty(
+        deps.as_mut(),
+        UnrevealedBidPenalty::Forfeit { bps: 10_000 },
+    )?;
+
+    test_commit_bid(deps.as_mut(), env.clone(), USER2, 10, &coins(10, UANDR))?;
+
+    // the unrevealed bid is refunded in full, and the NFT goes back to the creator
+    let response = test_resolve_auction(deps.as_mut(), after_seconds(&env, 901), USER1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_native_funds(UANDR, 10, USER2)?,
+            transfer_nft_msg(NFT_ADDR, USER1, TOKEN1)?,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cancel_sealed_bid_auction_refunds_collateral() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_sealed_bid_auction(
+        deps.as_mut(),
+        env.clone(),
+        PriceAssetUnchecked::native(UANDR),
+        None,
+        300,
+    )?;
+
+    test_commit_bid(deps.as_mut(), env.clone(), USER2, 10, &coins(12, UANDR))?;
+    test_commit_bid(deps.as_mut(), env.clone(), USER3, 6, &coins(6, UANDR))?;
+
+    let response = test_cancel_auction(deps.as_mut(), env, USER1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER1, TOKEN1)?,
+            transfer_native_funds(UANDR, 12, USER2)?,
+            transfer_native_funds(UANDR, 6, USER3)?,
+        ]
+    );
+    // bids are only penalized once the reveal phase is over, so nothing is forfeited
+    assert_eq!(
+        response.attributes,
+        vec![attr("action", "cancel_auction"), attr("auction_id", "0")]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn commit_cw20_sealed_bid() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_cw20_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, CW20_ADDR)?;
+    create_sealed_bid_auction(
+        deps.as_mut(),
+        env.clone(),
+        PriceAssetUnchecked::cw20(CW20_ADDR),
+        None,
+        300,
+    )?;
+
+    receive_cw20(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(CW20_ADDR, &[]),
+        Cw20ReceiveMsg {
+            sender: USER2.to_string(),
+            amount: Uint128::new(9),
+            msg: to_json_binary(&Cw20HookMsg::CommitBid {
+                auction_id: 0,
+                commitment: commitment(7, USER2),
+            })?,
+        },
+    )?;
+
+    let sealed_bid = query_sealed_bid(deps.as_ref(), 0, USER2.to_string())?
+        .sealed_bid
+        .unwrap();
+    assert_eq!(sealed_bid.collateral, Uint128::new(9));
+    assert_eq!(
+        sealed_bid.commitment,
+        query_sealed_bid_commitment(
+            deps.as_ref(),
+            7u8.into(),
+            SALT.to_string(),
+            USER2.to_string()
+        )?
+        .commitment
+    );
+
+    test_reveal_bid(deps.as_mut(), after_seconds(&env, 601), USER2, 7)?;
+
+    let response = test_resolve_auction(deps.as_mut(), after_seconds(&env, 901), USER1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_cw20_funds(CW20_ADDR, 2, USER2)?,
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+            transfer_cw20_funds(CW20_ADDR, 7, USER1)?,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn sealed_bid_collateral_is_accounted() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_sealed_bid_auction(
+        deps.as_mut(),
+        env.clone(),
+        PriceAssetUnchecked::native(UANDR),
+        None,
+        300,
+    )?;
+
+    test_commit_bid(deps.as_mut(), env.clone(), USER2, 10, &coins(12, UANDR))?;
+    test_reveal_bid(deps.as_mut(), after_seconds(&env, 601), USER2, 10)?;
+
+    deps.querier
+        .update_balance(env.contract.address.clone(), coins(12, UANDR));
+
+    let balances = query_unaccounted_balances(deps.as_ref(), env)?.balances;
+    assert_eq!(balances[0].accounted, Uint128::new(12));
+    assert_eq!(balances[0].unaccounted, Uint128::zero());
+
+    Ok(())
+}
+
+#[test]
+fn invalid_sealed_bids_fail() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_sealed_bid_auction(
+        deps.as_mut(),
+        env.clone(),
+        PriceAssetUnchecked::native(UANDR),
+        None,
+        300,
+    )?;
+
+    let result = test_commit_bid(deps.as_mut(), env.clone(), USER2, 4, &coins(4, UANDR));
+    assert_eq!(
+        result,
+        Err(BidLowerThanMinimum {
+            minimum: Uint128::new(5)
+        })
+    );
+
+    let result = test_commit_bid(deps.as_mut(), env.clone(), USER2, 5, &[coin(5, "uother")]);
+    assert_eq!(result, Err(BidWrongAsset));
+
+    test_commit_bid(deps.as_mut(), env.clone(), USER2, 8, &coins(8, UANDR))?;
+    let result = test_commit_bid(deps.as_mut(), env.clone(), USER2, 9, &coins(9, UANDR));
+    assert_eq!(result, Err(SealedBidAlreadyCommitted));
+
+    test_commit_bid(deps.as_mut(), env.clone(), USER3, 12, &coins(10, UANDR))?;
+
+    let reveal_env = after_seconds(&env, 601);
+
+    let result = test_reveal_bid(deps.as_mut(), reveal_env.clone(), USER2, 9);
+    assert_eq!(result, Err(CommitmentMismatch));
+
+    let result = test_reveal_bid(deps.as_mut(), reveal_env, USER3, 12);
+    assert_eq!(result, Err(RevealedBidExceedsCollateral));
+
+    let result = test_reveal_bid(deps.as_mut(), after_seconds(&env, 901), USER2, 8);
+    assert_eq!(result, Err(NotInRevealPhase));
+
+    Ok(())
+}
+
+#[test]
+fn create_invalid_sealed_bid_auction_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let result = create_sealed_bid_auction(
+        deps.as_mut(),
+        env.clone(),
+        PriceAssetUnchecked::native(UANDR),
+        None,
+        0,
+    );
+    assert_eq!(result, Err(InvalidSealedBidAuction));
+
+    let result = create_sealed_bid_auction(
+        deps.as_mut(),
+        env.clone(),
+        PriceAssetUnchecked::native(UANDR),
+        Some(50),
+        300,
+    );
+    assert_eq!(result, Err(InvalidSealedBidAuction));
+
+    let result = test_update_unrevealed_bid_penalty(
+        deps.as_mut(),
+        UnrevealedBidPenalty::Forfeit { bps: 10_001 },
+    );
+    assert_eq!(result, Err(InvalidUnrevealedBidPenalty));
+
+    Ok(())
+}
+
+#[test]
+fn sealed_bids_per_auction_are_capped() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_sealed_bid_auction(
+        deps.as_mut(),
+        env.clone(),
+        PriceAssetUnchecked::native(UANDR),
+        None,
+        300,
+    )?;
+
+    let bidders: Vec<String> = (0..MAX_SEALED_BIDS_PER_AUCTION)
+        .map(|i| format!("bidder{i}"))
+        .collect();
+    for bidder in &bidders {
+        test_commit_bid(deps.as_mut(), env.clone(), bidder, 5, &coins(5, UANDR))?;
+    }
+
+    let result = test_commit_bid(deps.as_mut(), env.clone(), USER2, 5, &coins(5, UANDR));
+    assert_eq!(
+        result,
+        Err(TooManySealedBids {
+            max: MAX_SEALED_BIDS_PER_AUCTION
+        })
+    );
+
+    // every held bid is settled when the auction is resolved
+    let response = test_resolve_auction(deps.as_mut(), after_seconds(&env, 901), USER1, 0)?;
+    assert_eq!(
+        response.messages.len(),
+        MAX_SEALED_BIDS_PER_AUCTION as usize + 1
+    );
+    assert!(response.attributes.contains(&attr(
+        "unrevealed_bids",
+        MAX_SEALED_BIDS_PER_AUCTION.to_string()
+    )));
+
+    Ok(())
+}
@@ -0,0 +1,231 @@
+use crate::execute::{commit_bid, reveal_bid};
+use crate::query::query_auction;
+use crate::sealed_bids::sealed_bid_commitment;
+use crate::tests::helpers::{
+    after_seconds, instantiate_with_cw20_price_asset, instantiate_with_native_price_asset,
+    send_test_nft, test_bid, test_cw20_bid, test_resolve_auction, transfer_cw20_funds,
+    transfer_native_funds, transfer_nft_msg, ADMIN, CW20_ADDR, NFT_ADDR, TOKEN1, UANDR, USER1,
+    USER2, USER3,
+};
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{attr, coins, Addr, DepsMut, Env, Response, Uint128};
+use cw_utils::Duration::Time;
+use tracks_auction_api::api::{
+    AuctionKind, DutchAuction, PriceAssetUnchecked, PriceDecay, SealedBidAuction,
+};
+use tracks_auction_api::error::AuctionError::SecondPriceNotSupported;
+use tracks_auction_api::error::AuctionResult;
+use tracks_auction_api::msg::Cw721HookMsg::CreateAuction;
+
+fn create_second_price_auction(
+    deps: DepsMut,
+    env: Env,
+    price_asset: PriceAssetUnchecked,
+    kind: Option<AuctionKind>,
+) -> AuctionResult<Response> {
+    send_test_nft(
+        deps,
+        env,
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        &CreateAuction {
+            duration: Time(600),
+            start: None,
+            minimum_bid_amount: Uint128::new(5),
+            buyout_price: None,
+            price_asset,
+            bid_increment: None,
+            kind,
+            second_price: Some(true),
+            reserve_price: None,
+            relist: None,
+        },
+    )
+}
+
+#[test]
+fn winner_pays_second_highest_bid() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let response = create_second_price_auction(
+        deps.as_mut(),
+        env.clone(),
+        PriceAssetUnchecked::native(UANDR),
+        None,
+    )?;
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "create_auction"),
+            attr("auction_id", "0"),
+            attr("second_price", "true"),
+        ]
+    );
+
+    test_bid(deps.as_mut(), env.clone(), USER2, 0, 10, &coins(10, UANDR))?;
+    test_bid(deps.as_mut(), env.clone(), USER3, 0, 15, &coins(15, UANDR))?;
+
+    let auction = query_auction(deps.as_ref(), 0)?.auction;
+    assert_eq!(auction.runner_up_amount, Some(Uint128::new(10)));
+    assert_eq!(auction.sale_price(), Some(Uint128::new(10)));
+
+    let response = test_resolve_auction(deps.as_mut(), after_seconds(&env, 601), USER1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER3, TOKEN1)?,
+            transfer_native_funds(UANDR, 10, USER1)?,
+            transfer_native_funds(UANDR, 5, USER3)?,
+        ]
+    );
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "resolve_auction"),
+            attr("auction_id", "0"),
+            attr("sale_price", "10"),
+            attr("winner_refund", "5"),
+            attr("fee_bps", "0"),
+            attr("fee_amount", "0"),
+            attr("creator_amount", "10"),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn single_bid_pays_minimum_bid() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_second_price_auction(
+        deps.as_mut(),
+        env.clone(),
+        PriceAssetUnchecked::native(UANDR),
+        None,
+    )?;
+
+    test_bid(deps.as_mut(), env.clone(), USER2, 0, 12, &coins(12, UANDR))?;
+
+    let response = test_resolve_auction(deps.as_mut(), after_seconds(&env, 601), USER1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+            transfer_native_funds(UANDR, 5, USER1)?,
+            transfer_native_funds(UANDR, 7, USER2)?,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cw20_winner_pays_second_highest_bid() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_cw20_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, CW20_ADDR)?;
+    create_second_price_auction(
+        deps.as_mut(),
+        env.clone(),
+        PriceAssetUnchecked::cw20(CW20_ADDR),
+        None,
+    )?;
+
+    test_cw20_bid(deps.as_mut(), env.clone(), USER2, 0, 10, 10, CW20_ADDR)?;
+    test_cw20_bid(deps.as_mut(), env.clone(), USER3, 0, 15, 15, CW20_ADDR)?;
+
+    let response = test_resolve_auction(deps.as_mut(), after_seconds(&env, 601), USER1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_nft_msg(NFT_ADDR, USER3, TOKEN1)?,
+            transfer_cw20_funds(CW20_ADDR, 10, USER1)?,
+            transfer_cw20_funds(CW20_ADDR, 5, USER3)?,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn sealed_bid_winner_pays_second_highest_revealed_bid() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_second_price_auction(
+        deps.as_mut(),
+        env.clone(),
+        PriceAssetUnchecked::native(UANDR),
+        Some(AuctionKind::SealedBid(SealedBidAuction {
+            reveal_duration: 300,
+        })),
+    )?;
+
+    for (bidder, amount, collateral) in [(USER2, 10u8, 12), (USER3, 8, 8)] {
+        let commitment = sealed_bid_commitment(amount.into(), "salt", &Addr::unchecked(bidder));
+        commit_bid(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(bidder, &coins(collateral, UANDR)),
+            0,
+            commitment,
+        )?;
+    }
+
+    // the lower bid is revealed last, and still sets the price
+    for (bidder, amount) in [(USER2, 10u8), (USER3, 8)] {
+        reveal_bid(
+            deps.as_mut(),
+            after_seconds(&env, 601),
+            mock_info(bidder, &[]),
+            0,
+            amount.into(),
+            "salt".to_string(),
+        )?;
+    }
+
+    let response = test_resolve_auction(deps.as_mut(), after_seconds(&env, 901), USER1, 0)?;
+    assert_eq!(
+        response.messages,
+        vec![
+            transfer_native_funds(UANDR, 4, USER2)?,
+            transfer_nft_msg(NFT_ADDR, USER2, TOKEN1)?,
+            transfer_native_funds(UANDR, 8, USER1)?,
+        ]
+    );
+    // the winner gets back their collateral above the price, not only their bid above it
+    assert!(response.attributes.contains(&attr("sale_price", "8")));
+    assert!(response.attributes.contains(&attr("winner_refund", "4")));
+
+    Ok(())
+}
+
+#[test]
+fn second_price_dutch_auction_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+
+    let result = create_second_price_auction(
+        deps.as_mut(),
+        env,
+        PriceAssetUnchecked::native(UANDR),
+        Some(AuctionKind::Dutch(DutchAuction {
+            start_price: Uint128::new(50),
+            decay: PriceDecay::Linear,
+        })),
+    );
+    assert_eq!(result, Err(SecondPriceNotSupported));
+
+    Ok(())
+}
@@ -0,0 +1,319 @@
+use crate::execute::{commit_bid, update_auction};
+use crate::query::query_auction;
+use crate::sealed_bids::sealed_bid_commitment;
+use crate::tests::helpers::{
+    after_seconds, create_test_auction, default_duration, instantiate_with_native_price_asset,
+    send_test_nft, test_add_collection, test_bid, test_remove_collection, ADMIN, NFT_ADDR, TOKEN1,
+    UANDR, USER1, USER2,
+};
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{attr, coins, Addr, DepsMut, Env, Response, Uint128};
+use cw_utils::Duration::{Height, Time};
+use cw_utils::{Duration, Expiration};
+use tracks_auction_api::api::{
+    AuctionKind, BuyoutPriceUpdate, CollectionSettings, PriceAssetUnchecked, SealedBidAuction,
+};
+use tracks_auction_api::error::AuctionError::{
+    AuctionExpired, AuctionUpdateNotAllowed, InvalidDurationExtension,
+    MinimumBidBelowCollectionFloor, Unauthorized,
+};
+use tracks_auction_api::error::AuctionResult;
+use tracks_auction_api::msg::Cw721HookMsg::CreateAuction;
+
+fn test_update_auction(
+    deps: DepsMut,
+    env: Env,
+    sender: &str,
+    minimum_bid_amount: Option<u128>,
+    buyout_price: Option<BuyoutPriceUpdate>,
+    duration: Option<Duration>,
+) -> AuctionResult<Response> {
+    update_auction(
+        deps,
+        env,
+        mock_info(sender, &[]),
+        0,
+        minimum_bid_amount.map(Uint128::new),
+        buyout_price,
+        duration,
+    )
+}
+
+fn set_buyout(amount: u128) -> Option<BuyoutPriceUpdate> {
+    Some(BuyoutPriceUpdate::Set {
+        amount: Uint128::new(amount),
+    })
+}
+
+#[test]
+fn update_auction_before_first_bid() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        Some(50),
+    )?;
+
+    let response = test_update_auction(
+        deps.as_mut(),
+        env.clone(),
+        USER1,
+        Some(10),
+        set_buyout(40),
+        Some(Time(900)),
+    )?;
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "update_auction"),
+            attr("auction_id", "0"),
+            attr("minimum_bid_amount", "10"),
+            attr("buyout_price", "40"),
+            attr("duration", "time: 900"),
+            attr(
+                "ends_at",
+                Expiration::AtTime(env.block.time.plus_seconds(900)).to_string()
+            ),
+        ]
+    );
+
+    let auction = query_auction(deps.as_ref(), 0)?.auction;
+    assert_eq!(auction.minimum_bid_amount, Uint128::new(10));
+    assert_eq!(auction.buyout_price, Some(Uint128::new(40)));
+    assert_eq!(auction.duration, Time(900));
+    assert_eq!(
+        auction.ends_at,
+        Expiration::AtTime(env.block.time.plus_seconds(900))
+    );
+
+    let response = test_update_auction(
+        deps.as_mut(),
+        env,
+        USER1,
+        None,
+        Some(BuyoutPriceUpdate::Remove),
+        None,
+    )?;
+    assert_eq!(
+        response.attributes,
+        vec![
+            attr("action", "update_auction"),
+            attr("auction_id", "0"),
+            attr("buyout_price", "none"),
+        ]
+    );
+    assert_eq!(query_auction(deps.as_ref(), 0)?.auction.buyout_price, None);
+
+    Ok(())
+}
+
+#[test]
+fn update_auction_of_removed_collection_ignores_its_floor() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    test_add_collection(
+        deps.as_mut(),
+        ADMIN,
+        NFT_ADDR,
+        Some(CollectionSettings {
+            label: None,
+            minimum_bid_floor: Some(Uint128::new(10)),
+        }),
+    )?;
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        10,
+        None,
+    )?;
+
+    let result = test_update_auction(deps.as_mut(), env.clone(), USER1, Some(5), None, None);
+    assert_eq!(
+        result,
+        Err(MinimumBidBelowCollectionFloor {
+            floor: Uint128::new(10)
+        })
+    );
+
+    test_remove_collection(deps.as_mut(), ADMIN, NFT_ADDR)?;
+
+    test_update_auction(deps.as_mut(), env, USER1, Some(5), None, None)?;
+    assert_eq!(
+        query_auction(deps.as_ref(), 0)?.auction.minimum_bid_amount,
+        Uint128::new(5)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn update_auction_with_bids_only_in_bidders_favor() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        Some(50),
+    )?;
+    test_bid(deps.as_mut(), env.clone(), USER2, 0, 10, &coins(10, UANDR))?;
+
+    for (minimum_bid_amount, buyout_price) in [
+        (Some(6), None),
+        (None, set_buyout(60)),
+        (None, Some(BuyoutPriceUpdate::Remove)),
+    ] {
+        let result = test_update_auction(
+            deps.as_mut(),
+            env.clone(),
+            USER1,
+            minimum_bid_amount,
+            buyout_price,
+            None,
+        );
+        assert_eq!(result, Err(AuctionUpdateNotAllowed));
+    }
+
+    let result = test_update_auction(
+        deps.as_mut(),
+        env.clone(),
+        USER1,
+        None,
+        None,
+        Some(Time(300)),
+    );
+    assert_eq!(result, Err(InvalidDurationExtension));
+
+    // extending the auction only gives bidders more time to compete
+    test_update_auction(
+        deps.as_mut(),
+        env,
+        USER1,
+        Some(3),
+        set_buyout(30),
+        Some(Time(900)),
+    )?;
+
+    let auction = query_auction(deps.as_ref(), 0)?.auction;
+    assert_eq!(auction.minimum_bid_amount, Uint128::new(3));
+    assert_eq!(auction.buyout_price, Some(Uint128::new(30)));
+    assert_eq!(auction.duration, Time(900));
+
+    Ok(())
+}
+
+#[test]
+fn update_auction_with_sealed_bids_only_in_bidders_favor() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    send_test_nft(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        &CreateAuction {
+            duration: default_duration(),
+            start: None,
+            minimum_bid_amount: Uint128::new(5),
+            buyout_price: None,
+            price_asset: PriceAssetUnchecked::native(UANDR),
+            bid_increment: None,
+            kind: Some(AuctionKind::SealedBid(SealedBidAuction {
+                reveal_duration: 300,
+            })),
+            second_price: None,
+            reserve_price: None,
+            relist: None,
+        },
+    )?;
+    commit_bid(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(USER2, &coins(10, UANDR)),
+        0,
+        sealed_bid_commitment(Uint128::new(10), "salt", &Addr::unchecked(USER2)),
+    )?;
+
+    let result = test_update_auction(deps.as_mut(), env.clone(), USER1, Some(6), None, None);
+    assert_eq!(result, Err(AuctionUpdateNotAllowed));
+
+    test_update_auction(deps.as_mut(), env, USER1, Some(4), None, None)?;
+
+    Ok(())
+}
+
+#[test]
+fn invalid_auction_update_fails() -> anyhow::Result<()> {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    instantiate_with_native_price_asset(deps.as_mut(), env.clone(), ADMIN, NFT_ADDR, UANDR)?;
+    create_test_auction(
+        deps.as_mut(),
+        env.clone(),
+        NFT_ADDR,
+        TOKEN1,
+        USER1,
+        default_duration(),
+        5,
+        None,
+    )?;
+
+    let result = test_update_auction(deps.as_mut(), env.clone(), USER2, Some(10), None, None);
+    assert_eq!(result, Err(Unauthorized));
+
+    let result = test_update_auction(
+        deps.as_mut(),
+        env.clone(),
+        USER1,
+        None,
+        None,
+        Some(Time(300)),
+    );
+    assert_eq!(result, Err(InvalidDurationExtension));
+
+    let result = test_update_auction(
+        deps.as_mut(),
+        env.clone(),
+        USER1,
+        None,
+        None,
+        Some(Height(900)),
+    );
+    assert_eq!(result, Err(InvalidDurationExtension));
+
+    let result = test_update_auction(
+        deps.as_mut(),
+        after_seconds(&env, 601),
+        USER1,
+        Some(10),
+        None,
+        None,
+    );
+    assert_eq!(result, Err(AuctionExpired));
+
+    Ok(())
+}
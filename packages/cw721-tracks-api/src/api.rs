@@ -1,12 +1,17 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Uint64;
+use cosmwasm_std::{Addr, Uint64};
 
 #[cw_serde]
 pub struct TrackMetadata {
     pub artist_name: String,
+    /// Address of the artist, only accepted when it's the address minting the token.
+    /// Unlike the artist name, it can be relied upon to tell who released the track.
+    pub artist_address: Option<Addr>,
     pub album: Option<AlbumMetadata>,
     pub track_name: String,
     pub audio_track_url: String,
+    /// Edition number of the token, for tracks released as a number of copies.
+    pub edition: Option<u32>,
 }
 
 #[cw_serde]
@@ -8,5 +8,8 @@ pub struct InstantiateMsg {
     pub symbol: String,
 }
 
+#[cw_serde]
+pub struct MigrateMsg {}
+
 pub type ExecuteMsg = cw721_base::ExecuteMsg<TrackMetadata, Empty>;
 pub type QueryMsg = cw721_base::QueryMsg<Empty>;
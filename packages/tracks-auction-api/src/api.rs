@@ -1,18 +1,197 @@
 use crate::api::PriceAsset::Cw20;
+use crate::error::AuctionResult;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Api, BlockInfo, StdResult, Uint128};
+use cosmwasm_std::{
+    Addr, Api, BlockInfo, Coin, Decimal, HexBinary, StdError, StdResult, Timestamp, Uint128,
+};
+use cw721_tracks_api::api::TrackMetadata;
 use cw_asset::AssetInfo;
-use cw_utils::Duration;
 use cw_utils::Duration::{Height, Time};
-use std::ops::Add;
+use cw_utils::{Duration, Expiration, Scheduled};
 use PriceAsset::Native;
 
 pub type AuctionId = u64;
 
+pub type ListingId = u64;
+
+pub type OfferId = u64;
+
+pub type CollectionOfferId = u64;
+
+pub type LotId = u64;
+
+pub type DropId = u64;
+
+pub type RaffleId = u64;
+
 #[cw_serde]
 pub struct Config {
-    pub whitelisted_nft: Addr,
-    pub price_asset: PriceAsset,
+    /// Address allowed to update the configuration and transfer the admin role.
+    pub admin: Addr,
+    /// Assets that auction creators can choose to price their auctions in.
+    pub price_assets: Vec<PriceAsset>,
+    /// Marketplace fee taken out of the sale proceeds, in basis points (1/10000).
+    pub fee_bps: u16,
+    /// Address receiving the marketplace fees.
+    pub fee_collector: Addr,
+    /// Deposit required to create an auction or a raffle, if any.
+    pub creation_deposit: Option<CreationDeposit>,
+    /// Bounds for the durations auctions can be created with.
+    pub duration_limits: DurationLimits,
+    /// Maximum number of active auctions and open raffles a single creator can have at once.
+    /// Unlimited if not set.
+    pub max_active_auctions_per_creator: Option<u32>,
+    /// Default rule for how much each bid has to exceed the previous one.
+    pub bid_increment: BidIncrement,
+    /// Rules for extending auctions that receive bids shortly before they end.
+    pub anti_sniping: AntiSniping,
+    /// What happens to the collateral of sealed bids that are never revealed.
+    pub unrevealed_bid_penalty: UnrevealedBidPenalty,
+    /// Trusted operator of the randomness raffle winners are drawn with.
+    /// Raffles cannot be created if not set.
+    pub randomness_beacon: Option<RandomnessBeacon>,
+}
+
+/// Trusted operator of the randomness raffle winners are drawn with, publishing a signature of
+/// each round number at a fixed period, drand-style.
+///
+/// Unlike a public beacon, the operator commits to each raffle's signature before its tickets go
+/// on sale, so it knows the randomness in advance and has to be trusted not to take part.
+#[cw_serde]
+pub struct RandomnessBeacon {
+    /// secp256k1 public key signing the rounds, in compressed (33 bytes) or uncompressed
+    /// (65 bytes) form.
+    pub public_key: HexBinary,
+    /// Time at which the first round is published.
+    pub genesis_time: Timestamp,
+    /// Seconds between two rounds.
+    pub period: u64,
+}
+
+impl RandomnessBeacon {
+    /// First round published strictly after the given time. Round 1 is published at genesis.
+    pub fn first_round_after(&self, time: Timestamp) -> u64 {
+        if time < self.genesis_time {
+            return 1;
+        }
+
+        (time.seconds() - self.genesis_time.seconds()) / self.period + 2
+    }
+}
+
+/// Penalty for committing a sealed bid and never revealing it.
+#[cw_serde]
+#[derive(Default)]
+pub enum UnrevealedBidPenalty {
+    /// The whole collateral is returned to the bidder.
+    #[default]
+    Refund,
+    /// This share of the collateral goes to the fee collector, in basis points (1/10000).
+    /// The rest is returned to the bidder.
+    Forfeit { bps: u16 },
+}
+
+/// Rule for how much a bid has to exceed the active bid of an auction.
+/// Does not apply to the first bid, which only has to reach the auction's minimum bid amount.
+#[cw_serde]
+pub enum BidIncrement {
+    /// Bids have to exceed the active bid by at least this amount.
+    Absolute { amount: Uint128 },
+    /// Bids have to exceed the active bid by at least this share of it, in basis points (1/10000).
+    /// The increment is rounded up, and is always at least 1.
+    Percentage { bps: u16 },
+}
+
+impl Default for BidIncrement {
+    fn default() -> Self {
+        BidIncrement::Absolute {
+            amount: Uint128::one(),
+        }
+    }
+}
+
+impl BidIncrement {
+    /// Calculate the minimum amount a bid has to exceed the given active bid by.
+    pub fn increment_over(&self, active_bid_amount: Uint128) -> Uint128 {
+        match self {
+            BidIncrement::Absolute { amount } => *amount,
+            BidIncrement::Percentage { bps } => active_bid_amount
+                .mul_ceil(Decimal::from_ratio(*bps, 10_000u16))
+                .max(Uint128::one()),
+        }
+    }
+}
+
+/// Bounds for auction durations. Each bound only applies to durations of its own kind,
+/// and no bound is applied if it's not set.
+#[cw_serde]
+#[derive(Default)]
+pub struct DurationLimits {
+    /// Minimum duration in seconds.
+    pub min_time: Option<u64>,
+    /// Maximum duration in seconds.
+    pub max_time: Option<u64>,
+    /// Minimum duration in blocks.
+    pub min_height: Option<u64>,
+    /// Maximum duration in blocks.
+    pub max_height: Option<u64>,
+}
+
+/// Extension of an auction's end when a bid is placed shortly before it.
+/// Both values are in the same unit as the auction's duration, seconds or blocks.
+#[cw_serde]
+pub struct ExtensionWindow {
+    /// Bids placed within this many seconds or blocks before the end extend the auction.
+    pub window: u64,
+    /// How far the end is pushed out by each such bid.
+    pub extension: u64,
+}
+
+/// Anti-sniping rules, one for each kind of auction duration.
+/// Auctions are never extended if the rule for their kind of duration is not set.
+#[cw_serde]
+#[derive(Default)]
+pub struct AntiSniping {
+    /// Rule for auctions with a duration in seconds.
+    pub time: Option<ExtensionWindow>,
+    /// Rule for auctions with a duration in blocks.
+    pub height: Option<ExtensionWindow>,
+}
+
+impl AntiSniping {
+    /// Get the rule that applies to auctions with the given duration.
+    pub fn for_duration(&self, duration: &Duration) -> Option<ExtensionWindow> {
+        match duration {
+            Time(_) => self.time.clone(),
+            Height(_) => self.height.clone(),
+        }
+    }
+}
+
+/// Refundable deposit taken from the auction creator, to make spamming auctions costly.
+#[cw_serde]
+pub struct CreationDeposit {
+    /// Native coin deposited for each created auction.
+    pub amount: Coin,
+    /// Whether the deposit goes to the fee collector when the auction is canceled or ends
+    /// with no bids. If not, it is returned to the creator in those cases as well.
+    pub forfeit_unsold: bool,
+}
+
+/// Optional settings applied to auctions of a single whitelisted NFT collection.
+#[cw_serde]
+#[derive(Default)]
+pub struct CollectionSettings {
+    /// Human-readable name of the collection, e.g. the label it belongs to.
+    pub label: Option<String>,
+    /// Lowest minimum bid amount that auctions for this collection can be created with.
+    pub minimum_bid_floor: Option<Uint128>,
+}
+
+#[cw_serde]
+pub struct WhitelistedCollection {
+    pub nft_contract: Addr,
+    pub settings: CollectionSettings,
 }
 
 #[cw_serde]
@@ -30,6 +209,98 @@ pub struct Bid {
     pub posted_at: BlockInfo,
 }
 
+/// How the price of an auction is determined.
+#[cw_serde]
+pub enum AuctionKind {
+    /// Ascending-price auction, won by the highest bid once it ends.
+    English,
+    /// Descending-price auction, won at once by the first bid at or above the current price.
+    Dutch(DutchAuction),
+    /// Auction where bids are committed as hashes while it runs, and revealed after it ends.
+    /// The highest revealed bid wins.
+    SealedBid(SealedBidAuction),
+}
+
+/// One more round of an auction that ended without a sale, run right after it ends.
+#[cw_serde]
+pub struct RelistAttempt {
+    /// Duration of the new auction. Has to be of the same kind as the original duration.
+    pub duration: Duration,
+    /// Minimum bid amount of the new auction. Can be lowered, but not raised.
+    /// Keeps the previous round's minimum bid amount if not set.
+    pub minimum_bid_amount: Option<Uint128>,
+}
+
+/// Change to the buyout price of an active auction.
+#[cw_serde]
+pub enum BuyoutPriceUpdate {
+    /// Sets a new buyout price, or adds one to an auction without it.
+    Set { amount: Uint128 },
+    /// Removes the buyout price.
+    Remove,
+}
+
+/// Reveal phase of a sealed-bid auction. Bids can be revealed once the auction ends,
+/// until the reveal phase ends, and the auction can only be resolved after that.
+#[cw_serde]
+pub struct SealedBidAuction {
+    /// Length of the reveal phase, in the same unit as the auction's duration.
+    pub reveal_duration: u64,
+}
+
+/// Bid committed to a sealed-bid auction, but not settled yet.
+#[cw_serde]
+pub struct SealedBid {
+    pub bidder: Addr,
+    /// SHA-256 hash of the bid amount as a 16-byte big-endian integer, followed by the salt
+    /// and the bidder address as UTF-8 strings.
+    pub commitment: HexBinary,
+    /// Funds deposited with the commitment, in the auction's price asset.
+    /// A revealed bid can be at most this amount.
+    pub collateral: Uint128,
+    pub committed_at: BlockInfo,
+    /// Whether the bid was revealed. Only the highest revealed bid is kept after revealing.
+    pub revealed: bool,
+}
+
+/// Price schedule of a Dutch auction. The price starts at `start_price` when the auction starts,
+/// and decays to the auction's minimum bid amount, which acts as the floor, by the time it ends.
+#[cw_serde]
+pub struct DutchAuction {
+    pub start_price: Uint128,
+    pub decay: PriceDecay,
+}
+
+/// How the price of a Dutch auction decays. Time and blocks are counted in the same unit as
+/// the auction's duration.
+#[cw_serde]
+pub enum PriceDecay {
+    /// Price decreases continuously over the whole duration.
+    Linear,
+    /// Price decreases in equal steps, one at the end of every interval of this many seconds
+    /// or blocks, reaching the floor after the last full interval.
+    Stepwise { interval: u64 },
+}
+
+impl DutchAuction {
+    /// Calculate the price after the given elapsed part of the total duration.
+    pub fn price_at(&self, floor_price: Uint128, elapsed: u64, total: u64) -> Uint128 {
+        let (elapsed, total) = match self.decay {
+            PriceDecay::Linear => (elapsed.min(total), total),
+            PriceDecay::Stepwise { interval } => {
+                let steps = total / interval;
+                ((elapsed / interval).min(steps), steps)
+            }
+        };
+
+        let decay = self
+            .start_price
+            .saturating_sub(floor_price)
+            .multiply_ratio(elapsed, total);
+        self.start_price - decay
+    }
+}
+
 #[cw_serde]
 pub struct TrackAuction {
     pub status: AuctionStatus,
@@ -41,8 +312,10 @@ pub struct TrackAuction {
     /// funds, or the NFT (if the auction fails).
     pub creator: Addr,
     /// NFT contract to which the token representing this track belongs.
+    /// For bundle auctions, the contract of the lot's first token.
     pub nft_contract: Addr,
     /// ID of the NFT token representing this track.
+    /// For bundle auctions, the ID of the lot's first token.
     pub track_token_id: String,
     /// Minimum initial bid that will be accepted.
     pub minimum_bid_amount: Uint128,
@@ -52,25 +325,142 @@ pub struct TrackAuction {
     pub active_bid: Option<Bid>,
     /// Price that, if specified and offered in a bid, immediately ends the auctions and wins it.
     pub buyout_price: Option<Uint128>,
+    /// Marketplace fee in basis points, fixed at the time the auction was created.
+    pub fee_bps: u16,
+    /// Deposit taken from the creator when the auction was created, if any.
+    pub creation_deposit: Option<CreationDeposit>,
+    /// Rule for how much each bid has to exceed the previous one.
+    pub bid_increment: BidIncrement,
+    /// Last block height or time at which bids are accepted. Set to the creation time plus
+    /// the duration, and pushed out by bids placed within the anti-sniping window.
+    pub ends_at: Expiration,
+    /// Anti-sniping rule fixed at the time the auction was created, if any.
+    pub anti_sniping: Option<ExtensionWindow>,
+    /// First block height or time at which bids are accepted. The duration is counted from here.
+    pub starts_at: Scheduled,
+    /// How the price of the auction is determined.
+    pub kind: AuctionKind,
+    /// Penalty for unrevealed bids fixed at the time a sealed-bid auction was created.
+    /// Not set for other kinds of auctions.
+    pub unrevealed_bid_penalty: Option<UnrevealedBidPenalty>,
+    /// Whether the winner pays the second-highest bid instead of their own.
+    pub second_price: bool,
+    /// Second-highest bid amount, if any.
+    pub runner_up_amount: Option<Uint128>,
+    /// Lot of tokens sold together in a bundle auction. Not set for single-track auctions.
+    pub lot_id: Option<LotId>,
+    /// Relist attempts left, used in order if the auction ends without a sale.
+    pub relist_attempts: Vec<RelistAttempt>,
+    /// Auction that ended without a sale and was relisted as this one, if any.
+    pub relisted_from: Option<AuctionId>,
 }
 
 impl TrackAuction {
-    /// Calculate what the minimum amount should be for the next bid
-    pub fn minimum_next_bid_amount(&self) -> Uint128 {
+    /// Calculate what the minimum amount should be for the next bid.
+    /// Fails if it overflows, in which case no higher bid can be placed.
+    pub fn minimum_next_bid_amount(&self) -> AuctionResult<Uint128> {
         match &self.active_bid {
-            None => self.minimum_bid_amount,
-            Some(bid) => bid.amount.add(Uint128::from(1u8)),
+            None => Ok(self.minimum_bid_amount),
+            Some(bid) => Ok(bid
+                .amount
+                .checked_add(self.bid_increment.increment_over(bid.amount))
+                .map_err(StdError::from)?),
+        }
+    }
+
+    /// Calculate what the winner of the active bid pays if the auction ends now.
+    /// In second-price auctions, that's the second-highest bid, or the minimum bid amount
+    /// if there was only one bid.
+    pub fn sale_price(&self) -> Option<Uint128> {
+        let bid = self.active_bid.as_ref()?;
+
+        if !self.second_price {
+            return Some(bid.amount);
+        }
+
+        Some(self.runner_up_amount.unwrap_or(self.minimum_bid_amount))
+    }
+
+    /// Calculate the current price of a Dutch auction, or None for other kinds of auctions.
+    pub fn current_price(&self, current_block: &BlockInfo) -> Option<Uint128> {
+        let dutch_auction = match &self.kind {
+            AuctionKind::Dutch(dutch_auction) => dutch_auction,
+            AuctionKind::English | AuctionKind::SealedBid(_) => return None,
+        };
+
+        // start and duration are always of the same kind
+        let elapsed = match self.starts_at {
+            Scheduled::AtHeight(start) => current_block.height.saturating_sub(start),
+            Scheduled::AtTime(start) => {
+                current_block.time.seconds().saturating_sub(start.seconds())
+            }
+        };
+        let total = match self.duration {
+            Height(blocks) => blocks,
+            Time(seconds) => seconds,
+        };
+
+        Some(dutch_auction.price_at(self.minimum_bid_amount, elapsed, total))
+    }
+
+    /// End of the reveal phase of a sealed-bid auction, or None for other kinds of auctions.
+    pub fn reveal_ends_at(&self) -> Option<Expiration> {
+        let reveal_duration = match &self.kind {
+            AuctionKind::SealedBid(sealed_bid_auction) => sealed_bid_auction.reveal_duration,
+            AuctionKind::English | AuctionKind::Dutch(_) => return None,
+        };
+
+        match self.ends_at {
+            Expiration::AtHeight(height) => Some(Expiration::AtHeight(height + reveal_duration)),
+            Expiration::AtTime(time) => {
+                Some(Expiration::AtTime(time.plus_seconds(reveal_duration)))
+            }
+            Expiration::Never {} => Some(Expiration::Never {}),
         }
     }
 
+    /// Whether the auction can be resolved, i.e. it ended, along with its reveal phase if any.
+    pub fn is_resolvable(&self, current_block: &BlockInfo) -> bool {
+        match self.reveal_ends_at() {
+            Some(reveal_ends_at) => is_past(&reveal_ends_at, current_block),
+            None => self.has_ended(current_block),
+        }
+    }
+
+    pub fn has_started(&self, current_block: &BlockInfo) -> bool {
+        self.starts_at.is_triggered(current_block)
+    }
+
     pub fn has_ended(&self, current_block: &BlockInfo) -> bool {
-        match self.duration {
-            Height(height) => current_block.height > self.created_at.height + height,
-            Time(seconds) => current_block.time > self.created_at.time.plus_seconds(seconds),
+        is_past(&self.ends_at, current_block)
+    }
+
+    /// Calculate the new end of the auction if a bid placed at the given block falls within
+    /// its anti-sniping window.
+    pub fn extended_end(&self, current_block: &BlockInfo) -> Option<Expiration> {
+        let rule = self.anti_sniping.as_ref()?;
+
+        match self.ends_at {
+            Expiration::AtHeight(height) if current_block.height + rule.window > height => {
+                Some(Expiration::AtHeight(height + rule.extension))
+            }
+            Expiration::AtTime(time) if current_block.time.plus_seconds(rule.window) > time => {
+                Some(Expiration::AtTime(time.plus_seconds(rule.extension)))
+            }
+            _ => None,
         }
     }
 }
 
+/// Whether the given end point has passed. The end point itself still counts as before the end.
+fn is_past(end: &Expiration, current_block: &BlockInfo) -> bool {
+    match end {
+        Expiration::AtHeight(height) => current_block.height > *height,
+        Expiration::AtTime(time) => current_block.time > *time,
+        Expiration::Never {} => false,
+    }
+}
+
 #[cw_serde]
 pub enum PriceAssetUnchecked {
     Native { denom: String },
@@ -102,6 +492,17 @@ impl PriceAssetUnchecked {
     }
 }
 
+impl From<PriceAsset> for PriceAssetUnchecked {
+    fn from(asset: PriceAsset) -> Self {
+        match asset {
+            Native { denom } => PriceAssetUnchecked::Native { denom },
+            Cw20 { contract } => PriceAssetUnchecked::Cw20 {
+                contract: contract.to_string(),
+            },
+        }
+    }
+}
+
 #[cw_serde]
 pub enum PriceAsset {
     Native { denom: String },
@@ -137,7 +538,350 @@ pub struct AuctionsResponse {
     pub auctions: Vec<TrackAuction>,
 }
 
+/// Track offered at a fixed price. Stays open until it's bought or delisted.
+#[cw_serde]
+pub struct Listing {
+    pub id: ListingId,
+    pub created_at: BlockInfo,
+    /// The address that listed the track, receiving the funds once it's bought.
+    pub seller: Addr,
+    /// NFT contract to which the token representing this track belongs.
+    pub nft_contract: Addr,
+    /// ID of the NFT token representing this track.
+    pub track_token_id: String,
+    /// Amount of the price asset the track is sold for.
+    pub price: Uint128,
+    /// Asset in which the price is denominated.
+    pub price_asset: PriceAsset,
+    /// Marketplace fee in basis points, fixed at the time the listing was created.
+    pub fee_bps: u16,
+}
+
+#[cw_serde]
+pub struct ListingResponse {
+    pub listing: Listing,
+}
+
+#[cw_serde]
+pub struct ListingsResponse {
+    pub listings: Vec<Listing>,
+}
+
+/// Funds escrowed for a track that isn't necessarily for sale.
+/// Stays open until it's accepted by the token's owner, retracted, or refunded after expiring.
+#[cw_serde]
+pub struct Offer {
+    pub id: OfferId,
+    pub created_at: BlockInfo,
+    /// The address that made the offer, receiving the NFT if it's accepted.
+    pub offerer: Addr,
+    /// NFT contract to which the token representing this track belongs.
+    pub nft_contract: Addr,
+    /// ID of the NFT token representing this track.
+    pub track_token_id: String,
+    /// Amount of the price asset escrowed for the track.
+    pub amount: Uint128,
+    /// Asset in which the offer is made.
+    pub price_asset: PriceAsset,
+    /// Once expired, the offer can no longer be accepted, and anyone can refund it.
+    pub expires: Expiration,
+}
+
+#[cw_serde]
+pub struct OfferResponse {
+    pub offer: Offer,
+}
+
+#[cw_serde]
+pub struct OffersResponse {
+    pub offers: Vec<Offer>,
+}
+
+#[cw_serde]
+pub struct LotToken {
+    pub nft_contract: Addr,
+    pub token_id: String,
+}
+
+/// Tokens escrowed to be auctioned together, such as the tracks of an album.
+/// Tokens can only be added while the lot is a draft, before its auction is started.
+#[cw_serde]
+pub struct Lot {
+    pub id: LotId,
+    /// The address that opened the lot, and the only one that can add tokens to it.
+    pub creator: Addr,
+    pub tokens: Vec<LotToken>,
+    /// Auction selling the lot. Not set while the lot is a draft.
+    pub auction_id: Option<AuctionId>,
+}
+
+#[cw_serde]
+pub struct LotResponse {
+    pub lot: Lot,
+}
+
+/// Open edition of a track, sold during a time window, with each edition minted by the
+/// marketplace when it's bought.
+#[cw_serde]
+pub struct Drop {
+    pub id: DropId,
+    pub created_at: BlockInfo,
+    /// The address that created the drop, receiving the funds of its sales.
+    pub artist: Addr,
+    /// NFT contract in which the editions are minted.
+    pub nft_contract: Addr,
+    /// Metadata of every edition, each minted with its own edition number.
+    pub metadata: TrackMetadata,
+    /// Amount of the price asset each edition is sold for.
+    pub price: Uint128,
+    /// Asset in which the price is denominated.
+    pub price_asset: PriceAsset,
+    /// Marketplace fee in basis points, fixed at the time the drop was created.
+    pub fee_bps: u16,
+    /// Time from which editions can be bought.
+    pub starts_at: Timestamp,
+    /// Time from which editions can no longer be bought.
+    pub ends_at: Timestamp,
+    /// Maximum number of editions, if any.
+    pub supply_cap: Option<u32>,
+    /// Number of editions sold so far, which is also the number of the last edition.
+    pub minted: u32,
+}
+
+impl Drop {
+    /// Number of editions that can still be sold, or None if the supply is not capped.
+    pub fn remaining_supply(&self) -> Option<u32> {
+        self.supply_cap
+            .map(|supply_cap| supply_cap.saturating_sub(self.minted))
+    }
+}
+
+#[cw_serde]
+pub struct DropResponse {
+    pub drop: Drop,
+}
+
+#[cw_serde]
+pub struct DropsResponse {
+    pub drops: Vec<Drop>,
+}
+
+#[cw_serde]
+pub enum RaffleStatus {
+    /// Tickets can be bought once the beacon committed to the draw until the raffle ends,
+    /// and the winner drawn afterwards.
+    Open,
+    /// The winner was drawn, and received the NFT.
+    Drawn,
+    /// The raffle was canceled before any ticket was sold, and the NFT returned.
+    Canceled,
+    /// The winner was not drawn before the draw deadline, so the tickets were refunded
+    /// and the NFT returned.
+    Voided,
+}
+
+/// Raffle of an escrowed track, whose winner is drawn among the tickets sold using the
+/// randomness beacon's signature of a round, committed to before any ticket is sold.
+#[cw_serde]
+pub struct Raffle {
+    pub id: RaffleId,
+    pub created_at: BlockInfo,
+    pub creator: Addr,
+    pub nft_contract: Addr,
+    pub track_token_id: String,
+    /// Amount of the price asset each ticket is sold for.
+    pub ticket_price: Uint128,
+    /// Asset in which the ticket price is denominated.
+    pub price_asset: PriceAsset,
+    /// Marketplace fee in basis points, fixed at the time the raffle was created.
+    pub fee_bps: u16,
+    /// Deposit taken from the creator when the raffle was created, if any.
+    pub creation_deposit: Option<CreationDeposit>,
+    /// Time from which tickets can no longer be bought.
+    pub ends_at: Timestamp,
+    /// Maximum number of tickets, if any.
+    pub max_tickets: Option<u32>,
+    /// Number of tickets sold so far. Tickets are numbered from 0.
+    pub tickets_sold: u32,
+    /// Public key of the randomness beacon, fixed at the time the raffle was created.
+    pub beacon_public_key: HexBinary,
+    /// Beacon round the winner is drawn with, the first one published after the raffle ends.
+    pub draw_round: u64,
+    pub status: RaffleStatus,
+    /// Number of the winning ticket, once drawn.
+    pub winning_ticket: Option<u32>,
+    /// Owner of the winning ticket, once drawn.
+    pub winner: Option<Addr>,
+    /// Time after which anyone can void the raffle if its winner has not been drawn.
+    pub draw_deadline: Timestamp,
+    /// SHA-256 hash of the beacon's signature of the draw round, committed to by the beacon
+    /// before tickets go on sale. The draw has to reveal that exact signature.
+    pub draw_commitment: Option<HexBinary>,
+    /// Running SHA-256 hash of the ticket purchases, mixed into the draw so the winner
+    /// doesn't only depend on the beacon.
+    pub ticket_entropy: HexBinary,
+}
+
+impl Raffle {
+    /// Amount paid for all the tickets sold so far.
+    pub fn ticket_sales(&self) -> Uint128 {
+        self.ticket_price * Uint128::from(self.tickets_sold)
+    }
+}
+
+/// Consecutive tickets of a raffle bought at once.
+#[cw_serde]
+pub struct TicketPurchase {
+    pub buyer: Addr,
+    /// Number of the first ticket bought.
+    pub first_ticket: u32,
+    pub count: u32,
+}
+
+#[cw_serde]
+pub struct RaffleResponse {
+    pub raffle: Raffle,
+}
+
+#[cw_serde]
+pub struct RafflesResponse {
+    pub raffles: Vec<Raffle>,
+}
+
+#[cw_serde]
+pub struct RaffleTicketsResponse {
+    pub purchases: Vec<TicketPurchase>,
+}
+
+/// Tokens of a collection that a collection offer can be filled with.
+#[cw_serde]
+pub enum OfferTarget {
+    /// Any token of the collection.
+    AnyToken {},
+    /// Tokens whose metadata has the given artist name.
+    /// Minting is permissionless, so anyone can mint tracks under any artist name.
+    ArtistName { artist_name: String },
+    /// Tokens whose metadata has the given artist address, which only the artist can set.
+    VerifiedArtist { artist_address: String },
+}
+
+/// Funds escrowed for a number of tokens of a collection, matching the offer's target.
+/// Any owner of a matching token can fill it, until all the wanted tokens are bought,
+/// or the offer is retracted or refunded after expiring.
+#[cw_serde]
+pub struct CollectionOffer {
+    pub id: CollectionOfferId,
+    pub created_at: BlockInfo,
+    /// The address that made the offer, receiving the NFTs it's filled with.
+    pub offerer: Addr,
+    /// NFT contract whose tokens can fill the offer.
+    pub nft_contract: Addr,
+    pub target: OfferTarget,
+    /// Amount of the price asset paid for each token.
+    pub price_per_token: Uint128,
+    /// Asset in which the offer is made.
+    pub price_asset: PriceAsset,
+    /// Number of tokens still wanted. The escrow holds the price of each of them.
+    pub remaining_quantity: u32,
+    /// Once expired, the offer can no longer be filled, and anyone can refund it.
+    pub expires: Expiration,
+}
+
+impl CollectionOffer {
+    /// Amount escrowed for the tokens still wanted.
+    pub fn escrowed_amount(&self) -> Uint128 {
+        self.price_per_token * Uint128::from(self.remaining_quantity)
+    }
+}
+
+#[cw_serde]
+pub struct CollectionOfferResponse {
+    pub offer: CollectionOffer,
+}
+
+#[cw_serde]
+pub struct CollectionOffersResponse {
+    pub offers: Vec<CollectionOffer>,
+}
+
 #[cw_serde]
 pub struct ConfigResponse {
     pub config: Config,
 }
+
+#[cw_serde]
+pub struct CollectionResponse {
+    pub collection: WhitelistedCollection,
+}
+
+#[cw_serde]
+pub struct CollectionsResponse {
+    pub collections: Vec<WhitelistedCollection>,
+}
+
+#[cw_serde]
+pub struct FeeInfoResponse {
+    /// Marketplace fee that new auctions will be created with, in basis points (1/10000).
+    pub fee_bps: u16,
+    pub fee_collector: Addr,
+}
+
+#[cw_serde]
+pub struct PendingAdminResponse {
+    /// Address proposed as the new admin, if there is an ongoing admin transfer.
+    pub pending_admin: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct PauseStatusResponse {
+    /// Whether creating auctions and bidding is currently blocked.
+    pub paused: bool,
+}
+
+#[cw_serde]
+pub struct CreationDepositBalanceResponse {
+    /// Pre-funded deposits not yet used for creating auctions, one entry per denom.
+    pub balances: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct AssetBalance {
+    pub asset: PriceAsset,
+    /// Amount of the asset currently held by the contract.
+    pub held: Uint128,
+    /// Amount the contract owes to bidders, auction creators and depositors.
+    pub accounted: Uint128,
+    /// Amount held on top of what is accounted for, e.g. funds sent to the contract directly.
+    pub unaccounted: Uint128,
+}
+
+#[cw_serde]
+pub struct UnaccountedBalancesResponse {
+    pub balances: Vec<AssetBalance>,
+}
+
+#[cw_serde]
+pub struct CurrentPriceResponse {
+    pub price: Uint128,
+    pub asset: PriceAsset,
+}
+
+#[cw_serde]
+pub struct ReservePriceResponse {
+    /// Whether the auction has a hidden reserve price, which is removed once it is finished.
+    pub has_reserve_price: bool,
+    /// Whether the active bid, or the maximum of an active proxy bid, reaches the reserve price.
+    /// Always true without a reserve price.
+    pub reserve_met: bool,
+}
+
+#[cw_serde]
+pub struct SealedBidResponse {
+    /// The bidder's unsettled bid, if any.
+    pub sealed_bid: Option<SealedBid>,
+}
+
+#[cw_serde]
+pub struct SealedBidCommitmentResponse {
+    pub commitment: HexBinary,
+}
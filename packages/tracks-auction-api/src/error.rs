@@ -1,5 +1,6 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{Coin, StdError, Uint128};
 use cw_asset::AssetError;
+use cw_utils::{Duration, Scheduled};
 use thiserror::Error;
 
 pub type AuctionResult<T> = Result<T, AuctionError>;
@@ -18,9 +19,107 @@ pub enum AuctionError {
     #[error("CW721 you're using is not whitelisted for auctions")]
     Cw721NotWhitelisted,
 
+    #[error("Minimum bid amount is lower than the collection's floor of {floor}")]
+    MinimumBidBelowCollectionFloor { floor: Uint128 },
+
+    #[error("At least one price asset has to be accepted")]
+    NoPriceAssets,
+
+    #[error("Price asset is not accepted for auctions")]
+    PriceAssetNotAccepted,
+
+    #[error("Fee cannot be higher than {max_fee_bps} basis points")]
+    FeeTooHigh { max_fee_bps: u16 },
+
+    #[error("Contract is paused")]
+    ContractPaused,
+
+    #[error("Contract is not paused")]
+    ContractNotPaused,
+
+    #[error("Creating an auction requires a deposit of {required}")]
+    InsufficientCreationDeposit { required: Coin },
+
+    #[error("Creation deposit has to be funded with the required deposit denom only")]
+    InvalidCreationDepositFunds,
+
+    #[error("Cannot withdraw more than the deposited balance")]
+    WithdrawingMoreThanDeposited,
+
     #[error("Duration has to be greater than 0")]
     InvalidAuctionDuration,
 
+    #[error("Auction start cannot be in the past, and has to be of the same kind as its duration")]
+    InvalidAuctionStart,
+
+    #[error("Dutch auction start price has to be above its floor, its price steps have to fit in its duration, and it cannot have a buyout price")]
+    InvalidDutchAuction,
+
+    #[error("Auction is not a Dutch auction")]
+    NotDutchAuction,
+
+    #[error("Sealed-bid auction has to have a reveal phase, and cannot have a buyout price")]
+    InvalidSealedBidAuction,
+
+    #[error("Auction is not a sealed-bid auction")]
+    NotSealedBidAuction,
+
+    #[error("Bids on sealed-bid auctions have to be committed and revealed")]
+    SealedBidsMustBeCommitted,
+
+    #[error("Auction already holds the maximum of {max} sealed bids")]
+    TooManySealedBids { max: u32 },
+
+    #[error("A sealed bid was already committed to this auction")]
+    SealedBidAlreadyCommitted,
+
+    #[error("No unsettled sealed bid found for this auction")]
+    SealedBidNotFound,
+
+    #[error("Sealed bid was already revealed")]
+    SealedBidAlreadyRevealed,
+
+    #[error(
+        "Sealed bids can only be revealed after the auction ends, until its reveal phase ends"
+    )]
+    NotInRevealPhase,
+
+    #[error("Revealed bid does not match the committed hash")]
+    CommitmentMismatch,
+
+    #[error("Revealed bid exceeds its collateral")]
+    RevealedBidExceedsCollateral,
+
+    #[error("Unrevealed bid penalty can be at most 10000 basis points")]
+    InvalidUnrevealedBidPenalty,
+
+    #[error("Dutch auctions cannot be settled at the second-highest bid")]
+    SecondPriceNotSupported,
+
+    #[error("Proxy bids can only be placed on English auctions that are not second-price")]
+    ProxyBidsNotSupported,
+
+    #[error(
+        "Reserve price has to be above the minimum bid amount and at most the buyout price, \
+        and cannot be set for Dutch auctions"
+    )]
+    InvalidReservePrice,
+
+    #[error("Duration has to be at least {min}")]
+    AuctionDurationTooShort { min: Duration },
+
+    #[error("Duration can be at most {max}")]
+    AuctionDurationTooLong { max: Duration },
+
+    #[error("Minimum duration cannot be greater than the maximum duration")]
+    InvalidDurationLimits,
+
+    #[error("Anti-sniping window and extension have to be greater than 0")]
+    InvalidAntiSniping,
+
+    #[error("Creator cannot have more than {max} active auctions and raffles")]
+    TooManyActiveAuctions { max: u32 },
+
     #[error("No auction with given ID was found")]
     AuctionIdNotFound,
 
@@ -33,12 +132,18 @@ pub enum AuctionError {
     #[error("Supplied funds do not match the attempted bid")]
     InsufficientFundsForBid,
 
-    #[error("Bid is lower than minimum required")]
-    BidLowerThanMinimum,
+    #[error("Bid is lower than minimum required of {minimum}")]
+    BidLowerThanMinimum { minimum: Uint128 },
+
+    #[error("Bid increment has to be greater than 0, and a percentage can be at most 10000 basis points")]
+    InvalidBidIncrement,
 
     #[error("Attempting to bid using the wrong asset")]
     BidWrongAsset,
 
+    #[error("Cannot place a bid before the auction starts at {starts_at}")]
+    AuctionNotStarted { starts_at: Scheduled },
+
     #[error("Cannot place a bid after the auction has ended")]
     BiddingAfterAuctionEnded,
 
@@ -53,4 +158,184 @@ pub enum AuctionError {
 
     #[error("Auction has already expired")]
     AuctionExpired,
+
+    #[error("No listing with given ID was found")]
+    ListingIdNotFound,
+
+    #[error("Listing price has to be greater than 0")]
+    InvalidListingPrice,
+
+    #[error("Listing price is lower than the collection's floor of {floor}")]
+    ListingPriceBelowCollectionFloor { floor: Uint128 },
+
+    #[error("Listing price changed to {price}")]
+    ListingPriceMismatch { price: Uint128 },
+
+    #[error("Attempting to buy with no funds")]
+    NoPaymentFundsSupplied,
+
+    #[error("Trying to send assets not necessary for the purchase")]
+    UnnecessaryAssetsForPayment,
+
+    #[error("Supplied funds do not cover the listing price")]
+    InsufficientFundsForPurchase,
+
+    #[error("Attempting to buy using the wrong asset")]
+    PaymentWrongAsset,
+
+    #[error("No offer with given ID was found")]
+    OfferIdNotFound,
+
+    #[error("Attempting to make an offer with no funds")]
+    NoOfferFundsSupplied,
+
+    #[error("Trying to send more than one asset for the offer")]
+    UnnecessaryAssetsForOffer,
+
+    #[error("Offer amount has to be greater than 0")]
+    InvalidOfferAmount,
+
+    #[error("Offer is lower than the collection's floor of {floor}")]
+    OfferBelowCollectionFloor { floor: Uint128 },
+
+    #[error("Offer expiration has to be in the future")]
+    InvalidOfferExpiration,
+
+    #[error("Offer has expired")]
+    OfferExpired,
+
+    #[error("Offer has not expired yet")]
+    OfferNotExpired,
+
+    #[error("Offer was made for a different token")]
+    OfferTokenMismatch,
+
+    #[error("No collection offer with given ID was found")]
+    CollectionOfferIdNotFound,
+
+    #[error("Number of tokens wanted has to be greater than 0")]
+    InvalidOfferQuantity,
+
+    #[error("Supplied funds do not cover the price of all the tokens wanted")]
+    InsufficientFundsForOffer,
+
+    #[error("Token does not match the collection offer")]
+    TokenDoesNotMatchOffer,
+
+    #[error("No lot with given ID was found")]
+    LotIdNotFound,
+
+    #[error("Lot's auction was already started")]
+    LotAlreadyStarted,
+
+    #[error("Cannot start an auction for a lot without tokens")]
+    EmptyLot,
+
+    #[error("Lot already holds the maximum of {max} tokens")]
+    LotFull { max: u32 },
+
+    #[error("Auctions can be relisted at most {max} times")]
+    TooManyRelistAttempts { max: u32 },
+
+    #[error(
+        "Relist attempts have to use the same kind of duration as the auction, \
+        and cannot raise the minimum bid amount"
+    )]
+    InvalidRelistAttempt,
+
+    #[error("Once an auction has bids, its terms can only be changed in the bidders' favor")]
+    AuctionUpdateNotAllowed,
+
+    #[error("Auction duration can only be extended, keeping its kind")]
+    InvalidDurationExtension,
+
+    #[error("Drop ID not found")]
+    DropIdNotFound,
+
+    #[error("Drop price has to be greater than 0")]
+    InvalidDropPrice,
+
+    #[error("Drop price has to be at least the collection floor of {floor}")]
+    DropPriceBelowCollectionFloor { floor: Uint128 },
+
+    #[error("Drop has to end after it starts, and in the future")]
+    InvalidDropWindow,
+
+    #[error("Drop supply cap has to be greater than 0")]
+    InvalidDropSupplyCap,
+
+    #[error("Drop metadata cannot set an artist address or an edition number")]
+    InvalidDropMetadata,
+
+    #[error("Drop has not started yet")]
+    DropNotStarted,
+
+    #[error("Drop has ended")]
+    DropEnded,
+
+    #[error("Drop is sold out")]
+    DropSoldOut,
+
+    #[error("Randomness beacon has to have a valid public key and a period greater than 0")]
+    InvalidRandomnessBeacon,
+
+    #[error("Raffles cannot be created without a randomness beacon")]
+    RandomnessBeaconNotConfigured,
+
+    #[error("Raffle ID not found")]
+    RaffleIdNotFound,
+
+    #[error("Ticket price has to be greater than 0")]
+    InvalidTicketPrice,
+
+    #[error("Ticket price has to be at least the collection floor of {floor}")]
+    TicketPriceBelowCollectionFloor { floor: Uint128 },
+
+    #[error("Raffle has to end in the future")]
+    InvalidRaffleEnd,
+
+    #[error("Maximum number of raffle tickets has to be greater than 0")]
+    InvalidMaxTickets,
+
+    #[error("Number of tickets has to be greater than 0")]
+    InvalidTicketCount,
+
+    #[error("Only {remaining} tickets are left")]
+    NotEnoughTicketsLeft { remaining: u32 },
+
+    #[error("Raffle is not open")]
+    RaffleNotOpen,
+
+    #[error("Raffle has ended")]
+    RaffleEnded,
+
+    #[error("Raffle has not ended yet")]
+    RaffleStillInProgress,
+
+    #[error("No tickets were sold in the raffle")]
+    NoTicketsSold,
+
+    #[error("Raffle cannot be canceled once tickets were sold")]
+    RaffleHasTickets,
+
+    #[error("Randomness beacon has not committed to the raffle's draw yet")]
+    RaffleDrawNotCommitted,
+
+    #[error("Raffle draws can only be committed to once, before any ticket is sold")]
+    RaffleDrawAlreadyCommitted,
+
+    #[error("Draw commitment is not signed by the raffle's randomness beacon")]
+    InvalidDrawCommitment,
+
+    #[error("Signature does not match the raffle's draw commitment")]
+    DrawCommitmentMismatch,
+
+    #[error("Raffle can only be voided after its draw deadline")]
+    RaffleDrawDeadlineNotReached,
+
+    #[error("Raffle already holds the maximum of {max} ticket purchases")]
+    TooManyTicketPurchases { max: u32 },
+
+    #[error("Signature is not the randomness beacon's signature of round {round}")]
+    InvalidBeaconSignature { round: u64 },
 }
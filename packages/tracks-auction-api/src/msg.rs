@@ -1,20 +1,59 @@
 use crate::api::{
-    AuctionId, AuctionResponse, AuctionsResponse, ConfigResponse, PriceAssetUnchecked,
+    AntiSniping, AuctionId, AuctionKind, AuctionResponse, AuctionsResponse, BidIncrement,
+    BuyoutPriceUpdate, CollectionOfferId, CollectionOfferResponse, CollectionOffersResponse,
+    CollectionResponse, CollectionSettings, CollectionsResponse, ConfigResponse, CreationDeposit,
+    CreationDepositBalanceResponse, CurrentPriceResponse, DropId, DropResponse, DropsResponse,
+    DurationLimits, FeeInfoResponse, ListingId, ListingResponse, ListingsResponse, LotId,
+    LotResponse, OfferId, OfferResponse, OfferTarget, OffersResponse, PauseStatusResponse,
+    PendingAdminResponse, PriceAssetUnchecked, RaffleId, RaffleResponse, RaffleTicketsResponse,
+    RafflesResponse, RandomnessBeacon, RelistAttempt, ReservePriceResponse,
+    SealedBidCommitmentResponse, SealedBidResponse, UnaccountedBalancesResponse,
+    UnrevealedBidPenalty,
 };
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{HexBinary, Timestamp, Uint128};
 use cw20::Cw20ReceiveMsg;
 use cw721::Cw721ReceiveMsg;
-use cw_utils::Duration;
+use cw721_tracks_api::api::TrackMetadata;
+use cw_utils::{Duration, Expiration, Scheduled};
 
 #[cw_serde]
 pub struct InstantiateMsg {
-    /// NFT contract whose tokens are allowed in this auction contract.
-    pub whitelisted_nft: String,
-    /// Asset in which all the auctions created will be priced.
-    pub price_asset: PriceAssetUnchecked,
+    /// Address allowed to change the configuration. Defaults to the instantiator if not set.
+    pub admin: Option<String>,
+    /// NFT contracts whose tokens are allowed in this auction contract.
+    /// They are whitelisted with default settings.
+    pub whitelisted_nfts: Vec<String>,
+    /// Assets that auction creators can choose to price their auctions in.
+    pub price_assets: Vec<PriceAssetUnchecked>,
+    /// Marketplace fee taken out of the sale proceeds, in basis points (1/10000). At most 2500.
+    pub fee_bps: u16,
+    /// Address receiving the marketplace fees. Defaults to the admin if not set.
+    pub fee_collector: Option<String>,
+    /// Deposit required to create an auction or a raffle. No deposit is required if not set,
+    /// or if the amount is zero.
+    pub creation_deposit: Option<CreationDeposit>,
+    /// Bounds for the durations auctions can be created with. Unbounded if not set.
+    pub duration_limits: Option<DurationLimits>,
+    /// Maximum number of active auctions and open raffles a single creator can have at once.
+    /// Unlimited if not set, or if set to zero.
+    pub max_active_auctions_per_creator: Option<u32>,
+    /// Default rule for how much each bid has to exceed the previous one.
+    /// Defaults to an absolute increment of 1 if not set.
+    pub bid_increment: Option<BidIncrement>,
+    /// Rules for extending auctions that receive bids shortly before they end.
+    /// Auctions are never extended if not set.
+    pub anti_sniping: Option<AntiSniping>,
+    /// Penalty for never revealing a sealed bid. Collateral is refunded in full if not set.
+    pub unrevealed_bid_penalty: Option<UnrevealedBidPenalty>,
+    /// Trusted operator of the randomness raffle winners are drawn with.
+    /// Raffles cannot be created if not set.
+    pub randomness_beacon: Option<RandomnessBeacon>,
 }
 
+#[cw_serde]
+pub struct MigrateMsg {}
+
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Offer a bid on an auction for a single track.
@@ -27,24 +66,325 @@ pub enum ExecuteMsg {
         bid_amount: Uint128,
     },
 
+    /// Places a proxy bid on an auction, escrowing the attached native funds up to a maximum.
+    /// The contract bids the lowest amount that wins on the bidder's behalf, and raises it by
+    /// the auction's minimum increment whenever another bid outbids it, up to the maximum.
+    /// Between two proxy bids with the same maximum, the earlier one wins.
+    /// The unused part of the maximum is refunded once the bid is outbid or the auction ends.
+    ProxyBid {
+        /// ID of the auction to bid on.
+        auction_id: AuctionId,
+        /// The most the bidder is willing to pay, never returned by queries.
+        max_amount: Uint128,
+    },
+
+    /// Commits a sealed bid on a sealed-bid auction, while the auction runs.
+    /// The attached native funds are the bid's collateral, and have to be at least the
+    /// auction's minimum bid amount. Each bidder can commit once per auction.
+    CommitBid {
+        auction_id: AuctionId,
+        /// Hash of the bid, see `SealedBid` for how it's calculated.
+        commitment: HexBinary,
+    },
+
+    /// Reveals a committed sealed bid, during the auction's reveal phase.
+    /// Collateral of bids that are not the highest is refunded right away.
+    RevealBid {
+        auction_id: AuctionId,
+        amount: Uint128,
+        salt: String,
+    },
+
+    /// Buys a listed track with the attached native funds.
+    Buy {
+        listing_id: ListingId,
+        /// Price the buyer expects to pay. Required to be explicitly set, so a price change
+        /// right before the purchase makes it fail instead of charging a different price.
+        price: Uint128,
+    },
+
+    /// Changes the price of a listing. Only callable by the seller.
+    UpdatePrice {
+        listing_id: ListingId,
+        price: Uint128,
+    },
+
+    /// Removes a listing and sends the NFT back to the seller. Only callable by the seller.
+    Delist {
+        listing_id: ListingId,
+    },
+
+    /// Offers the attached native funds for a track, whether or not it's for sale.
+    /// The funds are escrowed until the token's owner accepts the offer by sending the NFT
+    /// with `Cw721HookMsg::AcceptOffer`, or until the offer is retracted or refunded.
+    MakeOffer {
+        nft_contract: String,
+        token_id: String,
+        /// Once expired, the offer can no longer be accepted. Has to be in the future.
+        expires: Expiration,
+    },
+
+    /// Removes an offer and returns its funds. Only callable by the offerer.
+    RetractOffer {
+        offer_id: OfferId,
+    },
+
+    /// Removes an expired offer and returns its funds to the offerer. Callable by anyone.
+    RefundExpiredOffer {
+        offer_id: OfferId,
+    },
+
+    /// Offers the attached native funds for a number of tokens of a collection, matching the
+    /// target. The funds have to cover the price of all of them. Owners of matching tokens
+    /// fill the offer one token at a time, by sending the NFT with
+    /// `Cw721HookMsg::AcceptCollectionOffer`.
+    MakeCollectionOffer {
+        nft_contract: String,
+        target: OfferTarget,
+        price_per_token: Uint128,
+        /// Number of tokens wanted. Has to be greater than 0.
+        quantity: u32,
+        /// Once expired, the offer can no longer be filled. Has to be in the future.
+        expires: Expiration,
+    },
+
+    /// Removes a collection offer and returns the funds escrowed for the tokens still wanted.
+    /// Only callable by the offerer.
+    RetractCollectionOffer {
+        offer_id: CollectionOfferId,
+    },
+
+    /// Removes an expired collection offer and returns its funds to the offerer.
+    /// Callable by anyone.
+    RefundExpiredCollectionOffer {
+        offer_id: CollectionOfferId,
+    },
+
+    /// Opens a draft lot, to auction several tracks together, such as an album or an EP.
+    /// Tokens are added to it with `Cw721HookMsg::AddToLot`.
+    OpenLot {},
+
+    /// Starts the auction of a draft lot, after which no more tokens can be added to it.
+    /// Only callable by the lot's creator. The auction settings are the same as for
+    /// `Cw721HookMsg::CreateAuction`, and apply to the lot as a whole. The minimum bid
+    /// amount has to reach the floors of all the lot's collections.
+    StartLotAuction {
+        lot_id: LotId,
+        duration: Duration,
+        start: Option<Scheduled>,
+        minimum_bid_amount: Uint128,
+        buyout_price: Option<Uint128>,
+        price_asset: PriceAssetUnchecked,
+        bid_increment: Option<BidIncrement>,
+        kind: Option<AuctionKind>,
+        second_price: Option<bool>,
+        reserve_price: Option<Uint128>,
+        relist: Option<Vec<RelistAttempt>>,
+    },
+
+    /// Removes a draft lot and sends all its tokens back to the creator.
+    /// Only callable by the lot's creator.
+    DiscardLot {
+        lot_id: LotId,
+    },
+
+    /// Creates an open-edition drop of a track, minted in the NFT contract on each purchase.
+    /// The sender is the drop's artist, receiving the funds of its sales.
+    CreateDrop {
+        /// NFT contract the editions are minted in. Has to be a whitelisted collection.
+        nft_contract: String,
+
+        /// Metadata of every edition. Cannot set an artist address or an edition number,
+        /// the edition number is set when each edition is minted.
+        metadata: TrackMetadata,
+
+        /// Price of each edition. Has to be greater than 0, and at least the collection's
+        /// minimum bid floor.
+        price: Uint128,
+
+        /// Asset in which the drop is priced. Has to be one of the accepted price assets.
+        price_asset: PriceAssetUnchecked,
+
+        /// Time from which editions can be bought.
+        starts_at: Timestamp,
+
+        /// Time from which editions can no longer be bought. Has to be after the start,
+        /// and in the future.
+        ends_at: Timestamp,
+
+        /// Maximum number of editions. Has to be greater than 0. Uncapped if not set.
+        supply_cap: Option<u32>,
+    },
+
+    /// Buys an edition of a drop with the attached native funds, minting it to the sender.
+    /// Anything sent on top of the price is refunded.
+    MintFromDrop {
+        drop_id: DropId,
+    },
+
+    /// Commits the randomness beacon to the signature a raffle will be drawn with, before any
+    /// ticket is sold. Callable by anyone relaying the beacon's commitment.
+    ///
+    /// The beacon's operator knows the raffle's randomness from then on, so it has to be trusted
+    /// not to buy tickets steering the draw.
+    CommitRaffleDraw {
+        raffle_id: RaffleId,
+        /// SHA-256 hash of the beacon's signature of the raffle's draw round.
+        commitment: HexBinary,
+        /// 64-byte secp256k1 signature by the beacon of the SHA-256 hash of the big-endian
+        /// raffle ID followed by the commitment.
+        signature: HexBinary,
+    },
+
+    /// Buys tickets of an open raffle with the attached native funds, once the beacon
+    /// committed to its draw. Anything sent on top of the price of the tickets is refunded.
+    BuyTickets {
+        raffle_id: RaffleId,
+        /// Number of tickets to buy. Has to be greater than 0.
+        count: u32,
+    },
+
+    /// Draws the winner of a raffle that has ended, with the randomness beacon's signature
+    /// of the raffle's draw round, which has to match the draw commitment.
+    /// Callable by anyone once the signature is published.
+    ///
+    /// The NFT goes to the owner of the winning ticket, and the ticket sales, minus the
+    /// marketplace fee, to the raffle's creator.
+    DrawRaffle {
+        raffle_id: RaffleId,
+        /// 64-byte secp256k1 signature of the SHA-256 hash of the big-endian draw round number.
+        signature: HexBinary,
+    },
+
+    /// Cancels a raffle no ticket was sold in, returning the NFT to its creator.
+    /// Only callable by the raffle's creator.
+    CancelRaffle {
+        raffle_id: RaffleId,
+    },
+
+    /// Voids a raffle whose winner was not drawn by its draw deadline, refunding every ticket
+    /// and returning the NFT to its creator. Callable by anyone.
+    VoidRaffle {
+        raffle_id: RaffleId,
+    },
+
     /// Resolves an auction that has ended.
     /// This means that the auction's status will be changed, and assets will be resolved.
     ///
     /// If there is an active bid, the NFT will go to the bidder and the bid to the auction creator.
-    /// If not, the NFT will go back to its owner.
+    /// If not, the NFT will go back to its owner. Bundle auctions transfer all the lot's tokens.
     ResolveAuction {
         auction_id: AuctionId,
     },
 
     /// Cancels an active auction. Only callable by the auction creator.
     /// Will refund the active bid (if any), and send back the NFT to the creator.
+    /// Bundle auctions send back all the lot's tokens.
     CancelAuction {
         auction_id: AuctionId,
     },
 
+    /// Updates the terms of an active auction. Only callable by the auction creator.
+    ///
+    /// Before the first bid, the minimum bid amount and buyout price can be changed freely,
+    /// and the duration extended. Once there are bids, including sealed ones, only changes in
+    /// the bidders' favor are allowed, lowering the minimum bid amount or the buyout price.
+    UpdateAuction {
+        auction_id: AuctionId,
+
+        /// New minimum bid amount. Unchanged if not set.
+        minimum_bid_amount: Option<Uint128>,
+
+        /// New buyout price, or its removal. Unchanged if not set.
+        buyout_price: Option<BuyoutPriceUpdate>,
+
+        /// New duration, counted from the auction's start. Has to be of the same kind as the
+        /// current duration, and not shorter, even before the first bid. Unchanged if not set.
+        duration: Option<Duration>,
+    },
+
     Receive(Cw20ReceiveMsg),
 
     ReceiveNft(Cw721ReceiveMsg),
+
+    /// Pre-funds the deposit required to create auctions.
+    /// The attached native funds have to be in the denom of the currently required deposit.
+    ///
+    /// Each created auction takes one deposit out of the sender's balance.
+    FundCreationDeposit {},
+
+    /// Withdraws pre-funded deposits that were not used for creating auctions.
+    WithdrawCreationDeposit {
+        denom: String,
+        /// Amount to withdraw. Withdraws the whole balance of the denom if not set.
+        amount: Option<Uint128>,
+    },
+
+    /// Updates the contract configuration. Only callable by the admin.
+    ///
+    /// Changes only apply to auctions created afterwards, existing auctions keep their settings.
+    UpdateConfig {
+        /// New set of assets that auctions can be priced in. Replaces the current set.
+        price_assets: Option<Vec<PriceAssetUnchecked>>,
+        /// New marketplace fee, in basis points (1/10000). At most 2500.
+        fee_bps: Option<u16>,
+        /// New address receiving the marketplace fees.
+        fee_collector: Option<String>,
+        /// New deposit required to create an auction or a raffle. Setting a zero amount removes
+        /// the requirement.
+        creation_deposit: Option<CreationDeposit>,
+        /// New bounds for auction durations. Replaces all the current bounds.
+        duration_limits: Option<DurationLimits>,
+        /// New maximum number of active auctions and open raffles per creator. Setting zero
+        /// removes the limit. Creators already over the new limit keep their auctions and
+        /// raffles, but can't create new ones.
+        max_active_auctions_per_creator: Option<u32>,
+        /// New default rule for bid increments.
+        bid_increment: Option<BidIncrement>,
+        /// New anti-sniping rules. Replaces all the current rules, and only applies
+        /// to auctions created afterwards.
+        anti_sniping: Option<AntiSniping>,
+        /// New penalty for unrevealed sealed bids. Only applies to auctions created afterwards.
+        unrevealed_bid_penalty: Option<UnrevealedBidPenalty>,
+        /// New randomness beacon for raffles. Only applies to raffles created afterwards.
+        randomness_beacon: Option<RandomnessBeacon>,
+    },
+
+    /// Adds an NFT collection to the whitelist, or updates its settings if it's already
+    /// whitelisted. Only callable by the admin.
+    AddCollection {
+        nft_contract: String,
+        /// Settings for the collection. Defaults will be used if not specified.
+        settings: Option<CollectionSettings>,
+    },
+
+    /// Removes an NFT collection from the whitelist. Only callable by the admin.
+    ///
+    /// No new auctions can be created for the collection's tokens,
+    /// but the existing ones can still be bid on, resolved and canceled.
+    RemoveCollection {
+        nft_contract: String,
+    },
+
+    /// Proposes a new admin. Only callable by the current admin.
+    ///
+    /// The role is not transferred until the proposed address accepts it.
+    /// Proposing another address replaces the previous proposal.
+    ProposeNewAdmin {
+        new_admin: String,
+    },
+
+    /// Accepts the admin role. Only callable by the proposed admin.
+    AcceptAdminRole {},
+
+    /// Pauses the contract in case of an emergency. Only callable by the admin.
+    ///
+    /// While paused, no auctions can be created and no bids can be placed.
+    /// Resolving and canceling auctions keeps working, so escrowed NFTs and funds can be returned.
+    Pause {},
+
+    /// Lifts the pause. Only callable by the admin.
+    Unpause {},
 }
 
 #[cw_serde]
@@ -57,15 +397,54 @@ pub enum Cw20HookMsg {
         /// from the funds received (especially if e.g. fees are added later on).
         bid_amount: Uint128,
     },
+    /// Places a proxy bid with the received tokens, see `ExecuteMsg::ProxyBid`.
+    ProxyBid {
+        auction_id: AuctionId,
+        max_amount: Uint128,
+    },
+    /// Commits a sealed bid, with the received tokens as collateral.
+    CommitBid {
+        auction_id: AuctionId,
+        commitment: HexBinary,
+    },
+    /// Buys a listed track with the received tokens.
+    Buy {
+        listing_id: ListingId,
+        /// Price the buyer expects to pay, see `ExecuteMsg::Buy`.
+        price: Uint128,
+    },
+    /// Offers the received tokens for a track, see `ExecuteMsg::MakeOffer`.
+    MakeOffer {
+        nft_contract: String,
+        token_id: String,
+        expires: Expiration,
+    },
+    /// Offers the received tokens for a number of tokens of a collection,
+    /// see `ExecuteMsg::MakeCollectionOffer`.
+    MakeCollectionOffer {
+        nft_contract: String,
+        target: OfferTarget,
+        price_per_token: Uint128,
+        quantity: u32,
+        expires: Expiration,
+    },
+    /// Buys an edition of a drop with the received tokens, see `ExecuteMsg::MintFromDrop`.
+    MintFromDrop { drop_id: DropId },
+    /// Buys raffle tickets with the received tokens, see `ExecuteMsg::BuyTickets`.
+    BuyTickets { raffle_id: RaffleId, count: u32 },
 }
 
 #[cw_serde]
 pub enum Cw721HookMsg {
     CreateAuction {
-        /// Duration of the auction. Once it ends, no new bids are accepted, and if there is
-        /// an active bid, that bid wins the auction.
+        /// Duration of the auction, counted from its start. Once it ends, no new bids are
+        /// accepted, and if there is an active bid, that bid wins the auction.
         duration: Duration,
 
+        /// Block height or time at which bidding opens. Has to be of the same kind as the
+        /// duration, and not in the past. The auction starts right away if not set.
+        start: Option<Scheduled>,
+
         /// Minimum amount of funds to be accepted as the first bid
         minimum_bid_amount: Uint128,
 
@@ -74,6 +453,72 @@ pub enum Cw721HookMsg {
         /// If the amount is specified and someone bids that or higher amount, the auction
         /// ends immediately, and they win the bidding.
         buyout_price: Option<Uint128>,
+
+        /// Asset in which the auction is priced. Has to be one of the accepted price assets.
+        price_asset: PriceAssetUnchecked,
+
+        /// Rule for how much each bid has to exceed the previous one.
+        /// Uses the contract's default rule if not set.
+        bid_increment: Option<BidIncrement>,
+
+        /// Kind of the auction. Defaults to an English auction if not set.
+        /// Dutch auctions use the minimum bid amount as their floor price,
+        /// and cannot have a buyout price.
+        kind: Option<AuctionKind>,
+
+        /// Whether the winner pays the second-highest bid, or the minimum bid amount if there
+        /// was only one bid, with the rest of their bid refunded. Disabled if not set.
+        /// Not supported for Dutch auctions.
+        second_price: Option<bool>,
+
+        /// Price the highest bid has to reach for the NFT to be sold. Lower bids are accepted,
+        /// but if the highest bid is below it when the auction ends, the NFT is returned.
+        /// It is never returned by queries, which only tell whether it has been met.
+        /// Has to be above the minimum bid amount, and at most the buyout price.
+        /// Not supported for Dutch auctions.
+        reserve_price: Option<Uint128>,
+
+        /// Rounds to run in turn if the auction ends without a sale, each as a new auction
+        /// keeping the NFT escrowed, that links back to the previous one. No relisting if not set.
+        relist: Option<Vec<RelistAttempt>>,
+    },
+
+    /// Lists the track at a fixed price, until it's bought or delisted.
+    CreateListing {
+        /// Price the track is sold for. Has to be greater than 0.
+        price: Uint128,
+
+        /// Asset in which the listing is priced. Has to be one of the accepted price assets.
+        price_asset: PriceAssetUnchecked,
+    },
+
+    /// Accepts an unexpired offer made for the sent track. The NFT goes to the offerer,
+    /// and the offered funds, minus the marketplace fee, to the sender.
+    AcceptOffer { offer_id: OfferId },
+
+    /// Fills an unexpired collection offer with the sent track, if it matches the offer's target.
+    /// The NFT goes to the offerer, and the price per token, minus the marketplace fee,
+    /// to the sender.
+    AcceptCollectionOffer { offer_id: CollectionOfferId },
+
+    /// Adds the sent track to a draft lot. Only the lot's creator can add tokens to it.
+    AddToLot { lot_id: LotId },
+
+    /// Raffles the track: tickets are sold until the raffle ends, and the winner is then
+    /// drawn with the configured randomness beacon.
+    CreateRaffle {
+        /// Price of each ticket. Has to be greater than 0, and at least the collection's
+        /// minimum bid floor.
+        ticket_price: Uint128,
+
+        /// Asset in which tickets are priced. Has to be one of the accepted price assets.
+        price_asset: PriceAssetUnchecked,
+
+        /// Time from which tickets can no longer be bought. Has to be in the future.
+        ends_at: Timestamp,
+
+        /// Maximum number of tickets. Has to be greater than 0. Unlimited if not set.
+        max_tickets: Option<u32>,
     },
 }
 
@@ -82,16 +527,145 @@ pub enum Cw721HookMsg {
 pub enum QueryMsg {
     #[returns(ConfigResponse)]
     Config {},
+    #[returns(PendingAdminResponse)]
+    PendingAdmin {},
+    #[returns(FeeInfoResponse)]
+    FeeInfo {},
+    #[returns(PauseStatusResponse)]
+    PauseStatus {},
+    #[returns(CreationDepositBalanceResponse)]
+    CreationDepositBalance { address: String },
+    /// Compares the contract's balances with the funds it is holding on behalf of users.
+    /// Iterates over all active auctions, so it is meant for monitoring rather than for
+    /// being called from other contracts.
+    #[returns(UnaccountedBalancesResponse)]
+    UnaccountedBalances {},
+    #[returns(CollectionResponse)]
+    Collection { nft_contract: String },
+    #[returns(CollectionsResponse)]
+    Collections {
+        /// Optional parameter to start listing collections after a certain address (used for pagination)
+        start_after: Option<String>,
+        /// Optional parameter to limit the size of query response
+        limit: Option<u32>,
+    },
     #[returns(AuctionResponse)]
     Auction { id: AuctionId },
+    /// Returns the current price of a Dutch auction.
+    #[returns(CurrentPriceResponse)]
+    CurrentPrice { auction_id: AuctionId },
+    /// Returns whether an auction's hidden reserve price has been met, without disclosing it.
+    #[returns(ReservePriceResponse)]
+    ReservePrice { auction_id: AuctionId },
+    /// Returns the unsettled sealed bid of a bidder on an auction.
+    #[returns(SealedBidResponse)]
+    SealedBid {
+        auction_id: AuctionId,
+        bidder: String,
+    },
+    /// Calculates the commitment for a sealed bid, to be used with `CommitBid`.
+    #[returns(SealedBidCommitmentResponse)]
+    SealedBidCommitment {
+        amount: Uint128,
+        salt: String,
+        bidder: String,
+    },
     #[returns(AuctionsResponse)]
     Auctions {
         /// Whether the query should return active auctions.
         /// When set to false, will return finished auctions.
         active_auctions: bool,
+        /// Optional parameter to only return auctions for tokens of the given NFT contract,
+        /// including bundle auctions with any token of it in their lot.
+        nft_contract: Option<String>,
         /// Optional parameter to start listing items after a certain ID (used for pagination)
         start_after: Option<AuctionId>,
         /// Optional parameter to limit the size of query response
         limit: Option<u32>,
     },
+    #[returns(ListingResponse)]
+    Listing { id: ListingId },
+    #[returns(ListingsResponse)]
+    Listings {
+        /// Optional parameter to only return listings of the given seller.
+        seller: Option<String>,
+        /// Optional parameter to only return listings for tokens of the given NFT contract.
+        nft_contract: Option<String>,
+        /// Optional parameter to start listing items after a certain ID (used for pagination)
+        start_after: Option<ListingId>,
+        /// Optional parameter to limit the size of query response
+        limit: Option<u32>,
+    },
+    #[returns(OfferResponse)]
+    Offer { id: OfferId },
+    /// Returns the open offers made for a token, including expired ones not yet refunded.
+    #[returns(OffersResponse)]
+    OffersByToken {
+        nft_contract: String,
+        token_id: String,
+        /// Optional parameter to start listing items after a certain ID (used for pagination)
+        start_after: Option<OfferId>,
+        /// Optional parameter to limit the size of query response
+        limit: Option<u32>,
+    },
+    /// Returns the open offers made by an address, including expired ones not yet refunded.
+    #[returns(OffersResponse)]
+    OffersByOfferer {
+        offerer: String,
+        /// Optional parameter to start listing items after a certain ID (used for pagination)
+        start_after: Option<OfferId>,
+        /// Optional parameter to limit the size of query response
+        limit: Option<u32>,
+    },
+    /// Returns a lot and the tokens it holds.
+    #[returns(LotResponse)]
+    Lot { id: LotId },
+    /// Returns a drop, including how many editions were sold.
+    #[returns(DropResponse)]
+    Drop { id: DropId },
+    #[returns(DropsResponse)]
+    Drops {
+        /// Optional parameter to only return drops created by the given artist.
+        artist: Option<String>,
+        /// Optional parameter to start listing items after a certain ID (used for pagination)
+        start_after: Option<DropId>,
+        /// Optional parameter to limit the size of query response
+        limit: Option<u32>,
+    },
+    /// Returns a raffle, including its winner once drawn.
+    #[returns(RaffleResponse)]
+    Raffle { id: RaffleId },
+    #[returns(RafflesResponse)]
+    Raffles {
+        /// Optional parameter to only return raffles created by the given address.
+        creator: Option<String>,
+        /// Optional parameter to start listing items after a certain ID (used for pagination)
+        start_after: Option<RaffleId>,
+        /// Optional parameter to limit the size of query response
+        limit: Option<u32>,
+    },
+    /// Returns the ticket purchases of a raffle, ordered by ticket number.
+    #[returns(RaffleTicketsResponse)]
+    RaffleTickets {
+        raffle_id: RaffleId,
+        /// Optional parameter to start listing purchases after a certain ticket number
+        /// (used for pagination)
+        start_after: Option<u32>,
+        /// Optional parameter to limit the size of query response
+        limit: Option<u32>,
+    },
+    #[returns(CollectionOfferResponse)]
+    CollectionOffer { id: CollectionOfferId },
+    /// Returns the open collection offers, including expired ones not yet refunded.
+    #[returns(CollectionOffersResponse)]
+    CollectionOffers {
+        /// Optional parameter to only return offers for tokens of the given NFT contract.
+        nft_contract: Option<String>,
+        /// Optional parameter to only return offers made by the given address.
+        offerer: Option<String>,
+        /// Optional parameter to start listing items after a certain ID (used for pagination)
+        start_after: Option<CollectionOfferId>,
+        /// Optional parameter to limit the size of query response
+        limit: Option<u32>,
+    },
 }
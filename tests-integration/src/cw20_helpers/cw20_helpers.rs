@@ -91,7 +91,7 @@ impl Cw20Mint for TestFixture {
                 recipient: receiver.into_addr().to_string(),
                 amount: amount.into(),
             },
-            &vec![],
+            &[],
         )
     }
 }
@@ -108,7 +108,7 @@ impl Cw20Burn for TestFixture {
             &Burn {
                 amount: amount.into(),
             },
-            &vec![],
+            &[],
         )
     }
 }
@@ -1 +1,2 @@
+#[allow(clippy::module_inception)]
 pub mod cw20_helpers;
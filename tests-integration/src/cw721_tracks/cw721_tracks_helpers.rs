@@ -11,9 +11,11 @@ use cw_multi_test::{App, AppResponse, ContractWrapper, Executor, IntoAddr};
 pub fn default_track_metadata() -> TrackMetadata {
     TrackMetadata {
         artist_name: "Boden".to_string(),
+        artist_address: None,
         album: None,
         track_name: "Debt Spiral".to_string(),
         audio_track_url: "https://www.usdebtclock.org/".to_string(),
+        edition: None,
     }
 }
 
@@ -75,7 +77,7 @@ impl Cw721TracksExecute for TestFixture {
                 token_uri: token_uri.map(|it| it.to_string()),
                 extension: metadata,
             },
-            &vec![],
+            &[],
         )
     }
 }
@@ -97,7 +99,7 @@ pub fn mint_nft(
             token_uri: token_uri.map(|it| it.to_string()),
             extension: metadata,
         },
-        &vec![],
+        &[],
     )
 }
 
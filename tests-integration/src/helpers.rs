@@ -17,6 +17,7 @@ pub const UANDR: &str = "uandr";
 
 const BLOCK_TIME_SEC: u64 = 5;
 
+#[allow(dead_code)]
 pub struct ContractInfo {
     pub addr: Addr,
     pub code_id: u64,
@@ -82,6 +83,7 @@ impl TestFixture {
     }
 }
 
+#[allow(dead_code)]
 pub trait NativeMInt {
     fn mint_native(&mut self, addr: &str, coins: Vec<Coin>) -> AnyResult<()>;
     fn mint_native_multi(&mut self, balances: Vec<(impl Into<String>, Vec<Coin>)>)
@@ -109,6 +111,7 @@ impl NativeMInt for App {
     }
 }
 
+#[allow(dead_code)]
 pub trait MoveBlock {
     fn move_block_height(&mut self, blocks: u64);
     fn move_time_sec(&mut self, seconds: u64);
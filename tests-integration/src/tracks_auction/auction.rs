@@ -10,6 +10,7 @@ use crate::tracks_auction::tracks_auction_helpers::{TracksAuctionExecute, Tracks
 use cosmwasm_std::{coin, coins};
 use cw_multi_test::App;
 use cw_utils::Duration;
+use tracks_auction_api::api::PriceAssetUnchecked;
 use Duration::Time;
 
 #[test]
@@ -20,7 +21,14 @@ fn nft_auction_with_several_bids() -> anyhow::Result<()> {
 
     fixture.mint_nft(USER1, token_id, None, default_track_metadata())?;
 
-    fixture.create_nft_auction(USER1, token_id, Time(100), 100, None)?;
+    fixture.create_nft_auction(
+        USER1,
+        token_id,
+        Time(100),
+        100,
+        None,
+        PriceAssetUnchecked::native(UATOM),
+    )?;
 
     // lower than minimum bid fails
     assert_is_err!(fixture.bid_on_auction(USER2, 0, coin(99, UATOM)));
@@ -70,7 +78,14 @@ fn nft_auction_with_instant_buyout() -> anyhow::Result<()> {
 
     fixture.mint_nft(USER1, token_id, None, default_track_metadata())?;
 
-    fixture.create_nft_auction(USER1, token_id, Time(100), 100, Some(200))?;
+    fixture.create_nft_auction(
+        USER1,
+        token_id,
+        Time(100),
+        100,
+        Some(200),
+        PriceAssetUnchecked::native(UATOM),
+    )?;
 
     // bid minimum
     fixture.bid_on_auction(USER2, 0, coin(100, UATOM))?;
@@ -92,6 +107,44 @@ fn nft_auction_with_instant_buyout() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn nft_auction_with_proxy_bids() -> anyhow::Result<()> {
+    let mut fixture = TestFixture::new_with_native(UATOM);
+
+    let token_id = "tokenID";
+
+    fixture.mint_nft(USER1, token_id, None, default_track_metadata())?;
+
+    fixture.create_nft_auction(
+        USER1,
+        token_id,
+        Time(100),
+        100,
+        None,
+        PriceAssetUnchecked::native(UATOM),
+    )?;
+
+    // the proxy bid is placed at the minimum, escrowing the whole maximum
+    fixture.proxy_bid_on_auction(USER2, 0, coin(150, UATOM))?;
+    fixture.assert_active_bid(0, USER2, coin(100, UATOM), None);
+    fixture.assert_balance(USER2, vec![]);
+
+    // a lower bid is refunded, and the proxy bid raised one increment above it
+    fixture.bid_on_auction(USER3, 0, coin(120, UATOM))?;
+    fixture.assert_active_bid(0, USER2, coin(121, UATOM), None);
+    fixture.assert_balance(USER3, coins(120, UATOM));
+
+    fixture.move_time_sec(101);
+    fixture.resolve_auction(ADMIN, 0)?;
+
+    // the winner pays the raised bid, and gets back the rest of the maximum
+    fixture.assert_nft_owner(token_id, USER2);
+    fixture.assert_balance(USER1, coins(121, UATOM));
+    fixture.assert_balance(USER2, coins(29, UATOM));
+
+    Ok(())
+}
+
 #[test]
 fn nft_auction_cancel() -> anyhow::Result<()> {
     let mut fixture = TestFixture::new_with_native(UATOM);
@@ -100,7 +153,14 @@ fn nft_auction_cancel() -> anyhow::Result<()> {
 
     fixture.mint_nft(USER1, token_id, None, default_track_metadata())?;
 
-    fixture.create_nft_auction(USER1, token_id, Time(100), 100, None)?;
+    fixture.create_nft_auction(
+        USER1,
+        token_id,
+        Time(100),
+        100,
+        None,
+        PriceAssetUnchecked::native(UATOM),
+    )?;
 
     // make a bid
     fixture.bid_on_auction(USER2, 0, coin(105, UATOM))?;
@@ -134,7 +194,14 @@ fn nft_auction_with_several_cw20_bids() -> anyhow::Result<()> {
 
     fixture.mint_nft(USER1, token_id, None, default_track_metadata())?;
 
-    fixture.create_nft_auction(USER1, token_id, Time(100), 100, None)?;
+    fixture.create_nft_auction(
+        USER1,
+        token_id,
+        Time(100),
+        100,
+        None,
+        PriceAssetUnchecked::cw20(cw20.to_string()),
+    )?;
 
     // lower than minimum bid fails
     assert_is_err!(fixture.bid_cw20_on_auction(USER2, 0, cw20.clone(), 99));
@@ -175,3 +242,58 @@ fn nft_auction_with_several_cw20_bids() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn nft_auctions_in_different_price_assets_side_by_side() -> anyhow::Result<()> {
+    let mut app = App::default();
+    let (cw20_code_id, cw20) = store_and_instantiate_cw20(&mut app)?;
+
+    let mut fixture = TestFixture::new_with_cw20(app, cw20_code_id, cw20.clone());
+
+    fixture.update_price_assets(
+        ADMIN,
+        vec![
+            PriceAssetUnchecked::native(UATOM),
+            PriceAssetUnchecked::cw20(cw20.to_string()),
+        ],
+    )?;
+
+    fixture.mint_nft(USER1, "atom_track", None, default_track_metadata())?;
+    fixture.mint_nft(USER1, "cw20_track", None, default_track_metadata())?;
+
+    fixture.create_nft_auction(
+        USER1,
+        "atom_track",
+        Time(100),
+        100,
+        None,
+        PriceAssetUnchecked::native(UATOM),
+    )?;
+    fixture.create_nft_auction(
+        USER1,
+        "cw20_track",
+        Time(100),
+        100,
+        None,
+        PriceAssetUnchecked::cw20(cw20.to_string()),
+    )?;
+
+    // each auction only accepts its own price asset
+    assert_is_err!(fixture.bid_cw20_on_auction(USER2, 0, cw20.clone(), 100));
+    assert_is_err!(fixture.bid_on_auction(USER2, 1, coin(100, UATOM)));
+
+    fixture.bid_on_auction(USER2, 0, coin(100, UATOM))?;
+    fixture.bid_cw20_on_auction(USER3, 1, cw20.clone(), 120)?;
+
+    fixture.move_time_sec(101);
+
+    fixture.resolve_auction(ADMIN, 0)?;
+    fixture.resolve_auction(ADMIN, 1)?;
+
+    fixture.assert_nft_owner("atom_track", USER2);
+    fixture.assert_nft_owner("cw20_track", USER3);
+    fixture.assert_balance(USER1, coins(100, UATOM));
+    fixture.assert_cw20_balance(USER1, cw20, 120);
+
+    Ok(())
+}
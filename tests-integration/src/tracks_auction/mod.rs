@@ -1,2 +1,3 @@
 mod auction;
+mod raffle;
 pub mod tracks_auction_helpers;
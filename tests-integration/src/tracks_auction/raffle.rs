@@ -0,0 +1,86 @@
+use crate::assert_is_err;
+use crate::cw721_tracks::cw721_tracks_helpers::{
+    default_track_metadata, Cw721TracksExecute, Cw721TracksQueries,
+};
+use crate::helpers::{BalanceQuery, MoveBlock, TestFixture, ADMIN, UATOM, USER1, USER2, USER3};
+use crate::tracks_auction::tracks_auction_helpers::TracksAuctionExecute;
+use cosmwasm_std::{coin, coins, HexBinary, Timestamp};
+use tracks_auction_api::api::{PriceAssetUnchecked, RandomnessBeacon};
+
+/// Beacon fixture, with a signature of round 35, the first round published after
+/// raffles ending 600 seconds after the initial block, and a signature of the
+/// commitment to drawing raffle 0 with it.
+const BEACON_PUBLIC_KEY: &str =
+    "02018145a3628c457cccd26f0d4031f241c2633ad13c00bc321d38f06fada6ca7d";
+const ROUND_35_SIGNATURE: &str = "2a8e6e01198abed698a1cdea6f4fc947b8218aa3f9b70f644fed1ebc61df3e65\
+623ea58bf6d962e88ad1f0ecd9f45520351cd7de810bb0245008e5252c987ea2";
+const ROUND_35_COMMITMENT: &str =
+    "7c7423b7c589db4f8b2016cdc30b582de9ad7c1a99c8f3907be6a9bf96cb7ff5";
+const COMMITMENT_SIGNATURE: &str =
+    "10e8ea55ae828fea992a9210b60b7ecc32ae6cf47d0005cbda835caa7d978fcb\
+7456218390ae7f7851b2f783316022df0f7ef060c07a5b73666ce09352d74af3";
+
+#[test]
+fn nft_raffle_drawn_with_beacon_signature() -> anyhow::Result<()> {
+    let mut fixture = TestFixture::new_with_native(UATOM);
+
+    let token_id = "tokenID";
+
+    fixture.mint_nft(USER1, token_id, None, default_track_metadata())?;
+
+    // raffles need a randomness beacon to be configured
+    let ends_at = fixture.app.block_info().time.plus_seconds(600);
+    assert_is_err!(fixture.create_nft_raffle(
+        USER1,
+        token_id,
+        10,
+        ends_at,
+        PriceAssetUnchecked::native(UATOM),
+    ));
+
+    fixture.update_randomness_beacon(
+        ADMIN,
+        RandomnessBeacon {
+            public_key: HexBinary::from_hex(BEACON_PUBLIC_KEY)?,
+            genesis_time: Timestamp::from_seconds(1_571_797_000),
+            period: 30,
+        },
+    )?;
+
+    fixture.create_nft_raffle(
+        USER1,
+        token_id,
+        10,
+        ends_at,
+        PriceAssetUnchecked::native(UATOM),
+    )?;
+
+    // tickets go on sale once the beacon committed to the round drawing the raffle
+    assert_is_err!(fixture.buy_raffle_tickets(USER2, 0, 2, coin(20, UATOM)));
+    fixture.commit_raffle_draw(
+        ADMIN,
+        0,
+        HexBinary::from_hex(ROUND_35_COMMITMENT)?,
+        HexBinary::from_hex(COMMITMENT_SIGNATURE)?,
+    )?;
+
+    fixture.buy_raffle_tickets(USER2, 0, 2, coin(20, UATOM))?;
+    fixture.buy_raffle_tickets(USER3, 0, 3, coin(30, UATOM))?;
+    fixture.buy_raffle_tickets(USER2, 0, 2, coin(20, UATOM))?;
+
+    // drawing before the raffle ends fails
+    let signature = HexBinary::from_hex(ROUND_35_SIGNATURE)?;
+    assert_is_err!(fixture.draw_raffle(ADMIN, 0, signature.clone()));
+
+    fixture.move_time_sec(600);
+
+    // no tickets can be bought once the raffle ended
+    assert_is_err!(fixture.buy_raffle_tickets(USER2, 0, 1, coin(10, UATOM)));
+
+    // the signature, mixed with the purchases, draws a ticket bought by USER3
+    fixture.draw_raffle(ADMIN, 0, signature)?;
+    fixture.assert_nft_owner(token_id, USER3);
+    fixture.assert_balance(USER1, coins(70, UATOM));
+
+    Ok(())
+}
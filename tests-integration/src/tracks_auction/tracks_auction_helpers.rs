@@ -1,14 +1,18 @@
 use crate::cw20_helpers::cw20_helpers::{Cw20Burn, Cw20Mint};
 use crate::helpers::{NativeMInt, TestFixture, ADMIN};
-use cosmwasm_std::{to_json_binary, Addr, BankMsg, BlockInfo, Coin, CosmosMsg, Uint128};
+use cosmwasm_std::{
+    to_json_binary, Addr, BankMsg, BlockInfo, Coin, CosmosMsg, HexBinary, Timestamp, Uint128,
+};
 use cw721::Cw721ExecuteMsg::SendNft;
 use cw_multi_test::error::AnyResult;
 use cw_multi_test::{App, AppResponse, ContractWrapper, Executor, IntoAddr};
 use cw_utils::Duration;
-use tracks_auction_api::api::{AuctionResponse, Bid, PriceAsset, PriceAssetUnchecked};
+use tracks_auction_api::api::{
+    AuctionResponse, Bid, PriceAsset, PriceAssetUnchecked, RandomnessBeacon,
+};
 use tracks_auction_api::msg::QueryMsg::Auction;
 use tracks_auction_api::msg::{Cw20HookMsg, ExecuteMsg as AuctionExecuteMsg, InstantiateMsg};
-use AuctionExecuteMsg::ResolveAuction;
+use AuctionExecuteMsg::{ResolveAuction, UpdateConfig};
 use BankMsg::Burn;
 use CosmosMsg::Bank;
 
@@ -27,8 +31,18 @@ pub fn instantiate_tracks_auction(
     price_asset: PriceAssetUnchecked,
 ) -> AnyResult<Addr> {
     let msg = InstantiateMsg {
-        whitelisted_nft,
-        price_asset,
+        admin: None,
+        whitelisted_nfts: vec![whitelisted_nft],
+        price_assets: vec![price_asset],
+        fee_bps: 0,
+        fee_collector: None,
+        creation_deposit: None,
+        duration_limits: None,
+        max_active_auctions_per_creator: None,
+        bid_increment: None,
+        anti_sniping: None,
+        unrevealed_bid_penalty: None,
+        randomness_beacon: None,
     };
 
     app.instantiate_contract(
@@ -60,6 +74,7 @@ pub trait TracksAuctionExecute {
         duration: Duration,
         minimum_bid_amount: u128,
         buyout_price: Option<u128>,
+        price_asset: PriceAssetUnchecked,
     ) -> AnyResult<AppResponse>;
 
     fn bid_on_auction(
@@ -77,9 +92,60 @@ pub trait TracksAuctionExecute {
         amount: u128,
     ) -> AnyResult<AppResponse>;
 
+    fn proxy_bid_on_auction(
+        &mut self,
+        bidder: &str,
+        auction_id: u64,
+        max_bid: Coin,
+    ) -> AnyResult<AppResponse>;
+
     fn cancel_auction(&mut self, sender: &str, auction_id: u64) -> AnyResult<AppResponse>;
 
     fn resolve_auction(&mut self, sender: &str, auction_id: u64) -> AnyResult<AppResponse>;
+
+    fn update_price_assets(
+        &mut self,
+        sender: &str,
+        price_assets: Vec<PriceAssetUnchecked>,
+    ) -> AnyResult<AppResponse>;
+
+    fn update_randomness_beacon(
+        &mut self,
+        sender: &str,
+        beacon: RandomnessBeacon,
+    ) -> AnyResult<AppResponse>;
+
+    fn create_nft_raffle(
+        &mut self,
+        owner: &str,
+        token_id: &str,
+        ticket_price: u128,
+        ends_at: Timestamp,
+        price_asset: PriceAssetUnchecked,
+    ) -> AnyResult<AppResponse>;
+
+    fn commit_raffle_draw(
+        &mut self,
+        sender: &str,
+        raffle_id: u64,
+        commitment: HexBinary,
+        signature: HexBinary,
+    ) -> AnyResult<AppResponse>;
+
+    fn buy_raffle_tickets(
+        &mut self,
+        buyer: &str,
+        raffle_id: u64,
+        count: u32,
+        payment: Coin,
+    ) -> AnyResult<AppResponse>;
+
+    fn draw_raffle(
+        &mut self,
+        sender: &str,
+        raffle_id: u64,
+        signature: HexBinary,
+    ) -> AnyResult<AppResponse>;
 }
 
 impl TracksAuctionExecute for TestFixture {
@@ -90,6 +156,7 @@ impl TracksAuctionExecute for TestFixture {
         duration: Duration,
         minimum_bid_amount: u128,
         buyout_price: Option<u128>,
+        price_asset: PriceAssetUnchecked,
     ) -> AnyResult<AppResponse> {
         self.app.execute_contract(
             owner.into_addr(),
@@ -99,11 +166,18 @@ impl TracksAuctionExecute for TestFixture {
                 token_id: token_id.to_string(),
                 msg: to_json_binary(&tracks_auction_api::msg::Cw721HookMsg::CreateAuction {
                     duration,
+                    start: None,
                     minimum_bid_amount: Uint128::from(minimum_bid_amount),
                     buyout_price: buyout_price.map(Uint128::from),
+                    price_asset,
+                    bid_increment: None,
+                    kind: None,
+                    second_price: None,
+                    reserve_price: None,
+                    relist: None,
                 })?,
             },
-            &vec![],
+            &[],
         )
     }
 
@@ -122,7 +196,7 @@ impl TracksAuctionExecute for TestFixture {
                 auction_id,
                 bid_amount: bid.amount,
             },
-            &vec![bid.clone()],
+            std::slice::from_ref(&bid),
         );
 
         // if bidding failed, burn what we minted to the bidder to make this 'atomic'
@@ -155,7 +229,7 @@ impl TracksAuctionExecute for TestFixture {
                     bid_amount: amount.into(),
                 })?,
             },
-            &vec![],
+            &[],
         );
 
         if result.is_err() {
@@ -166,12 +240,43 @@ impl TracksAuctionExecute for TestFixture {
         result
     }
 
+    fn proxy_bid_on_auction(
+        &mut self,
+        bidder: &str,
+        auction_id: u64,
+        max_bid: Coin,
+    ) -> AnyResult<AppResponse> {
+        self.app.mint_native(bidder, vec![max_bid.clone()])?;
+
+        let result = self.app.execute_contract(
+            bidder.into_addr(),
+            self.tracks_auction.addr.clone(),
+            &AuctionExecuteMsg::ProxyBid {
+                auction_id,
+                max_amount: max_bid.amount,
+            },
+            std::slice::from_ref(&max_bid),
+        );
+
+        // if bidding failed, burn what we minted to the bidder to make this 'atomic'
+        if result.is_err() {
+            self.app.execute(
+                bidder.into_addr(),
+                Bank(Burn {
+                    amount: vec![max_bid],
+                }),
+            )?;
+        }
+
+        result
+    }
+
     fn cancel_auction(&mut self, sender: &str, auction_id: u64) -> AnyResult<AppResponse> {
         self.app.execute_contract(
             sender.into_addr(),
             self.tracks_auction.addr.clone(),
             &AuctionExecuteMsg::CancelAuction { auction_id },
-            &vec![],
+            &[],
         )
     }
 
@@ -180,7 +285,145 @@ impl TracksAuctionExecute for TestFixture {
             sender.into_addr(),
             self.tracks_auction.addr.clone(),
             &ResolveAuction { auction_id },
-            &vec![],
+            &[],
+        )
+    }
+
+    fn update_price_assets(
+        &mut self,
+        sender: &str,
+        price_assets: Vec<PriceAssetUnchecked>,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            sender.into_addr(),
+            self.tracks_auction.addr.clone(),
+            &UpdateConfig {
+                price_assets: Some(price_assets),
+                fee_bps: None,
+                fee_collector: None,
+                creation_deposit: None,
+                duration_limits: None,
+                max_active_auctions_per_creator: None,
+                bid_increment: None,
+                anti_sniping: None,
+                unrevealed_bid_penalty: None,
+                randomness_beacon: None,
+            },
+            &[],
+        )
+    }
+
+    fn update_randomness_beacon(
+        &mut self,
+        sender: &str,
+        beacon: RandomnessBeacon,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            sender.into_addr(),
+            self.tracks_auction.addr.clone(),
+            &UpdateConfig {
+                price_assets: None,
+                fee_bps: None,
+                fee_collector: None,
+                creation_deposit: None,
+                duration_limits: None,
+                max_active_auctions_per_creator: None,
+                bid_increment: None,
+                anti_sniping: None,
+                unrevealed_bid_penalty: None,
+                randomness_beacon: Some(beacon),
+            },
+            &[],
+        )
+    }
+
+    fn create_nft_raffle(
+        &mut self,
+        owner: &str,
+        token_id: &str,
+        ticket_price: u128,
+        ends_at: Timestamp,
+        price_asset: PriceAssetUnchecked,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            owner.into_addr(),
+            self.cw721_tracks.addr.clone(),
+            &SendNft {
+                contract: self.tracks_auction.addr.to_string(),
+                token_id: token_id.to_string(),
+                msg: to_json_binary(&tracks_auction_api::msg::Cw721HookMsg::CreateRaffle {
+                    ticket_price: Uint128::from(ticket_price),
+                    price_asset,
+                    ends_at,
+                    max_tickets: None,
+                })?,
+            },
+            &[],
+        )
+    }
+
+    fn commit_raffle_draw(
+        &mut self,
+        sender: &str,
+        raffle_id: u64,
+        commitment: HexBinary,
+        signature: HexBinary,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            sender.into_addr(),
+            self.tracks_auction.addr.clone(),
+            &AuctionExecuteMsg::CommitRaffleDraw {
+                raffle_id,
+                commitment,
+                signature,
+            },
+            &[],
+        )
+    }
+
+    fn buy_raffle_tickets(
+        &mut self,
+        buyer: &str,
+        raffle_id: u64,
+        count: u32,
+        payment: Coin,
+    ) -> AnyResult<AppResponse> {
+        self.app.mint_native(buyer, vec![payment.clone()])?;
+
+        let result = self.app.execute_contract(
+            buyer.into_addr(),
+            self.tracks_auction.addr.clone(),
+            &AuctionExecuteMsg::BuyTickets { raffle_id, count },
+            std::slice::from_ref(&payment),
+        );
+
+        // if buying failed, burn what we minted to the buyer to make this 'atomic'
+        if result.is_err() {
+            self.app.execute(
+                buyer.into_addr(),
+                Bank(Burn {
+                    amount: vec![payment],
+                }),
+            )?;
+        }
+
+        result
+    }
+
+    fn draw_raffle(
+        &mut self,
+        sender: &str,
+        raffle_id: u64,
+        signature: HexBinary,
+    ) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            sender.into_addr(),
+            self.tracks_auction.addr.clone(),
+            &AuctionExecuteMsg::DrawRaffle {
+                raffle_id,
+                signature,
+            },
+            &[],
         )
     }
 }